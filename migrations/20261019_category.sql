@@ -0,0 +1,11 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+CREATE TABLE IF NOT EXISTS category (
+    id VARCHAR(36) PRIMARY KEY,
+    name TEXT NOT NULL,
+
+    UNIQUE(name)
+);
+
+-- A feed belongs to at most one category. Feeds without a category are listed ungrouped
+ALTER TABLE feed ADD COLUMN category_id VARCHAR(36) REFERENCES category(id) ON DELETE SET NULL;
@@ -0,0 +1,24 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- If the article was starred by the user (or by a filter rule)
+ALTER TABLE article ADD COLUMN starred SMALLINT NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS filter_rule (
+    id VARCHAR(36) PRIMARY KEY,
+
+    -- The feed the rule applies to. If it is NULL, the rule applies to all the feeds
+    feed_id VARCHAR(36),
+
+    -- The field the pattern is matched against: title, author, content or any
+    field TEXT NOT NULL,
+
+    -- How the pattern is matched: substring (case insensitive) or regex
+    match_type TEXT NOT NULL,
+
+    pattern TEXT NOT NULL,
+
+    -- What to do with the matching articles when they are ingested: skip, mark_as_read or star
+    action TEXT NOT NULL,
+
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
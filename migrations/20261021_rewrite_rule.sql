@@ -0,0 +1,22 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Rules applied to the HTML of the articles of a feed before it is sanitized. They are applied in
+-- the order they were created (rowid).
+CREATE TABLE IF NOT EXISTS rewrite_rule (
+    id VARCHAR(36) PRIMARY KEY,
+
+    feed_id VARCHAR(36) NOT NULL,
+
+    -- What the rule does: regex_replace, remove_selector or strip_url_params
+    kind TEXT NOT NULL,
+
+    -- A regex (regex_replace), a CSS selector (remove_selector) or a comma separated list of query
+    -- params (strip_url_params). A param ending with * matches all the params with that prefix
+    pattern TEXT NOT NULL,
+
+    -- Replacement for the matches of the regex, only used by regex_replace. It can reference
+    -- capture groups ($1, $name)
+    replacement TEXT,
+
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
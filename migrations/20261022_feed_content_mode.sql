@@ -0,0 +1,7 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Where the content of the articles of a feed comes from:
+--  - feed: the content included in the feed, the web page is downloaded only if there is none
+--  - full: the web page of the article is always downloaded
+--  - auto: the web page is downloaded if the content included in the feed looks truncated
+ALTER TABLE feed ADD COLUMN content_mode TEXT NOT NULL DEFAULT 'feed';
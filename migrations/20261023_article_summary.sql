@@ -0,0 +1,4 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Plain text teaser of the article, taken from the summary (or the content) included in the feed
+ALTER TABLE article ADD COLUMN summary TEXT;
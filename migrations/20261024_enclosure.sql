@@ -0,0 +1,23 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Files attached to an article (RSS <enclosure>, Atom rel="enclosure" links), usually podcast
+-- episodes
+CREATE TABLE IF NOT EXISTS enclosure (
+    id VARCHAR(36) PRIMARY KEY,
+    article_id VARCHAR(36) NOT NULL,
+    feed_id VARCHAR(36) NOT NULL,
+    url TEXT NOT NULL,
+    mime_type TEXT,
+
+    -- Size in bytes, as declared by the feed
+    length INTEGER,
+
+    -- Duration in seconds (itunes:duration)
+    duration INTEGER,
+
+    -- If the enclosure was downloaded to the server, the url it is served from
+    cached_url TEXT,
+
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
@@ -0,0 +1,4 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Path of the cached grayscale thumbnail shown in the article lists
+ALTER TABLE article ADD COLUMN thumbnail_url TEXT;
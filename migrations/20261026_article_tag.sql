@@ -0,0 +1,17 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Tags of the articles, taken from the categories of the feed entries or added by the user
+CREATE TABLE IF NOT EXISTS article_tag (
+    article_id VARCHAR(36) NOT NULL,
+    feed_id VARCHAR(36) NOT NULL,
+    name TEXT NOT NULL,
+
+    -- 1 if the tag was added by the user, 0 if it came from the feed
+    user_defined INTEGER NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (article_id, name),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS article_tag_name ON article_tag(name);
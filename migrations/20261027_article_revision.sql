@@ -0,0 +1,26 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Hash of the title and content included in the feed, used to detect when a publisher updates an
+-- article
+ALTER TABLE article ADD COLUMN content_hash TEXT;
+
+-- 1 if the article was updated by the publisher and the user did not read the new version yet
+ALTER TABLE article ADD COLUMN has_update INTEGER NOT NULL DEFAULT 0;
+
+-- When the last update of the article was detected
+ALTER TABLE article ADD COLUMN updated_at TEXT;
+
+-- Previous versions of the updated articles
+CREATE TABLE IF NOT EXISTS article_revision (
+    id VARCHAR(36) PRIMARY KEY,
+    article_id VARCHAR(36) NOT NULL,
+    feed_id VARCHAR(36) NOT NULL,
+    title TEXT NOT NULL,
+
+    -- Path of the file with the previous content
+    content TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
@@ -0,0 +1,65 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- The guid of an article is only unique inside its feed: different feeds can use the same guids
+-- (e.g. simple numeric ids, or the full feed of a site and one of its category feeds). SQLite can
+-- not alter a constraint, so the table is rebuilt keeping its data
+BEGIN TRANSACTION;
+
+CREATE TABLE article_new (
+    id VARCHAR(36) PRIMARY KEY,
+
+    title TEXT NOT NULL,
+
+    author TEXT,
+
+    guid TEXT,
+    -- The complete link to the article
+    link TEXT,
+
+    -- Content of the article. Depending on the storage engine used can be a fs path or the content itself
+    -- For this implementation (SQLite) it is a path to the fs
+    content TEXT,
+
+    -- If the article  was read
+    read SMALLINT,
+
+    -- If the article  was exracted from an HTML instead of the content field in RSS
+    html_parsed SMALLINT,
+
+    -- This is the pub date. If for some reason the field is not available we put the date we parsed the article
+    last_updated DATE NOT NULL,
+
+    feed_id VARCHAR(16),
+
+    starred SMALLINT NOT NULL DEFAULT 0,
+    summary TEXT,
+    thumbnail_url TEXT,
+    content_hash TEXT,
+    has_update INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT,
+
+    -- The article of another feed this one is a copy of, if the duplicated articles detection is
+    -- enabled
+    duplicate_of VARCHAR(36),
+
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    UNIQUE(feed_id, guid)
+);
+
+INSERT INTO article_new (
+    id, title, author, guid, link, content, read, html_parsed, last_updated, feed_id, starred,
+    summary, thumbnail_url, content_hash, has_update, updated_at
+)
+SELECT
+    id, title, author, guid, link, content, read, html_parsed, last_updated, feed_id, starred,
+    summary, thumbnail_url, content_hash, has_update, updated_at
+FROM article;
+
+DROP TABLE article;
+
+ALTER TABLE article_new RENAME TO article;
+
+-- Used to find the copies of an article in other feeds
+CREATE INDEX IF NOT EXISTS article_link ON article(link);
+
+COMMIT;
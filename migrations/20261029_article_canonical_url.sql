@@ -0,0 +1,7 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Normalized link of the article (the canonical one if the web page declares it), used to find
+-- its copies in other feeds. It is not set for the articles saved before, their link is used
+ALTER TABLE article ADD COLUMN canonical_url TEXT;
+
+CREATE INDEX IF NOT EXISTS article_canonical_url ON article(canonical_url);
@@ -0,0 +1,5 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- 1 if the feed did not include a valid date for the article. Its date is then guessed from the
+-- moment it was found and its position in the feed
+ALTER TABLE article ADD COLUMN date_guessed INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,11 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Publication date of the article. From now on, last_updated is the date of the last update
+-- reported by the feed (Atom <updated>), which is only used to detect changes
+ALTER TABLE article ADD COLUMN published_at TEXT;
+
+-- When the article was found in the feed, minus its position in it, used to list the articles
+-- in the order of the feed
+ALTER TABLE article ADD COLUMN found_at TEXT;
+
+UPDATE article SET published_at = last_updated, found_at = last_updated;
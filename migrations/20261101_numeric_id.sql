@@ -0,0 +1,25 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Integer ids for the clients that do not support UUIDs (Fever, Google Reader). They only grow, so
+-- the clients can ask for the articles newer than the last one they have
+CREATE TABLE IF NOT EXISTS numeric_id_sequence (
+    name TEXT PRIMARY KEY,
+    last_id INTEGER NOT NULL
+);
+
+ALTER TABLE feed ADD COLUMN numeric_id INTEGER;
+ALTER TABLE article ADD COLUMN numeric_id INTEGER;
+ALTER TABLE category ADD COLUMN numeric_id INTEGER;
+
+UPDATE feed SET numeric_id = rowid;
+UPDATE article SET numeric_id = rowid;
+UPDATE category SET numeric_id = rowid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_feed_numeric_id ON feed(numeric_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_article_numeric_id ON article(numeric_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_category_numeric_id ON category(numeric_id);
+
+INSERT INTO numeric_id_sequence (name, last_id) VALUES
+    ('feed', (SELECT COALESCE(MAX(numeric_id), 0) FROM feed)),
+    ('article', (SELECT COALESCE(MAX(numeric_id), 0) FROM article)),
+    ('category', (SELECT COALESCE(MAX(numeric_id), 0) FROM category));
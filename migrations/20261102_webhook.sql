@@ -0,0 +1,49 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Outgoing webhooks, called when new articles are found
+CREATE TABLE IF NOT EXISTS webhook (
+    id VARCHAR(36) PRIMARY KEY,
+
+    url TEXT NOT NULL,
+
+    -- Only the new articles of this feed are sent. If it is NULL, all the feeds are sent. The
+    -- webhook is deleted with the feed
+    feed_id VARCHAR(36),
+
+    -- Only the new articles of the feeds of this category are sent. If it is NULL, all the
+    -- categories are sent. The webhook is deleted with the category
+    category_id VARCHAR(36),
+
+    -- If it is set, the payload is signed with HMAC-SHA256 using it as the key
+    secret TEXT,
+
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES category(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+-- Queue (and log) of the calls to the webhooks. The failed calls are retried later
+CREATE TABLE IF NOT EXISTS webhook_delivery (
+    id VARCHAR(36) PRIMARY KEY,
+    webhook_id VARCHAR(36) NOT NULL,
+
+    -- JSON body sent to the webhook
+    payload TEXT NOT NULL,
+
+    -- pending, delivered or failed (when it ran out of attempts)
+    status TEXT NOT NULL,
+
+    attempts INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL,
+    next_attempt_at DATETIME NOT NULL,
+    last_attempt_at DATETIME,
+
+    -- HTTP status code of the response of the last attempt, if any
+    response_status INTEGER,
+
+    -- Error of the last attempt, if any
+    error TEXT,
+
+    FOREIGN KEY (webhook_id) REFERENCES webhook(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_status ON webhook_delivery(status, next_attempt_at);
@@ -0,0 +1,27 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- WebSub subscriptions of the feeds that advertise a hub. While a subscription is active the hub
+-- pushes the updates of the feed and it is not polled
+CREATE TABLE IF NOT EXISTS websub_subscription (
+    feed_id VARCHAR(36) PRIMARY KEY,
+
+    hub_url TEXT NOT NULL,
+
+    -- Url of the feed the subscription is for (its rel="self" link)
+    topic_url TEXT NOT NULL,
+
+    -- Key the hub uses to sign the pushed content
+    secret TEXT NOT NULL,
+
+    -- discovered (not requested yet), requested (waiting for the hub to verify it), active or
+    -- denied (by the hub)
+    status TEXT NOT NULL,
+
+    -- When the hub stops pushing the updates if the subscription is not renewed
+    lease_expires_at DATETIME,
+
+    -- When the subscription was last requested to the hub
+    requested_at DATETIME,
+
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
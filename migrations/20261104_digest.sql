@@ -0,0 +1,15 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Editions of the daily digest. The books are saved as EPUB files in the digests directory
+CREATE TABLE IF NOT EXISTS digest (
+    id VARCHAR(36) PRIMARY KEY,
+
+    title TEXT NOT NULL,
+
+    created_at DATETIME NOT NULL,
+
+    article_count INTEGER NOT NULL,
+
+    -- Path of the EPUB file
+    file_path TEXT NOT NULL
+);
@@ -0,0 +1,26 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Outbox of the documents sent to the e-reader by email. The attachments are saved as files in the
+-- outbox directory until they are sent, and the failed emails are retried later
+CREATE TABLE IF NOT EXISTS email_delivery (
+    id VARCHAR(36) PRIMARY KEY,
+
+    -- Subject of the email, the title of the document
+    title TEXT NOT NULL,
+
+    -- Path of the attached EPUB file
+    file_path TEXT NOT NULL,
+
+    -- pending, delivered or failed (when it ran out of attempts)
+    status TEXT NOT NULL,
+
+    attempts INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL,
+    next_attempt_at DATETIME NOT NULL,
+    last_attempt_at DATETIME,
+
+    -- Error of the last attempt, if any
+    error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_delivery_status ON email_delivery(status, next_attempt_at);
@@ -0,0 +1,21 @@
+-- SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+-- SPDX-License-Identifier: AGPL-3.0-only
+-- Record of the articles saved to read-it-later services
+CREATE TABLE IF NOT EXISTS saved_article (
+    id VARCHAR(36) PRIMARY KEY,
+    article_id VARCHAR(36) NOT NULL,
+    feed_id VARCHAR(36) NOT NULL,
+
+    -- Service the article was saved to: wallabag or endpoint
+    target TEXT NOT NULL,
+
+    saved_at TEXT NOT NULL,
+
+    -- Url of the article in the service, if it is known
+    url TEXT,
+
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (feed_id) REFERENCES feed(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_article_article_id ON saved_article(article_id);
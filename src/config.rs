@@ -30,6 +30,45 @@ pub struct Config {
     /// How many minutes we should wait before checking the feed for new articles
     #[serde(default = "Config::minutes_to_check_for_updates")]
     pub minutes_to_check_for_updates: u16,
+
+    /// Minimum length (in characters of text) of the content included in a feed to not consider
+    /// it truncated. Only used by the feeds in "auto" content mode
+    #[serde(default = "Config::min_feed_content_length")]
+    pub min_feed_content_length: usize,
+
+    /// Maximum size (in bytes) of the audio and PDF enclosures to download to the server so they
+    /// are available offline. If it is None, enclosures are not downloaded
+    #[serde(default)]
+    pub max_enclosure_size_to_cache: Option<u64>,
+
+    /// If true, an article that was already read is marked as unread again when the publisher
+    /// updates it
+    #[serde(default)]
+    pub mark_updated_articles_as_unread: bool,
+
+    /// If true, a new article with the same link as one of another feed is linked to it as a
+    /// copy, sharing its read status
+    #[serde(default)]
+    pub detect_duplicate_articles: bool,
+
+    /// Credentials of the third-party reader apps (Fever and Google Reader APIs). If any of them
+    /// is not set, the apps are not able to log in
+    #[serde(default)]
+    pub api_username: Option<String>,
+
+    #[serde(default)]
+    pub api_password: Option<String>,
+
+    /// Token required to read the exported feeds (`?token=...`). If it is not set, they are
+    /// public
+    #[serde(default)]
+    pub export_token: Option<String>,
+
+    /// Url the app is reachable at from the internet (for example, `https://reader.example.com`),
+    /// used as the callback of the WebSub subscriptions. If it is not set, the feeds are always
+    /// polled
+    #[serde(default)]
+    pub public_url: Option<String>,
 }
 
 impl Config {
@@ -69,4 +108,8 @@ impl Config {
     fn minutes_to_check_for_updates() -> u16 {
         120
     }
+
+    fn min_feed_content_length() -> usize {
+        500
+    }
 }
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::models::feed::Feed;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct AddFeedData {
+    pub url: String,
+
+    #[serde(default)]
+    pub category_id: Option<Uuid>,
+}
+
+/// Adds a feed and returns it
+pub async fn add_feed<S>(
+    State(state): State<S>,
+    Json(data): Json<AddFeedData>,
+) -> Result<(StatusCode, Json<Feed>), JsonApiError>
+where
+    S: AppState,
+{
+    let url = Url::try_from(data.url.as_str()).map_err(JsonApiError::bad_request)?;
+    let feed = state.feed_service().add_feed(url, data.category_id).await?;
+
+    Ok((StatusCode::CREATED, Json(feed)))
+}
@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+pub async fn delete_feed<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+) -> Result<StatusCode, JsonApiError>
+where
+    S: AppState,
+{
+    state.feed_service().delete_feed(feed_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::{ApiArticle, JsonApiError};
+use crate::models::enclosure::Enclosure;
+use crate::services::feed::FeedService;
+use crate::services::tag::TagService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct ArticleDetails {
+    #[serde(flatten)]
+    pub article: ApiArticle,
+    /// Processed HTML content of the article
+    pub html: String,
+    pub tags: Vec<String>,
+    pub enclosures: Vec<Enclosure>,
+}
+
+/// Returns an article with its content, downloading it if needed. Unlike the article page, it does
+/// not mark the article as read
+pub async fn get_article<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ArticleDetails>, JsonApiError>
+where
+    S: AppState,
+{
+    let (article, html) = state
+        .feed_service()
+        .get_item_content(feed_id, article_id)
+        .await?;
+
+    let tags = state
+        .tag_service()
+        .get_article_tags(article_id)
+        .await?
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+
+    let enclosures = state
+        .feed_service()
+        .get_article_enclosures(article_id)
+        .await?;
+
+    Ok(Json(ArticleDetails {
+        article: article.into(),
+        html,
+        tags,
+        enclosures,
+    }))
+}
@@ -0,0 +1,21 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::models::persisted_config::PersistedConfig;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+
+pub async fn get_config<S>(State(state): State<S>) -> Result<Json<PersistedConfig>, JsonApiError>
+where
+    S: AppState,
+{
+    Ok(Json(
+        state
+            .persisted_config_service()
+            .get_configuration()
+            .await?
+            .without_secrets(),
+    ))
+}
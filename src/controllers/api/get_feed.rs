@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::models::feed::Feed;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+use uuid::Uuid;
+
+pub async fn get_feed<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+) -> Result<Json<Feed>, JsonApiError>
+where
+    S: AppState,
+{
+    let feed = state
+        .feed_service()
+        .get_feed(feed_id)
+        .await?
+        .ok_or_else(|| JsonApiError::not_found(format!("the feed {feed_id} was not found")))?;
+
+    Ok(Json(feed))
+}
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashSet;
+
+use crate::controllers::api::{ApiArticle, JsonApiError};
+use crate::services::feed::FeedService;
+use crate::services::tag::TagService;
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct ArticleFilters {
+    pub read: Option<bool>,
+    pub starred: Option<bool>,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+/// Returns the articles of a feed, the most recent first, checking it for new ones like the
+/// article list does
+pub async fn get_feed_articles<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    Query(filters): Query<ArticleFilters>,
+) -> Result<Json<Vec<ApiArticle>>, JsonApiError>
+where
+    S: AppState,
+{
+    let (_, articles) = state.feed_service().get_channel(feed_id).await?;
+
+    let tagged_articles: Option<HashSet<Uuid>> = match &filters.tag {
+        Some(tag) => Some(
+            state
+                .tag_service()
+                .get_tag_articles(tag)
+                .await?
+                .into_iter()
+                .map(|a| a.id)
+                .collect(),
+        ),
+        None => None,
+    };
+
+    let articles = articles
+        .into_iter()
+        .filter(|a| filters.read.is_none_or(|read| a.read == read))
+        .filter(|a| filters.starred.is_none_or(|starred| a.starred == starred))
+        .filter(|a| tagged_articles.as_ref().is_none_or(|ids| ids.contains(&a.id)))
+        .skip(filters.offset)
+        .take(filters.limit.unwrap_or(usize::MAX))
+        .map(ApiArticle::from)
+        .collect();
+
+    Ok(Json(articles))
+}
@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::models::feed::Feed;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+
+pub async fn get_feed_list<S>(State(state): State<S>) -> Result<Json<Vec<Feed>>, JsonApiError>
+where
+    S: AppState,
+{
+    Ok(Json(state.feed_service().get_feed_list().await?))
+}
@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! JSON API (`/api/v1`) to automate the reader with scripts. It mirrors the feed and configuration
+//! services, and its errors are JSON objects instead of the HTML error pages
+mod add_feed;
+mod delete_feed;
+mod get_article;
+mod get_config;
+mod get_feed;
+mod get_feed_articles;
+mod get_feed_list;
+mod not_found;
+mod update_article;
+mod update_config;
+
+pub use add_feed::add_feed;
+pub use delete_feed::delete_feed;
+pub use get_article::get_article;
+pub use get_config::get_config;
+pub use get_feed::get_feed;
+pub use get_feed_articles::get_feed_articles;
+pub use get_feed_list::get_feed_list;
+pub use not_found::not_found;
+pub use update_article::update_article;
+pub use update_config::update_config;
+
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::ApiError;
+use crate::models::article::Article;
+
+/// Body of the error responses of the JSON API
+#[derive(Serialize)]
+pub struct ErrorBody {
+    pub error: String,
+}
+
+impl ErrorBody {
+    pub fn response(status_code: StatusCode, error: impl Into<String>) -> Response {
+        (status_code, Json(Self { error: error.into() })).into_response()
+    }
+}
+
+/// Article as returned by the JSON API. It leaves out the fields that only make sense inside the
+/// reader, like the path of the saved content or the data used to detect updates and copies
+#[derive(Serialize)]
+pub struct ApiArticle {
+    pub id: Uuid,
+    pub feed_id: Uuid,
+    pub title: String,
+    pub author: Option<String>,
+    pub guid: String,
+    pub link: String,
+    pub summary: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub read: bool,
+    pub starred: bool,
+    pub published_at: DateTime<Utc>,
+    pub has_update: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub duplicate_of: Option<Uuid>,
+}
+
+impl From<Article> for ApiArticle {
+    fn from(article: Article) -> Self {
+        Self {
+            id: article.id,
+            feed_id: article.feed_id,
+            title: article.title,
+            author: article.author,
+            guid: article.guid,
+            link: article.link,
+            summary: article.summary,
+            thumbnail_url: article.thumbnail_url,
+            read: article.read,
+            starred: article.starred,
+            published_at: article.published_at,
+            has_update: article.has_update,
+            updated_at: article.updated_at,
+            duplicate_of: article.duplicate_of,
+        }
+    }
+}
+
+/// Same as [`ApiError`], but rendered as an [`ErrorBody`]. Any error that can be converted to an
+/// [`ApiError`] can be converted to this one, so the handlers can use `?` with the service errors
+pub(crate) struct JsonApiError(ApiError);
+
+impl<E> From<E> for JsonApiError
+where
+    E: Into<ApiError>,
+{
+    fn from(error: E) -> Self {
+        Self(error.into())
+    }
+}
+
+impl IntoResponse for JsonApiError {
+    fn into_response(self) -> Response {
+        tracing::error!("{}", &self.0.original_error);
+        ErrorBody::response(self.0.status_code, self.0.original_error.to_string())
+    }
+}
+
+impl JsonApiError {
+    pub fn bad_request(error: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self(ApiError {
+            original_error: error.into(),
+            status_code: StatusCode::BAD_REQUEST,
+        })
+    }
+
+    pub fn not_found(error: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self(ApiError {
+            original_error: error.into(),
+            status_code: StatusCode::NOT_FOUND,
+        })
+    }
+
+    pub fn unauthorized(error: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self(ApiError {
+            original_error: error.into(),
+            status_code: StatusCode::UNAUTHORIZED,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use axum::http::Uri;
+use axum::response::Response;
+use reqwest::StatusCode;
+
+use super::ErrorBody;
+
+pub async fn not_found(uri: Uri) -> Response {
+    ErrorBody::response(StatusCode::NOT_FOUND, format!("{uri} was not found"))
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct ArticleUpdate {
+    pub read: Option<bool>,
+    pub starred: Option<bool>,
+}
+
+/// Marks an article as read or unread and stars or unstars it. Omitted fields are left unchanged
+pub async fn update_article<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id)): Path<(Uuid, Uuid)>,
+    Json(update): Json<ArticleUpdate>,
+) -> Result<StatusCode, JsonApiError>
+where
+    S: AppState,
+{
+    let feed_service = state.feed_service();
+    if feed_service.get_article(feed_id, article_id).await?.is_none() {
+        return Err(JsonApiError::not_found(format!(
+            "the article {article_id} of feed {feed_id} was not found"
+        )));
+    }
+
+    match update.read {
+        Some(true) => feed_service.mark_article_as_read(feed_id, article_id).await?,
+        Some(false) => feed_service.mark_article_as_unread(feed_id, article_id).await?,
+        None => {}
+    }
+
+    if let Some(starred) = update.starred {
+        feed_service
+            .set_article_starred(feed_id, article_id, starred)
+            .await?;
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
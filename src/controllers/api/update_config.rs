@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::api::JsonApiError;
+use crate::models::persisted_config::{ArticleSort, DigestConfig, PersistedConfig};
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigUpdate {
+    pub dark_theme: Option<bool>,
+    pub zoom: Option<f64>,
+    pub toolbar_position_left: Option<bool>,
+    pub hide_article_header: Option<bool>,
+    pub dont_invert_images: Option<bool>,
+    pub show_thumbnails: Option<bool>,
+    pub article_sort: Option<ArticleSort>,
+    pub digest: Option<DigestConfig>,
+}
+
+/// Updates the given settings, leaving the omitted ones unchanged, and returns the configuration
+pub async fn update_config<S>(
+    State(state): State<S>,
+    Json(update): Json<ConfigUpdate>,
+) -> Result<Json<PersistedConfig>, JsonApiError>
+where
+    S: AppState,
+{
+    // Every setting is validated before saving any, so an invalid one leaves all unchanged
+    if let Some(zoom) = update.zoom.filter(|zoom| !zoom.is_finite() || *zoom <= 0.0) {
+        return Err(JsonApiError::bad_request(format!("invalid zoom {zoom}")));
+    }
+    if update.digest.as_ref().is_some_and(|digest| digest.hour >= 24 || digest.editions == 0) {
+        return Err(JsonApiError::bad_request("invalid digest hour or editions"));
+    }
+
+    let service = state.persisted_config_service();
+
+    if let Some(zoom) = update.zoom {
+        service.set_zoom(zoom).await?;
+    }
+    if let Some(value) = update.dark_theme {
+        service.set_dark_theme(value).await?;
+    }
+    if let Some(value) = update.toolbar_position_left {
+        service.set_toolbar_position_left(value).await?;
+    }
+    if let Some(value) = update.hide_article_header {
+        service.set_hide_article_header(value).await?;
+    }
+    if let Some(value) = update.dont_invert_images {
+        service.set_dont_invert_images(value).await?;
+    }
+    if let Some(value) = update.show_thumbnails {
+        service.set_show_thumbnails(value).await?;
+    }
+    if let Some(value) = update.article_sort {
+        service.set_article_sort(value).await?;
+    }
+    if let Some(value) = update.digest {
+        service.set_digest(value).await?;
+    }
+
+    Ok(Json(service.get_configuration().await?.without_secrets()))
+}
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::category::CategoryService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct CategoryForm {
+    pub name: String,
+}
+
+pub async fn add_category<S>(
+    State(state): State<S>,
+    Form(category): Form<CategoryForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state.category_service().add_category(&category.name).await?;
+    Ok(Redirect::to("/category"))
+}
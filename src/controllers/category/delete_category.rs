@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::category::CategoryService;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    response::Redirect,
+};
+use uuid::Uuid;
+
+pub async fn delete_category<S: AppState>(
+    State(state): State<S>,
+    Path(category_id): Path<Uuid>,
+) -> Result<Redirect, ApiError> {
+    state.category_service().delete_category(category_id).await?;
+
+    Ok(Redirect::to("/category"))
+}
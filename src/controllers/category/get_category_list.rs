@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_CATEGORY_LIST};
+use crate::state::AppState;
+use axum::extract::State;
+use minijinja::context;
+
+pub async fn get_category_list<S>(State(state): State<S>) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let categories = state.category_service().get_category_list().await?;
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_CATEGORY_LIST,
+                context! { categories => categories },
+            )
+            .await?,
+    ))
+}
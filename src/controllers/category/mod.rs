@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod add_category;
+mod delete_category;
+mod get_category_article_list;
+mod get_category_list;
+mod rename_category;
+
+pub use add_category::add_category;
+pub use delete_category::delete_category;
+pub use get_category_article_list::get_category_article_list;
+pub use get_category_list::get_category_list;
+pub use rename_category::rename_category;
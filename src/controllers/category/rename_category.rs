@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::category::CategoryService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use axum::Form;
+use uuid::Uuid;
+
+use super::add_category::CategoryForm;
+
+pub async fn rename_category<S>(
+    State(state): State<S>,
+    Path(category_id): Path<Uuid>,
+    Form(category): Form<CategoryForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .category_service()
+        .rename_category(category_id, &category.name)
+        .await?;
+
+    Ok(Redirect::to("/category"))
+}
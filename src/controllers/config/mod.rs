@@ -1,15 +1,27 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 mod get_config;
+mod set_article_sort;
 mod set_dark_theme;
+mod set_digest;
 mod set_dont_invert_images;
 mod set_hide_article_header;
+mod set_save_endpoint;
+mod set_show_thumbnails;
+mod set_smtp;
 mod set_toolbar_position_left;
+mod set_wallabag;
 mod set_zoom;
 
 pub use get_config::get_config;
+pub use set_article_sort::set_article_sort;
 pub use set_dark_theme::set_dark_theme;
+pub use set_digest::set_digest;
 pub use set_dont_invert_images::set_dont_invert_images;
 pub use set_hide_article_header::set_hide_article_header;
+pub use set_save_endpoint::set_save_endpoint;
+pub use set_show_thumbnails::set_show_thumbnails;
+pub use set_smtp::set_smtp;
 pub use set_toolbar_position_left::set_toolbar_position_left;
+pub use set_wallabag::set_wallabag;
 pub use set_zoom::set_zoom;
@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::models::persisted_config::ArticleSort;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::Form;
+use axum::extract::State;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ArticleSortData {
+    pub article_sort: ArticleSort,
+}
+
+pub async fn set_article_sort<S>(
+    State(state): State<S>,
+    Form(article_sort_data): Form<ArticleSortData>,
+) -> Result<(), ApiError>
+where
+    S: AppState,
+{
+    state
+        .persisted_config_service()
+        .set_article_sort(article_sort_data.article_sort)
+        .await?;
+
+    Ok(())
+}
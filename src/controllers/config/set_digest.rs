@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::models::persisted_config::DigestConfig;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+/// Saves the settings of the digest. The form is read as a list of pairs, as the feeds and
+/// categories are sent as repeated fields and the unchecked checkboxes are not sent
+pub async fn set_digest<S>(
+    State(state): State<S>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    let invalid = |field: &str, value: &str| ApiError {
+        original_error: format!("invalid digest {field}: {value}").into(),
+        status_code: StatusCode::BAD_REQUEST,
+    };
+
+    let mut digest = DigestConfig::default();
+    for (field, value) in &fields {
+        match field.as_str() {
+            "enabled" => digest.enabled = true,
+            "mark_as_read" => digest.mark_as_read = true,
+            "hour" => {
+                digest.hour = value
+                    .parse()
+                    .ok()
+                    .filter(|hour| *hour < 24)
+                    .ok_or_else(|| invalid(field, value))?;
+            }
+            "editions" => {
+                digest.editions = value
+                    .parse()
+                    .ok()
+                    .filter(|editions| *editions > 0)
+                    .ok_or_else(|| invalid(field, value))?;
+            }
+            "feed_ids" => digest
+                .feed_ids
+                .push(Uuid::parse_str(value).map_err(|_| invalid(field, value))?),
+            "category_ids" => digest
+                .category_ids
+                .push(Uuid::parse_str(value).map_err(|_| invalid(field, value))?),
+            _ => {}
+        }
+    }
+
+    state.persisted_config_service().set_digest(digest).await?;
+
+    Ok(Redirect::to("/digests"))
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::models::persisted_config::SaveEndpointConfig;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct SaveEndpointForm {
+    #[serde(default)]
+    pub url: String,
+    /// The token is not shown in the form, so it is only changed when a new one is sent
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub token: Option<String>,
+}
+
+/// Saves the settings of the endpoint the urls of the articles are sent to
+pub async fn set_save_endpoint<S>(
+    State(state): State<S>,
+    Form(form): Form<SaveEndpointForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    let url = form.url.trim().to_owned();
+    if !url.is_empty() && !Url::parse(&url).is_ok_and(|url| url.scheme().starts_with("http")) {
+        return Err(ApiError {
+            original_error: format!("invalid endpoint url {url}").into(),
+            status_code: StatusCode::BAD_REQUEST,
+        });
+    }
+
+    let token = match form.token {
+        Some(token) => token,
+        None => {
+            state
+                .persisted_config_service()
+                .get_configuration()
+                .await?
+                .save_endpoint
+                .token
+        }
+    };
+
+    state
+        .persisted_config_service()
+        .set_save_endpoint(SaveEndpointConfig { url, token })
+        .await?;
+
+    Ok(Redirect::to("/save_to"))
+}
@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::Form;
+use axum::extract::State;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ShowThumbnailsData {
+    pub show_thumbnails: bool,
+}
+
+pub async fn set_show_thumbnails<S>(
+    State(state): State<S>,
+    Form(show_thumbnails_data): Form<ShowThumbnailsData>,
+) -> Result<(), ApiError>
+where
+    S: AppState,
+{
+    state
+        .persisted_config_service()
+        .set_show_thumbnails(show_thumbnails_data.show_thumbnails)
+        .await?;
+
+    Ok(())
+}
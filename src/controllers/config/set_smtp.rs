@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::models::persisted_config::{SmtpConfig, SmtpSecurity};
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct SmtpForm {
+    #[serde(default)]
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    #[serde(default)]
+    pub username: String,
+    /// The password is not shown in the form, so it is only changed when a new one is sent
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub from: String,
+    #[serde(default)]
+    pub recipient: String,
+}
+
+/// Saves the settings of the SMTP server used to send documents to the device
+pub async fn set_smtp<S>(
+    State(state): State<S>,
+    Form(form): Form<SmtpForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    let password = match form.password {
+        Some(password) => password,
+        None => {
+            state
+                .persisted_config_service()
+                .get_configuration()
+                .await?
+                .smtp
+                .password
+        }
+    };
+
+    state
+        .persisted_config_service()
+        .set_smtp(SmtpConfig {
+            host: form.host.trim().to_owned(),
+            port: form.port,
+            security: form.security,
+            username: form.username.trim().to_owned(),
+            password,
+            from: form.from.trim().to_owned(),
+            recipient: form.recipient.trim().to_owned(),
+        })
+        .await?;
+
+    Ok(Redirect::to("/outbox"))
+}
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::models::persisted_config::WallabagConfig;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct WallabagForm {
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub client_id: String,
+    /// The secrets are not shown in the form, so they are only changed when new ones are sent
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub client_secret: Option<String>,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub password: Option<String>,
+}
+
+/// Saves the settings of the Wallabag server the articles are saved to
+pub async fn set_wallabag<S>(
+    State(state): State<S>,
+    Form(form): Form<WallabagForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    let current = state
+        .persisted_config_service()
+        .get_configuration()
+        .await?
+        .wallabag;
+
+    state
+        .persisted_config_service()
+        .set_wallabag(WallabagConfig {
+            url: form.url.trim().to_owned(),
+            client_id: form.client_id.trim().to_owned(),
+            client_secret: form.client_secret.unwrap_or(current.client_secret),
+            username: form.username.trim().to_owned(),
+            password: form.password.unwrap_or(current.password),
+        })
+        .await?;
+
+    Ok(Redirect::to("/save_to"))
+}
@@ -0,0 +1,14 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::digest::DigestService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+
+/// Creates a digest now, without waiting for its scheduled time
+pub async fn create_digest<S: AppState>(State(state): State<S>) -> Result<Redirect, ApiError> {
+    state.digest_service().create_digest().await?;
+
+    Ok(Redirect::to("/digests"))
+}
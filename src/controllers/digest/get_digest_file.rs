@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::providers::epub::EPUB_MIME_TYPE;
+use crate::services::digest::DigestService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+use uuid::Uuid;
+
+/// Downloads the EPUB file of a digest
+pub async fn get_digest_file<S>(
+    State(state): State<S>,
+    Path(digest_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    S: AppState,
+{
+    let (digest, epub) = state.digest_service().get_digest_file(digest_id).await?;
+
+    let file_name = digest
+        .title
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, EPUB_MIME_TYPE.to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}.epub\""),
+            ),
+        ],
+        epub,
+    ))
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
+use crate::services::digest::DigestService;
+use crate::services::feed::FeedService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_DIGESTS};
+use crate::state::AppState;
+use crate::view_models::digest_item::DigestItem;
+use axum::extract::State;
+use minijinja::context;
+
+/// Lists the digests with their settings
+pub async fn get_digests<S>(State(state): State<S>) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let digests: Vec<DigestItem> = state
+        .digest_service()
+        .get_digest_list()
+        .await?
+        .into_iter()
+        .map(DigestItem::from)
+        .collect();
+    let feeds = state.feed_service().get_feed_list().await?;
+    let categories = state.category_service().get_category_list().await?;
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_DIGESTS,
+                context! {
+                    digests => digests,
+                    feeds => feeds,
+                    categories => categories,
+                    hours => (0..24).collect::<Vec<u32>>(),
+                },
+            )
+            .await?,
+    ))
+}
@@ -0,0 +1,11 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod create_digest;
+mod delete_digest;
+mod get_digest_file;
+mod get_digests;
+
+pub use create_digest::create_digest;
+pub use delete_digest::delete_digest;
+pub use get_digest_file::get_digest_file;
+pub use get_digests::get_digests;
@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::email::EmailService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_OUTBOX};
+use crate::state::AppState;
+use crate::view_models::email_delivery_item::EmailDeliveryItem;
+use axum::extract::State;
+use minijinja::context;
+
+/// Shows the settings of the SMTP server and the emails sent to the device
+pub async fn get_outbox<S>(State(state): State<S>) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let deliveries: Vec<EmailDeliveryItem> = state
+        .email_service()
+        .get_delivery_log()
+        .await?
+        .into_iter()
+        .map(EmailDeliveryItem::from)
+        .collect();
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_OUTBOX,
+                context! {
+                    deliveries => deliveries,
+                },
+            )
+            .await?,
+    ))
+}
@@ -0,0 +1,9 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod get_outbox;
+mod send_article;
+mod send_digest;
+
+pub use get_outbox::get_outbox;
+pub use send_article::send_article;
+pub use send_digest::send_digest;
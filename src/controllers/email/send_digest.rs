@@ -0,0 +1,21 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::email::EmailService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use uuid::Uuid;
+
+/// Queues the digest to be sent to the device by email
+pub async fn send_digest<S>(
+    State(state): State<S>,
+    Path(digest_id): Path<Uuid>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state.email_service().send_digest(digest_id).await?;
+
+    Ok(Redirect::to("/outbox"))
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::ExportParams;
+use crate::controllers::{absolute_article_urls, get_base_url, ApiError};
+use crate::services::export::ExportService;
+use crate::state::AppState;
+use crate::view_models::atom_export::atom_feed;
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, Uri};
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+
+/// Atom feed of the articles that match the query parameters (for example, `?starred=true`),
+/// with their content and absolute image urls, so they can be re-published or read in other apps
+pub async fn get_atom_export<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    uri: Uri,
+    Query(params): Query<ExportParams>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    S: AppState,
+{
+    let service = state.export_service();
+    if !service.is_authorized(params.token.as_deref()) {
+        return Err(ApiError {
+            original_error: "invalid export token".into(),
+            status_code: StatusCode::UNAUTHORIZED,
+        });
+    }
+
+    let mut exported = service.get_articles(&params.into()).await?;
+
+    let base_url = get_base_url(&headers);
+    for (_, _, html) in &mut exported.articles {
+        *html = absolute_article_urls(html, &base_url);
+    }
+
+    // The id of the feed is its url without the token, so it does not change with it. The token
+    // is not included in the self link either, as it is published with the feed
+    let query_string = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|param| !param.is_empty() && !param.starts_with("token="))
+        .collect::<Vec<_>>()
+        .join("&");
+    let id = format!("{base_url}{}?{query_string}", uri.path());
+    let self_url = id.clone();
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+        atom_feed(exported, id, self_url).to_string(),
+    ))
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::ExportParams;
+use crate::controllers::ApiError;
+use crate::providers::epub::EPUB_MIME_TYPE;
+use crate::services::export::ExportService;
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+
+/// EPUB book of the articles that match the query parameters (the same ones of the Atom export),
+/// to read them offline in the e-reader
+pub async fn get_epub_export<S>(
+    State(state): State<S>,
+    Query(params): Query<ExportParams>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    S: AppState,
+{
+    let service = state.export_service();
+    if !service.is_authorized(params.token.as_deref()) {
+        return Err(ApiError {
+            original_error: "invalid export token".into(),
+            status_code: StatusCode::UNAUTHORIZED,
+        });
+    }
+
+    let (title, epub) = service.get_epub(&params.into()).await?;
+
+    let file_name = title
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, EPUB_MIME_TYPE.to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}.epub\""),
+            ),
+        ],
+        epub,
+    ))
+}
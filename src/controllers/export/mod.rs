@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod get_atom_export;
+mod get_epub_export;
+
+pub use get_atom_export::get_atom_export;
+pub use get_epub_export::get_epub_export;
+
+use chrono::{NaiveDate, TimeDelta};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::controllers::empty_string_as_none;
+use crate::services::export::ExportQuery;
+
+#[derive(Deserialize, Debug)]
+pub struct ExportParams {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub starred: Option<bool>,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub read: Option<bool>,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub tag: Option<String>,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub feed_id: Option<Uuid>,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub category_id: Option<Uuid>,
+
+    /// First day of publication of the articles (`YYYY-MM-DD`)
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub from: Option<NaiveDate>,
+
+    /// Last day of publication of the articles (`YYYY-MM-DD`)
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub to: Option<NaiveDate>,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub limit: Option<usize>,
+
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl From<ExportParams> for ExportQuery {
+    fn from(params: ExportParams) -> Self {
+        // The days are inclusive, while the filters of the query are not
+        let start_of_day = |date: NaiveDate| date.and_time(Default::default()).and_utc();
+
+        ExportQuery {
+            starred: params.starred,
+            read: params.read,
+            tag: params.tag,
+            feed_id: params.feed_id,
+            category_id: params.category_id,
+            published_after: params.from.map(|from| start_of_day(from) - TimeDelta::seconds(1)),
+            published_before: params.to.map(|to| start_of_day(to) + TimeDelta::days(1)),
+            limit: params.limit,
+        }
+    }
+}
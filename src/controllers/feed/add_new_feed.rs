@@ -1,6 +1,6 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
-use crate::controllers::ApiError;
+use crate::controllers::{empty_string_as_none, ApiError};
 use crate::services::feed::FeedService;
 use crate::state::AppState;
 use axum::extract::State;
@@ -8,10 +8,14 @@ use axum::response::Redirect;
 use axum::Form;
 use reqwest::Url;
 use serde::Deserialize;
+use uuid::Uuid;
 
 #[derive(Deserialize, Debug)]
 pub struct FeedAddForm {
     pub url: String,
+
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub category_id: Option<Uuid>,
 }
 
 pub async fn add_new_feed<S>(
@@ -22,6 +26,9 @@ where
     S: AppState,
 {
     let url = Url::try_from(rss_url.url.as_str()).map_err(|e| anyhow::anyhow!("{e}"))?;
-    state.feed_service().add_feed(url).await?;
+    state
+        .feed_service()
+        .add_feed(url, rss_url.category_id)
+        .await?;
     Ok(Redirect::to("/"))
 }
@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
 use crate::services::templates::{TemplateService, TEMPLATE_NAME_FEED_ADD};
 use crate::state::AppState;
 use axum::extract::State;
@@ -10,10 +11,12 @@ pub async fn add_new_feed_form<S>(State(state): State<S>) -> Result<HtmlResponse
 where
     S: AppState,
 {
+    let categories = state.category_service().get_category_list().await?;
+
     Ok(HtmlResponse::new(
         state
             .template_service()
-            .render_template(TEMPLATE_NAME_FEED_ADD, context! {})
+            .render_template(TEMPLATE_NAME_FEED_ADD, context! { categories => categories })
             .await?,
     ))
 }
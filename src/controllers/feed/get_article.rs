@@ -3,9 +3,15 @@
 use crate::controllers::ApiError;
 use crate::controllers::HtmlResponse;
 use crate::services::feed::FeedService;
+use crate::services::revision::RevisionService;
+use crate::services::save_to::SaveToService;
+use crate::services::tag::TagService;
 use crate::services::templates::TemplateService;
 use crate::services::templates::TEMPLATE_NAME_ARTICLE;
 use crate::state::AppState;
+use crate::view_models::article_revision_item::ArticleRevisionItem;
+use crate::view_models::enclosure_item::EnclosureItem;
+use crate::view_models::saved_article_item::SavedArticleItem;
 use axum::extract::Path;
 use axum::extract::State;
 use minijinja::context;
@@ -25,15 +31,50 @@ where
         .get_item_content(feed_id, article_id)
         .await?;
 
+    let enclosures: Vec<EnclosureItem> = state
+        .feed_service()
+        .get_article_enclosures(article_id)
+        .await?
+        .into_iter()
+        .map(EnclosureItem::from)
+        .collect();
+
+    let tags = state.tag_service().get_article_tags(article_id).await?;
+
+    let revisions: Vec<ArticleRevisionItem> = state
+        .revision_service()
+        .get_article_revisions(article_id)
+        .await?
+        .into_iter()
+        .map(ArticleRevisionItem::from)
+        .collect();
+
+    let saved_articles: Vec<SavedArticleItem> = state
+        .save_to_service()
+        .get_saved_article_list(article_id)
+        .await?
+        .into_iter()
+        .map(SavedArticleItem::from)
+        .collect();
+
     let rendered_article = state
         .template_service()
         .render_template(
             TEMPLATE_NAME_ARTICLE,
-            context! { feed => feed, article => content, article_data => article_data },
+            context! {
+                feed => feed,
+                article => content,
+                article_data => article_data,
+                enclosures => enclosures,
+                tags => tags,
+                revisions => revisions,
+                saved_articles => saved_articles,
+            },
         )
         .await?;
 
-    if !article_data.read {
+    // Reading an updated article also clears its badge
+    if !article_data.read || article_data.has_update {
         state
             .feed_service()
             .mark_article_as_read(feed_id, article_id)
@@ -1,7 +1,9 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::export::ExportService;
 use crate::services::feed::FeedService;
+use crate::services::persisted_config::PersistedConfigService;
 use crate::services::templates::{TemplateService, TEMPLATE_NAME_ARTICLE_LIST};
 use crate::state::AppState;
 use crate::view_models::article_list_item::ArticleListItem;
@@ -17,7 +19,10 @@ pub async fn get_article_list<S>(
 where
     S: AppState,
 {
-    let (feed, articles) = state.feed_service().get_channel(feed_id).await?;
+    let (feed, mut articles) = state.feed_service().get_channel(feed_id).await?;
+
+    let config = state.persisted_config_service().get_configuration().await?;
+    config.article_sort.sort(&mut articles);
 
     let articles: Vec<ArticleListItem> = articles.into_iter().map(ArticleListItem::from).collect();
 
@@ -25,7 +30,11 @@ where
         .template_service()
         .render_template(
             TEMPLATE_NAME_ARTICLE_LIST,
-            context! { feed => feed, articles => articles },
+            context! {
+                feed => feed,
+                articles => articles,
+                export_token => state.export_service().token(),
+            },
         )
         .await?;
 
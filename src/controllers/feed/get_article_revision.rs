@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::controllers::HtmlResponse;
+use crate::services::feed::FeedService;
+use crate::services::revision::RevisionService;
+use crate::services::templates::TemplateService;
+use crate::services::templates::TEMPLATE_NAME_ARTICLE;
+use crate::state::AppState;
+use crate::view_models::article_revision_item::ArticleRevisionItem;
+use axum::extract::Path;
+use axum::extract::State;
+use minijinja::context;
+use uuid::Uuid;
+
+/// Shows a previous version of an article, before the publisher updated it
+pub async fn get_article_revision<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id, revision_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let feed = state.feed_service().get_feed(feed_id).await?;
+
+    let (article_data, _) = state
+        .feed_service()
+        .get_item_content(feed_id, article_id)
+        .await?;
+
+    let (revision, content) = state
+        .revision_service()
+        .get_article_revision(feed_id, article_id, revision_id)
+        .await?;
+
+    let rendered_article = state
+        .template_service()
+        .render_template(
+            TEMPLATE_NAME_ARTICLE,
+            context! {
+                feed => feed,
+                article => content,
+                article_data => article_data,
+                revision => ArticleRevisionItem::from(revision),
+            },
+        )
+        .await?;
+
+    Ok(HtmlResponse::new(rendered_article))
+}
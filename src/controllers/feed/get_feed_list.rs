@@ -1,9 +1,11 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
 use crate::services::templates::TEMPLATE_NAME_FEED_LIST;
 use crate::services::{feed::FeedService, templates::TemplateService};
 use crate::state::AppState;
+use crate::view_models::feed_group::FeedGroup;
 use axum::extract::State;
 use minijinja::context;
 
@@ -12,10 +14,13 @@ where
     S: AppState,
 {
     let feeds = state.feed_service().get_feed_list().await?;
+    let categories = state.category_service().get_category_list().await?;
+
+    let groups = FeedGroup::group(categories, feeds);
 
     let rendered_html = state
         .template_service()
-        .render_template(TEMPLATE_NAME_FEED_LIST, context! { feeds => feeds })
+        .render_template(TEMPLATE_NAME_FEED_LIST, context! { groups => groups })
         .await?;
 
     Ok(HtmlResponse::new(rendered_html))
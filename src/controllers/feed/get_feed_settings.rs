@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
+use crate::services::feed::FeedService;
+use crate::services::rewrite_rule::RewriteRuleService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_FEED_SETTINGS};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use minijinja::context;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct FeedSettingsQuery {
+    /// Number of articles reprocessed, set when redirected after reprocessing the feed
+    pub reprocessed: Option<usize>,
+}
+
+pub async fn get_feed_settings<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    Query(query): Query<FeedSettingsQuery>,
+) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let feed = state
+        .feed_service()
+        .get_feed(feed_id)
+        .await?
+        .ok_or_else(|| ApiError {
+            original_error: anyhow::anyhow!("the feed {feed_id} was not found").into(),
+            status_code: StatusCode::NOT_FOUND,
+        })?;
+
+    let categories = state.category_service().get_category_list().await?;
+    let rewrite_rules = state
+        .rewrite_rule_service()
+        .get_feed_rewrite_rules(feed_id)
+        .await?;
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_FEED_SETTINGS,
+                context! {
+                    feed => feed,
+                    categories => categories,
+                    rewrite_rules => rewrite_rules,
+                    reprocessed => query.reprocessed,
+                },
+            )
+            .await?,
+    ))
+}
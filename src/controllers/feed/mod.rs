@@ -4,12 +4,24 @@ mod add_new_feed;
 mod add_new_feed_form;
 mod delete_feed;
 mod get_article;
+mod get_article_revision;
 mod get_article_list;
 mod get_feed_list;
+mod get_feed_settings;
+mod reload_article;
+mod reprocess_feed;
+mod set_article_starred;
+mod update_feed_settings;
 
 pub use add_new_feed::add_new_feed;
 pub use add_new_feed_form::add_new_feed_form;
 pub use delete_feed::delete_feed;
 pub use get_article::get_article;
+pub use get_article_revision::get_article_revision;
 pub use get_article_list::get_article_list;
 pub use get_feed_list::get_feed_list;
+pub use get_feed_settings::get_feed_settings;
+pub use reload_article::reload_article;
+pub use reprocess_feed::reprocess_feed;
+pub use set_article_starred::set_article_starred;
+pub use update_feed_settings::update_feed_settings;
@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use uuid::Uuid;
+
+pub async fn reload_article<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id)): Path<(Uuid, Uuid)>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .feed_service()
+        .reload_article(feed_id, article_id)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/article/{article_id}")))
+}
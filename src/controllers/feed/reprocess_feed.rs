@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use uuid::Uuid;
+
+pub async fn reprocess_feed<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    let reprocessed = state.feed_service().reprocess_feed(feed_id).await?;
+
+    Ok(Redirect::to(&format!(
+        "/feed/{feed_id}/settings?reprocessed={reprocessed}"
+    )))
+}
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct StarredData {
+    pub starred: bool,
+}
+
+pub async fn set_article_starred<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id)): Path<(Uuid, Uuid)>,
+    Form(starred_data): Form<StarredData>,
+) -> Result<(), ApiError>
+where
+    S: AppState,
+{
+    state
+        .feed_service()
+        .set_article_starred(feed_id, article_id, starred_data.starred)
+        .await?;
+
+    Ok(())
+}
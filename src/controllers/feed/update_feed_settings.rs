@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::models::feed::ContentMode;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct FeedSettingsForm {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub category_id: Option<Uuid>,
+    pub content_mode: ContentMode,
+}
+
+pub async fn update_feed_settings<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    Form(settings): Form<FeedSettingsForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .feed_service()
+        .update_feed_category(feed_id, settings.category_id)
+        .await?;
+
+    state
+        .feed_service()
+        .update_feed_content_mode(feed_id, settings.content_mode)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}")))
+}
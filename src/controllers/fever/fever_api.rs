@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
+use crate::controllers::api::JsonApiError;
+use crate::controllers::{absolute_article_urls, get_base_url, refresh_feeds_in_background};
+use crate::services::fever::{FeverService, ItemsQuery, MarkAs, MarkTarget};
+use crate::state::AppState;
+use crate::view_models::fever::{
+    join_ids, FeverFavicon, FeverFeed, FeverFeedsGroup, FeverGroup, FeverItem,
+};
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::{Form, Json};
+use chrono::DateTime;
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+const API_VERSION: u8 = 3;
+
+/// Fever API endpoint. The clients send the requested objects as query parameters (for example,
+/// `?api&feeds&groups`) and the api key and `mark` actions in the body, although some of them mix
+/// them up, so both are read
+pub async fn fever_api<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+    body: Option<Form<HashMap<String, String>>>,
+) -> Result<Json<Map<String, Value>>, JsonApiError>
+where
+    S: AppState,
+{
+    let mut params = query;
+    if let Some(Form(body)) = body {
+        params.extend(body);
+    }
+
+    let service = state.fever_service();
+    let mut response = Map::new();
+    response.insert("api_version".to_owned(), json!(API_VERSION));
+
+    let authenticated = params
+        .get("api_key")
+        .is_some_and(|api_key| service.is_authenticated(api_key));
+    response.insert("auth".to_owned(), json!(u8::from(authenticated)));
+    if !authenticated {
+        return Ok(Json(response));
+    }
+
+    if let Some(mark) = params.get("mark") {
+        let mark_as = match params.get("as").map(String::as_str) {
+            Some("read") => MarkAs::Read,
+            Some("unread") => MarkAs::Unread,
+            Some("saved") => MarkAs::Saved,
+            Some("unsaved") => MarkAs::Unsaved,
+            mark_as => {
+                return Err(JsonApiError::bad_request(format!(
+                    "invalid mark as value {mark_as:?}"
+                )));
+            }
+        };
+
+        let id = parse_id(&params, "id")?
+            .ok_or_else(|| JsonApiError::bad_request("missing id of the marked object"))?;
+        let target = match mark.as_str() {
+            "item" => MarkTarget::Item(id),
+            "feed" => MarkTarget::Feed(id),
+            "group" => MarkTarget::Group(id),
+            mark => return Err(JsonApiError::bad_request(format!("invalid mark {mark}"))),
+        };
+
+        let before = parse_id(&params, "before")?
+            .map(|before| {
+                DateTime::from_timestamp(before, 0)
+                    .ok_or_else(|| JsonApiError::bad_request(format!("invalid date {before}")))
+            })
+            .transpose()?;
+
+        let saved = matches!(mark_as, MarkAs::Saved | MarkAs::Unsaved);
+        service.mark(target, mark_as, before).await?;
+
+        // The clients expect the updated ids after marking something
+        if saved {
+            params.insert("saved_item_ids".to_owned(), String::new());
+        } else {
+            params.insert("unread_item_ids".to_owned(), String::new());
+        }
+    }
+
+    // The feeds are checked for new articles when they are opened, the apps never do it, so we
+    // check them when the apps sync
+    if params.contains_key("items") || params.contains_key("unread_item_ids") {
+        refresh_feeds_in_background(&state);
+    }
+
+    let feeds = service.get_feeds().await?;
+    let last_refreshed_on_time = feeds
+        .iter()
+        .map(|feed| feed.last_updated.timestamp())
+        .max()
+        .unwrap_or_default();
+    response.insert(
+        "last_refreshed_on_time".to_owned(),
+        json!(last_refreshed_on_time),
+    );
+
+    if params.contains_key("groups") || params.contains_key("feeds") {
+        let groups = service.get_groups().await?;
+        if params.contains_key("groups") {
+            let fever_groups: Vec<FeverGroup> = groups.iter().map(FeverGroup::from).collect();
+            response.insert("groups".to_owned(), json!(fever_groups));
+        }
+        if params.contains_key("feeds") {
+            let fever_feeds: Vec<FeverFeed> = feeds.iter().map(FeverFeed::from).collect();
+            response.insert("feeds".to_owned(), json!(fever_feeds));
+        }
+        response.insert(
+            "feeds_groups".to_owned(),
+            json!(FeverFeedsGroup::from_feeds(&feeds, &groups)),
+        );
+    }
+
+    if params.contains_key("favicons") {
+        let favicons: Vec<FeverFavicon> = service
+            .get_favicons()
+            .await?
+            .into_iter()
+            .map(FeverFavicon::from)
+            .collect();
+        response.insert("favicons".to_owned(), json!(favicons));
+    }
+
+    if params.contains_key("items") {
+        let query = if let Some(with_ids) = params.get("with_ids") {
+            ItemsQuery::WithIds(parse_ids(with_ids)?)
+        } else if let Some(max_id) = parse_id(&params, "max_id")? {
+            // 0 is sent in the first request, to get the most recent items
+            ItemsQuery::MaxId(if max_id > 0 { max_id } else { i64::MAX })
+        } else if let Some(since_id) = parse_id(&params, "since_id")? {
+            ItemsQuery::SinceId(since_id)
+        } else {
+            ItemsQuery::MaxId(i64::MAX)
+        };
+
+        let feed_ids: HashMap<Uuid, i64> = feeds.iter().map(|f| (f.id, f.numeric_id)).collect();
+        let base_url = get_base_url(&headers);
+        let items: Vec<FeverItem> = service
+            .get_items(query)
+            .await?
+            .into_iter()
+            .map(|(article, html)| {
+                FeverItem::new(article, absolute_article_urls(&html, &base_url), &feed_ids)
+            })
+            .collect();
+
+        response.insert("items".to_owned(), json!(items));
+        response.insert("total_items".to_owned(), json!(service.get_total_items().await?));
+    }
+
+    if params.contains_key("links") {
+        // Hot links are not supported
+        response.insert("links".to_owned(), json!([]));
+    }
+
+    if params.contains_key("unread_item_ids") {
+        let ids = service.get_unread_item_ids().await?;
+        response.insert("unread_item_ids".to_owned(), json!(join_ids(&ids)));
+    }
+
+    if params.contains_key("saved_item_ids") {
+        let ids = service.get_saved_item_ids().await?;
+        response.insert("saved_item_ids".to_owned(), json!(join_ids(&ids)));
+    }
+
+    Ok(Json(response))
+}
+
+fn parse_id(params: &HashMap<String, String>, name: &str) -> Result<Option<i64>, JsonApiError> {
+    params
+        .get(name)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| JsonApiError::bad_request(format!("invalid {name} {value}")))
+        })
+        .transpose()
+}
+
+fn parse_ids(ids: &str) -> Result<Vec<i64>, JsonApiError> {
+    ids.split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            id.parse()
+                .map_err(|_| JsonApiError::bad_request(format!("invalid id {id}")))
+        })
+        .collect()
+}
@@ -0,0 +1,5 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod fever_api;
+
+pub use fever_api::fever_api;
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::models::filter_rule::{FilterAction, FilterField, FilterMatchType};
+use crate::services::filter_rule::FilterRuleService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct FilterRuleForm {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub feed_id: Option<Uuid>,
+    pub field: FilterField,
+    pub match_type: FilterMatchType,
+    pub pattern: String,
+    pub action: FilterAction,
+}
+
+pub async fn add_filter_rule<S>(
+    State(state): State<S>,
+    Form(rule): Form<FilterRuleForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .filter_rule_service()
+        .add_filter_rule(
+            rule.feed_id,
+            rule.field,
+            rule.match_type,
+            &rule.pattern,
+            rule.action,
+        )
+        .await?;
+
+    Ok(Redirect::to("/filters"))
+}
@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::filter_rule::FilterRuleService;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    response::Redirect,
+};
+use uuid::Uuid;
+
+pub async fn delete_filter_rule<S: AppState>(
+    State(state): State<S>,
+    Path(rule_id): Path<Uuid>,
+) -> Result<Redirect, ApiError> {
+    state.filter_rule_service().delete_filter_rule(rule_id).await?;
+
+    Ok(Redirect::to("/filters"))
+}
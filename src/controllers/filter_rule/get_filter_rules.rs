@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
+use crate::controllers::{empty_string_as_none, ApiError, HtmlResponse};
+use crate::models::filter_rule::{FilterField, FilterMatchType};
+use crate::services::feed::FeedService;
+use crate::services::filter_rule::FilterRuleService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_FILTER_RULES};
+use crate::state::AppState;
+use crate::view_models::article_list_item::ArticleListItem;
+use axum::extract::{Query, State};
+use minijinja::context;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A rule to be tested against the already saved articles
+#[derive(Deserialize, Serialize, Debug)]
+pub struct FilterRuleTestQuery {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub feed_id: Option<Uuid>,
+    pub field: Option<FilterField>,
+    pub match_type: Option<FilterMatchType>,
+    pub pattern: Option<String>,
+}
+
+pub async fn get_filter_rules<S>(
+    State(state): State<S>,
+    Query(test): Query<FilterRuleTestQuery>,
+) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let rules = state.filter_rule_service().get_filter_rule_list().await?;
+    let feeds = state.feed_service().get_feed_list().await?;
+
+    let matched_articles: Option<Vec<ArticleListItem>> =
+        if let (Some(field), Some(match_type), Some(pattern)) =
+            (test.field, test.match_type, test.pattern.as_deref())
+        {
+            let feeds_by_id: HashMap<Uuid, _> = feeds.iter().map(|f| (f.id, f)).collect();
+            let articles = state
+                .filter_rule_service()
+                .test_filter_rule(test.feed_id, field, match_type, pattern)
+                .await?;
+
+            Some(
+                articles
+                    .into_iter()
+                    .map(|a| {
+                        let feed = feeds_by_id.get(&a.feed_id).copied();
+                        ArticleListItem::from(a).with_feed(feed)
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_FILTER_RULES,
+                context! {
+                    rules => rules,
+                    feeds => feeds,
+                    test => test,
+                    matched_articles => matched_articles,
+                },
+            )
+            .await?,
+    ))
+}
@@ -0,0 +1,9 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod add_filter_rule;
+mod delete_filter_rule;
+mod get_filter_rules;
+
+pub use add_filter_rule::add_filter_rule;
+pub use delete_filter_rule::delete_filter_rule;
+pub use get_filter_rules::get_filter_rules;
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::Params;
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::Form;
+
+/// Logs a client in with the `Email` and `Passwd` parameters. The token is returned in the
+/// `key=value` lines format of Google's ClientLogin
+pub async fn client_login<S>(
+    State(state): State<S>,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<String, JsonApiError>
+where
+    S: AppState,
+{
+    let params = Params::new(query, body);
+    let token = state
+        .greader_service()
+        .login(
+            params.get("Email").unwrap_or_default(),
+            params.get("Passwd").unwrap_or_default(),
+        )
+        .ok_or_else(|| JsonApiError::unauthorized("invalid credentials"))?;
+
+    Ok(format!("SID={token}\nLSID={token}\nAuth={token}\n"))
+}
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, Params};
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::{GReaderService, StreamId};
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Form;
+
+/// Subscribes to, unsubscribes from or edits the label of the feeds given in the `s` parameter,
+/// according to the action (`ac`). Renaming the feeds (`t`) is not supported, their title is
+/// the one of the feed
+pub async fn edit_subscription<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<&'static str, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, body);
+    let service = state.greader_service();
+    let add_label = label(&params, "a")?;
+    let remove_label = label(&params, "r")?;
+    let streams = params.streams("s")?;
+
+    for stream in streams {
+        match params.get("ac") {
+            Some("subscribe") => {
+                let StreamId::Feed(url) = &stream else {
+                    return Err(JsonApiError::bad_request(format!("invalid feed {stream}")));
+                };
+                service.subscribe(url, add_label.as_deref()).await?;
+            }
+            Some("unsubscribe") => service.unsubscribe(&stream).await?,
+            Some("edit") => {
+                service
+                    .edit_label(&stream, add_label.as_deref(), remove_label.as_deref())
+                    .await?
+            }
+            action => {
+                return Err(JsonApiError::bad_request(format!(
+                    "invalid action {action:?}"
+                )));
+            }
+        }
+    }
+
+    Ok("OK")
+}
+
+/// Parses a label parameter, the labels are the categories of the feeds
+fn label(params: &Params, name: &str) -> Result<Option<String>, JsonApiError> {
+    match params.stream(name)? {
+        Some(StreamId::Label(label)) => Ok(Some(label)),
+        Some(stream) => Err(JsonApiError::bad_request(format!("invalid label {stream}"))),
+        None => Ok(None),
+    }
+}
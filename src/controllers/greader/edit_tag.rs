@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, Params};
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Form;
+
+/// Adds (`a`) and removes (`r`) the read and starred tags of the items given in the `i`
+/// parameter
+pub async fn edit_tag<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<&'static str, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, body);
+    let item_ids = params.item_ids()?;
+    let (add, remove) = (params.streams("a")?, params.streams("r")?);
+    state
+        .greader_service()
+        .edit_tags(&item_ids, &add, &remove)
+        .await?;
+
+    Ok("OK")
+}
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, build_items, Params};
+use crate::controllers::{api::JsonApiError, refresh_feeds_in_background};
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use crate::view_models::greader::GReaderItems;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+
+/// Returns a page of items of a stream with their content. The stream is given in the path or in
+/// the `s` parameter
+pub async fn get_stream_contents<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    stream: Option<Path<String>>,
+    Query(query): Query<Vec<(String, String)>>,
+) -> Result<Json<GReaderItems>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, None);
+    let query = params.stream_query(stream.as_deref().map(String::as_str))?;
+
+    // The feeds are checked for new articles when they are opened, the apps never do it, so we
+    // check them when the apps start a sync
+    let service = state.greader_service();
+    if query.continuation.is_none() {
+        refresh_feeds_in_background(&state);
+    }
+
+    let (articles, continuation) = service.get_stream(&query).await?;
+    let items = build_items(&state, &headers, articles).await?;
+
+    Ok(Json(GReaderItems::new(query.stream, items, continuation)))
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
+use super::{authenticate, Params};
+use crate::controllers::{api::JsonApiError, refresh_feeds_in_background};
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use crate::view_models::greader::{GReaderItemRef, GReaderItemRefs};
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+
+/// Returns the ids of a page of items of the stream given in the `s` parameter
+pub async fn get_stream_item_ids<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+) -> Result<Json<GReaderItemRefs>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, None);
+    let query = params.stream_query(None)?;
+
+    // The feeds are checked for new articles when they are opened, the apps never do it, so we
+    // check them when the apps start a sync
+    let service = state.greader_service();
+    if query.continuation.is_none() {
+        refresh_feeds_in_background(&state);
+    }
+
+    let feeds: HashMap<_, _> = service
+        .get_subscriptions()
+        .await?
+        .into_iter()
+        .map(|(feed, _)| (feed.id, feed))
+        .collect();
+
+    let (articles, continuation) = service.get_stream(&query).await?;
+    let item_refs = articles
+        .iter()
+        .map(|article| GReaderItemRef::new(article, feeds.get(&article.feed_id)))
+        .collect();
+
+    Ok(Json(GReaderItemRefs::new(item_refs, continuation)))
+}
@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, build_items, Params};
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::{GReaderService, StreamId};
+use crate::state::AppState;
+use crate::view_models::greader::GReaderItems;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::{Form, Json};
+
+/// Returns the content of the items given in the `i` parameter
+pub async fn get_stream_items_contents<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<Json<GReaderItems>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let item_ids = Params::new(query, body).item_ids()?;
+    let articles = state.greader_service().get_items(&item_ids).await?;
+    let items = build_items(&state, &headers, articles).await?;
+
+    Ok(Json(GReaderItems::new(StreamId::ReadingList, items, None)))
+}
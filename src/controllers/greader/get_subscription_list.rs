@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::authenticate;
+use crate::controllers::api::JsonApiError;
+use crate::controllers::get_base_url;
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use crate::view_models::greader::GReaderSubscription;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+/// Returns the feeds, with their category as label
+pub async fn get_subscription_list<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let base_url = get_base_url(&headers);
+    let subscriptions: Vec<GReaderSubscription> = state
+        .greader_service()
+        .get_subscriptions()
+        .await?
+        .iter()
+        .map(|(feed, category)| GReaderSubscription::new(feed, category.as_ref(), &base_url))
+        .collect();
+
+    Ok(Json(json!({ "subscriptions": subscriptions })))
+}
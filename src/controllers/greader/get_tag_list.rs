@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::authenticate;
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::{GReaderService, StreamId};
+use crate::state::AppState;
+use crate::view_models::greader::GReaderTag;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+/// Returns the starred state and the labels (categories)
+pub async fn get_tag_list<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let labels = state.greader_service().get_labels().await?;
+    let tags: Vec<GReaderTag> = std::iter::once(StreamId::Starred)
+        .chain(labels.into_iter().map(|label| StreamId::Label(label.name)))
+        .map(GReaderTag::from)
+        .collect();
+
+    Ok(Json(json!({ "tags": tags })))
+}
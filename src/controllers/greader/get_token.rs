@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::authenticate;
+use crate::controllers::api::JsonApiError;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::HeaderMap;
+
+/// Returns the token the clients send when they modify something. Requests are already
+/// authenticated by the auth token, so it is the same one
+pub async fn get_token<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+) -> Result<String, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)
+}
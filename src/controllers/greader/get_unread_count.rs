@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
+use super::authenticate;
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::{GReaderService, StreamId};
+use crate::state::AppState;
+use crate::view_models::greader::GReaderUnreadCount;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+/// Returns the number of unread items of every feed, label and of the reading list
+pub async fn get_unread_count<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let mut total = (0, 0);
+    let mut labels: HashMap<String, (u32, i64)> = HashMap::new();
+    let mut unread_counts = vec![];
+    for (feed, category) in state.greader_service().get_subscriptions().await? {
+        let count = u32::from(feed.unread_count);
+        let newest_item = feed.last_updated.timestamp_micros();
+        unread_counts.push(GReaderUnreadCount::new(StreamId::feed(&feed), count, newest_item));
+
+        let counts = std::iter::once(&mut total)
+            .chain(category.map(|category| labels.entry(category.name).or_default()));
+        for (label_count, label_newest_item) in counts {
+            *label_count += count;
+            *label_newest_item = (*label_newest_item).max(newest_item);
+        }
+    }
+
+    unread_counts.extend(labels.into_iter().map(|(label, (count, newest_item))| {
+        GReaderUnreadCount::new(StreamId::Label(label), count, newest_item)
+    }));
+    unread_counts.push(GReaderUnreadCount::new(
+        StreamId::ReadingList,
+        total.0,
+        total.1,
+    ));
+
+    Ok(Json(json!({ "max": total.0, "unreadcounts": unread_counts })))
+}
@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::authenticate;
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+/// Returns the user, there is only one
+pub async fn get_user_info<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+    let username = state.greader_service().username();
+
+    Ok(Json(json!({
+        "userId": "1",
+        "userName": username,
+        "userProfileId": "1",
+        "userEmail": username,
+    })))
+}
@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, Params};
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::GReaderService;
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Form;
+
+/// Marks the items of the stream given in the `s` parameter as read. If the `ts` parameter is
+/// sent (in microseconds), only the items found before it are marked
+pub async fn mark_all_as_read<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<&'static str, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, body);
+    let stream = params
+        .stream("s")?
+        .ok_or_else(|| JsonApiError::bad_request("missing stream"))?;
+    let before = params.date("ts", 1_000_000)?;
+
+    state
+        .greader_service()
+        .mark_all_as_read(&stream, before)
+        .await?;
+
+    Ok("OK")
+}
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Google Reader API, used by third-party reader apps. The clients log in with `ClientLogin` and
+//! send the returned token in the `Authorization: GoogleLogin auth=<token>` header
+mod client_login;
+mod edit_subscription;
+mod edit_tag;
+mod get_stream_contents;
+mod get_stream_item_ids;
+mod get_stream_items_contents;
+mod get_subscription_list;
+mod get_tag_list;
+mod get_token;
+mod get_unread_count;
+mod get_user_info;
+mod mark_all_as_read;
+mod quickadd_subscription;
+
+pub use client_login::client_login;
+pub use edit_subscription::edit_subscription;
+pub use edit_tag::edit_tag;
+pub use get_stream_contents::get_stream_contents;
+pub use get_stream_item_ids::get_stream_item_ids;
+pub use get_stream_items_contents::get_stream_items_contents;
+pub use get_subscription_list::get_subscription_list;
+pub use get_tag_list::get_tag_list;
+pub use get_token::get_token;
+pub use get_unread_count::get_unread_count;
+pub use get_user_info::get_user_info;
+pub use mark_all_as_read::mark_all_as_read;
+pub use quickadd_subscription::quickadd_subscription;
+
+use std::collections::HashMap;
+
+use axum::{
+    http::{header, HeaderMap},
+    Form,
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    controllers::{absolute_article_urls, api::JsonApiError, get_base_url},
+    models::{article::Article, category::Category, feed::Feed},
+    services::greader::{parse_item_id, GReaderService, StreamId, StreamQuery, DEFAULT_ITEMS},
+    state::AppState,
+    view_models::greader::GReaderItem,
+};
+
+/// Checks the token sent in the `Authorization` header and returns it
+fn authenticate<S: AppState>(state: &S, headers: &HeaderMap) -> Result<String, JsonApiError> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("GoogleLogin auth="))
+        .map(str::trim)
+        .filter(|token| state.greader_service().is_authenticated(token))
+        .map(str::to_owned)
+        .ok_or_else(|| JsonApiError::unauthorized("invalid or missing auth token"))
+}
+
+/// Builds the items of a response, with their HTML content and the feed and label they belong to
+async fn build_items<S: AppState>(
+    state: &S,
+    headers: &HeaderMap,
+    articles: Vec<Article>,
+) -> Result<Vec<GReaderItem>, JsonApiError> {
+    let service = state.greader_service();
+    let subscriptions: HashMap<Uuid, (Feed, Option<Category>)> = service
+        .get_subscriptions()
+        .await?
+        .into_iter()
+        .map(|(feed, category)| (feed.id, (feed, category)))
+        .collect();
+
+    let base_url = get_base_url(headers);
+    let mut items = Vec::with_capacity(articles.len());
+    for article in articles {
+        let html = absolute_article_urls(&service.get_item_html(&article).await?, &base_url);
+        let (feed, category) = match subscriptions.get(&article.feed_id) {
+            Some((feed, category)) => (Some(feed), category.as_ref()),
+            None => (None, None),
+        };
+        items.push(GReaderItem::new(article, html, feed, category));
+    }
+
+    Ok(items)
+}
+
+/// Parameters of a request. The clients send them in the query or in the body, and some of them
+/// (for example, the item ids) are repeated, so they are kept as a list
+struct Params(Vec<(String, String)>);
+
+impl Params {
+    fn new(query: Vec<(String, String)>, body: Option<Form<Vec<(String, String)>>>) -> Self {
+        let mut params = query;
+        if let Some(Form(body)) = body {
+            params.extend(body);
+        }
+
+        Self(params)
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, value)| key == name && !value.is_empty())
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.0
+            .iter()
+            .filter(move |(key, value)| key == name && !value.is_empty())
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn parse<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, JsonApiError> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| JsonApiError::bad_request(format!("invalid {name} {value}")))
+            })
+            .transpose()
+    }
+
+    fn stream(&self, name: &str) -> Result<Option<StreamId>, JsonApiError> {
+        Ok(self.get(name).map(str::parse).transpose()?)
+    }
+
+    fn streams(&self, name: &str) -> Result<Vec<StreamId>, JsonApiError> {
+        Ok(self
+            .get_all(name)
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn item_ids(&self) -> Result<Vec<i64>, JsonApiError> {
+        self.get_all("i")
+            .map(|id| {
+                parse_item_id(id)
+                    .ok_or_else(|| JsonApiError::bad_request(format!("invalid item id {id}")))
+            })
+            .collect()
+    }
+
+    /// Parses a date sent as a unix timestamp in the given unit (for example, 1_000_000 for
+    /// microseconds)
+    fn date(
+        &self,
+        name: &str,
+        units_per_second: i64,
+    ) -> Result<Option<DateTime<Utc>>, JsonApiError> {
+        self.parse::<i64>(name)?
+            .map(|timestamp| {
+                DateTime::from_timestamp(timestamp / units_per_second, 0).ok_or_else(|| {
+                    JsonApiError::bad_request(format!("invalid {name} {timestamp}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Parses the parameters of a stream request. `stream` is the one given in the path, if any,
+    /// otherwise it is taken from the `s` parameter
+    fn stream_query(&self, stream: Option<&str>) -> Result<StreamQuery, JsonApiError> {
+        let stream = match stream.or_else(|| self.get("s")) {
+            Some(stream) => stream.parse()?,
+            None => StreamId::ReadingList,
+        };
+
+        Ok(StreamQuery {
+            stream,
+            exclude: self.stream("xt")?,
+            include: self.stream("it")?,
+            count: self.parse("n")?.unwrap_or(DEFAULT_ITEMS),
+            oldest_first: self.get("r") == Some("o"),
+            newer_than: self.date("ot", 1)?,
+            older_than: self.date("nt", 1)?,
+            continuation: self.get("c").map(str::to_owned),
+        })
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{authenticate, Params};
+use crate::controllers::api::JsonApiError;
+use crate::services::greader::{GReaderService, StreamId};
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::{Form, Json};
+use serde_json::{json, Value};
+
+/// Subscribes to the feed given in the `quickadd` parameter
+pub async fn quickadd_subscription<S>(
+    State(state): State<S>,
+    headers: HeaderMap,
+    Query(query): Query<Vec<(String, String)>>,
+    body: Option<Form<Vec<(String, String)>>>,
+) -> Result<Json<Value>, JsonApiError>
+where
+    S: AppState,
+{
+    authenticate(&state, &headers)?;
+
+    let params = Params::new(query, body);
+    let url = params
+        .get("quickadd")
+        .ok_or_else(|| JsonApiError::bad_request("missing quickadd url"))?;
+    let url = url.strip_prefix("feed/").unwrap_or(url);
+
+    let feed = state.greader_service().subscribe(url, None).await?;
+
+    Ok(Json(json!({
+        "numResults": 1,
+        "query": url,
+        "streamId": StreamId::feed(&feed).to_string(),
+        "streamName": feed.title,
+    })))
+}
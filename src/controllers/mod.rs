@@ -1,11 +1,34 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+pub mod api;
+pub mod category;
 pub mod config;
+pub mod digest;
+pub mod email;
+pub mod export;
 pub mod feed;
+pub mod fever;
+pub mod greader;
+pub mod filter_rule;
 pub mod not_found;
+pub mod rewrite_rule;
+pub mod save_to;
+pub mod tag;
+pub mod webhook;
+pub mod websub;
 
-use axum::response::{Html, IntoResponse};
+use std::{fmt::Display, str::FromStr};
+
+use axum::{
+    http::HeaderMap,
+    response::{Html, IntoResponse},
+};
 use reqwest::{header, StatusCode};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::router::ARTICLES_DIR;
+use crate::services::feed::FeedService;
+use crate::state::AppState;
 
 pub(crate) struct ApiError {
     pub original_error: Box<dyn std::error::Error + 'static>,
@@ -79,3 +102,50 @@ impl IntoResponse for HtmlResponse {
             .into_response()
     }
 }
+
+/// HTML forms send an empty string for unselected options. This deserializer maps them to `None`
+/// so they can be used with optional fields, for example: `Option<Uuid>`
+pub(crate) fn empty_string_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value: Option<String> = Option::deserialize(de)?;
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(s) => T::from_str(s).map_err(de::Error::custom).map(Some),
+    }
+}
+
+/// Checks the feeds for new articles in the background. The apps sync with the articles that are
+/// already saved instead of waiting for every feed to download, and get the new ones on the next
+/// sync. Errors are only logged
+pub(crate) fn refresh_feeds_in_background<S: AppState>(state: &S) {
+    let state = state.clone();
+    tokio::spawn(async move {
+        if let Err(e) = state.feed_service().refresh_feeds().await {
+            tracing::error!("unable to check the feeds for new articles: {e:?}");
+        }
+    });
+}
+
+/// Url the client used to reach us, taken from the `Host` header (and `X-Forwarded-Proto` if we
+/// are behind a reverse proxy). Used by the APIs to make the urls of the files we serve absolute
+pub(crate) fn get_base_url(headers: &HeaderMap) -> String {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    let scheme = header("x-forwarded-proto").unwrap_or("http");
+
+    match header(header::HOST.as_str()) {
+        Some(host) => format!("{scheme}://{host}"),
+        None => String::new(),
+    }
+}
+
+/// The images of the articles are served by us with relative urls, that the apps can not resolve
+pub(crate) fn absolute_article_urls(html: &str, base_url: &str) -> String {
+    html.replace(
+        &format!("\"{ARTICLES_DIR}/"),
+        &format!("\"{base_url}{ARTICLES_DIR}/"),
+    )
+}
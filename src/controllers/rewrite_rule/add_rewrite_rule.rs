@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::models::rewrite_rule::RewriteRuleKind;
+use crate::services::rewrite_rule::RewriteRuleService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct RewriteRuleForm {
+    pub kind: RewriteRuleKind,
+    pub pattern: String,
+    pub replacement: Option<String>,
+}
+
+pub async fn add_rewrite_rule<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    Form(rule): Form<RewriteRuleForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .rewrite_rule_service()
+        .add_rewrite_rule(feed_id, rule.kind, &rule.pattern, rule.replacement)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/settings")))
+}
@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::rewrite_rule::RewriteRuleService;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    response::Redirect,
+};
+use uuid::Uuid;
+
+pub async fn delete_rewrite_rule<S: AppState>(
+    State(state): State<S>,
+    Path((feed_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<Redirect, ApiError> {
+    state
+        .rewrite_rule_service()
+        .delete_rewrite_rule(feed_id, rule_id)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/settings")))
+}
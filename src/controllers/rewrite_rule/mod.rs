@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod add_rewrite_rule;
+mod delete_rewrite_rule;
+
+pub use add_rewrite_rule::add_rewrite_rule;
+pub use delete_rewrite_rule::delete_rewrite_rule;
@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_SAVE_TO};
+use crate::state::AppState;
+use axum::extract::State;
+use minijinja::context;
+
+/// Shows the settings of the read-it-later services
+pub async fn get_save_to<S>(State(state): State<S>) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(TEMPLATE_NAME_SAVE_TO, context! {})
+            .await?,
+    ))
+}
@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod get_save_to;
+mod save_article;
+
+pub use get_save_to::get_save_to;
+pub use save_article::save_article;
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::models::saved_article::SaveTarget;
+use crate::services::save_to::SaveToService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use uuid::Uuid;
+
+/// Saves the article to a read-it-later service
+pub async fn save_article<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id, target)): Path<(Uuid, Uuid, SaveTarget)>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .save_to_service()
+        .save_article(feed_id, article_id, target)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/article/{article_id}")))
+}
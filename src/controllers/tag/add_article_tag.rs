@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::tag::TagService;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct ArticleTagForm {
+    pub name: String,
+}
+
+pub async fn add_article_tag<S>(
+    State(state): State<S>,
+    Path((feed_id, article_id)): Path<(Uuid, Uuid)>,
+    Form(tag): Form<ArticleTagForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .tag_service()
+        .add_article_tag(feed_id, article_id, &tag.name)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/article/{article_id}")))
+}
@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::tag::TagService;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    response::Redirect,
+};
+use uuid::Uuid;
+
+pub async fn delete_article_tag<S: AppState>(
+    State(state): State<S>,
+    Path((feed_id, article_id, name)): Path<(Uuid, Uuid, String)>,
+) -> Result<Redirect, ApiError> {
+    state
+        .tag_service()
+        .delete_article_tag(feed_id, article_id, &name)
+        .await?;
+
+    Ok(Redirect::to(&format!("/feed/{feed_id}/article/{article_id}")))
+}
@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::models::tag::ArticleTag;
+use crate::services::feed::FeedService;
+use crate::services::persisted_config::PersistedConfigService;
+use crate::services::tag::TagService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_ARTICLE_RIVER};
+use crate::state::AppState;
+use crate::view_models::article_list_item::ArticleListItem;
+use axum::extract::Path;
+use axum::extract::State;
+use minijinja::context;
+use uuid::Uuid;
+
+pub async fn get_tag_article_list<S>(
+    State(state): State<S>,
+    Path(name): Path<String>,
+) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let mut articles = state.tag_service().get_tag_articles(&name).await?;
+
+    let config = state.persisted_config_service().get_configuration().await?;
+    config.article_sort.sort(&mut articles);
+
+    let feeds: HashMap<Uuid, _> = state
+        .feed_service()
+        .get_feed_list()
+        .await?
+        .into_iter()
+        .map(|f| (f.id, f))
+        .collect();
+
+    let articles = ArticleListItem::from_articles(articles, &feeds);
+    // The title shows the name the tag is looked up with, not the raw path segment
+    let title = format!("#{}", ArticleTag::normalize_name(&name).unwrap_or_default());
+
+    let rendered_html = state
+        .template_service()
+        .render_template(
+            TEMPLATE_NAME_ARTICLE_RIVER,
+            context! { title => title, articles => articles },
+        )
+        .await?;
+
+    Ok(HtmlResponse::new(rendered_html))
+}
@@ -0,0 +1,9 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod add_article_tag;
+mod delete_article_tag;
+mod get_tag_article_list;
+
+pub use add_article_tag::add_article_tag;
+pub use delete_article_tag::delete_article_tag;
+pub use get_tag_article_list::get_tag_article_list;
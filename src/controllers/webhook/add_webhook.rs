@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{empty_string_as_none, ApiError};
+use crate::services::webhook::WebhookService;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Redirect;
+use axum::Form;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct WebhookForm {
+    pub url: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub feed_id: Option<Uuid>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub category_id: Option<Uuid>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub secret: Option<String>,
+}
+
+pub async fn add_webhook<S>(
+    State(state): State<S>,
+    Form(webhook): Form<WebhookForm>,
+) -> Result<Redirect, ApiError>
+where
+    S: AppState,
+{
+    state
+        .webhook_service()
+        .add_webhook(
+            &webhook.url,
+            webhook.feed_id,
+            webhook.category_id,
+            webhook.secret,
+        )
+        .await?;
+
+    Ok(Redirect::to("/webhooks"))
+}
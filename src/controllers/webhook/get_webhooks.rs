@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::{ApiError, HtmlResponse};
+use crate::services::category::CategoryService;
+use crate::services::feed::FeedService;
+use crate::services::templates::{TemplateService, TEMPLATE_NAME_WEBHOOKS};
+use crate::services::webhook::WebhookService;
+use crate::state::AppState;
+use crate::view_models::webhook_delivery_item::WebhookDeliveryItem;
+use axum::extract::State;
+use minijinja::context;
+
+/// Lists the webhooks and the log of their most recent deliveries
+pub async fn get_webhooks<S>(State(state): State<S>) -> Result<HtmlResponse, ApiError>
+where
+    S: AppState,
+{
+    let webhooks = state.webhook_service().get_webhook_list().await?;
+    let deliveries: Vec<WebhookDeliveryItem> = state
+        .webhook_service()
+        .get_delivery_log()
+        .await?
+        .into_iter()
+        .map(WebhookDeliveryItem::from)
+        .collect();
+    let feeds = state.feed_service().get_feed_list().await?;
+    let categories = state.category_service().get_category_list().await?;
+
+    Ok(HtmlResponse::new(
+        state
+            .template_service()
+            .render_template(
+                TEMPLATE_NAME_WEBHOOKS,
+                context! {
+                    webhooks => webhooks,
+                    deliveries => deliveries,
+                    feeds => feeds,
+                    categories => categories,
+                },
+            )
+            .await?,
+    ))
+}
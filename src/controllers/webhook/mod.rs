@@ -0,0 +1,9 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod add_webhook;
+mod delete_webhook;
+mod get_webhooks;
+
+pub use add_webhook::add_webhook;
+pub use delete_webhook::delete_webhook;
+pub use get_webhooks::get_webhooks;
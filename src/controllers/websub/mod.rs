@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod receive_content;
+mod verify_intent;
+
+pub use receive_content::receive_content;
+pub use verify_intent::verify_intent;
@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::feed::FeedService;
+use crate::services::websub::{WebSubService, SIGNATURE_HEADER};
+use crate::state::AppState;
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use uuid::Uuid;
+
+/// Content pushed by the hub. Content with an invalid signature is acknowledged anyway, as the
+/// spec requires, so the hub does not retry it
+pub async fn receive_content<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, ApiError>
+where
+    S: AppState,
+{
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    let authentic = state
+        .websub_service()
+        .authenticate_content(feed_id, signature, &body)
+        .await?;
+
+    if authentic {
+        let content_type = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+
+        state
+            .feed_service()
+            .push_feed_content(feed_id, &body, content_type)
+            .await?;
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
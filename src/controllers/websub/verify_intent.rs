@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::controllers::ApiError;
+use crate::services::websub::{Verification, WebSubService, WebSubServiceError};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+/// Verification of intent of the hub. The challenge is sent back only to confirm it, in any other
+/// case (a denial or a verification that does not match the subscription) the answer is a bare
+/// 404, as the hubs only read its status
+pub async fn verify_intent<S>(
+    State(state): State<S>,
+    Path(feed_id): Path<Uuid>,
+    Query(verification): Query<Verification>,
+) -> Result<Response, ApiError>
+where
+    S: AppState,
+{
+    match state
+        .websub_service()
+        .verify_intent(feed_id, &verification)
+        .await
+    {
+        Ok(Some(challenge)) => Ok(challenge.into_response()),
+        Ok(None) => Ok(StatusCode::NOT_FOUND.into_response()),
+        Err(
+            e @ (WebSubServiceError::SubscriptionNotFound(_)
+            | WebSubServiceError::VerificationMismatch(_)),
+        ) => {
+            tracing::warn!("{e}");
+            Ok(StatusCode::NOT_FOUND.into_response())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
@@ -32,6 +32,27 @@ async fn main() {
     // Create state
     let state = State::new(connection, config.clone()).await;
 
+    // Send the queued webhook calls in the background
+    tokio::spawn(services::webhook::run_delivery_queue(
+        state.webhook_service.clone(),
+    ));
+
+    // Create the daily digest at its scheduled time
+    tokio::spawn(services::digest::run_digest_schedule(
+        state.digest_service.clone(),
+    ));
+
+    // Send the emails of the outbox in the background
+    tokio::spawn(services::email::run_outbox(state.email_service.clone()));
+
+    // Request and renew the WebSub subscriptions. The hubs can only reach the app through its
+    // public url
+    if config.public_url.is_some() {
+        tokio::spawn(services::websub::run_subscription_requests(
+            state.websub_service.clone(),
+        ));
+    }
+
     // Initialize App
     let app = router::build(state, &config);
     let listener = tokio::net::TcpListener::bind(format!("{}:{}", config.ip, config.port))
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use axum::{body::to_bytes, http::header, response::Response};
+
+use crate::controllers::api::ErrorBody;
+
+/// Maximum size of an error body that is wrapped in an [`ErrorBody`]
+const MAX_ERROR_BODY_SIZE: usize = 16 * 1024;
+
+/// Converts the error responses of the JSON API that are not JSON (for example, the rejections of
+/// the axum extractors for an invalid path or body, which are plain text) to an [`ErrorBody`]
+pub async fn json_error_response(response: Response) -> Response {
+    let status_code = response.status();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"));
+
+    if !(status_code.is_client_error() || status_code.is_server_error()) || is_json {
+        return response;
+    }
+
+    let message = match to_bytes(response.into_body(), MAX_ERROR_BODY_SIZE).await {
+        Ok(body) if !body.is_empty() => String::from_utf8_lossy(&body).into_owned(),
+        _ => status_code
+            .canonical_reason()
+            .unwrap_or("unknown error")
+            .to_owned(),
+    };
+
+    ErrorBody::response(status_code, message)
+}
@@ -1,3 +1,4 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 pub mod error_handling_middleware;
+pub mod json_error_middleware;
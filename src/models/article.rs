@@ -1,26 +1,146 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 use chrono::{DateTime, Utc};
+use reqwest::Url;
 use serde::Serialize;
 use sqlite::Row;
 use uuid::Uuid;
 
 use crate::repositories::RepositoryError;
 
+/// Query params added by newsletters, social networks and analytics tools to track where a visit
+/// comes from. A param ending with `*` matches all the params with that prefix
+const TRACKING_PARAMS: [&str; 9] = [
+    "utm_*", "fbclid", "gclid", "mc_cid", "mc_eid", "igshid", "ref_src", "yclid", "_hs*",
+];
+
+/// Minimum ratio of shared words for two titles to be considered the same
+const TITLE_SIMILARITY_THRESHOLD: f64 = 0.8;
+
+/// Titles with fewer words are too generic (e.g. "Weekly update") to be compared
+const TITLE_SIMILARITY_MIN_WORDS: usize = 4;
+
 #[derive(Serialize, Debug)]
 pub struct Article {
     pub id: Uuid,
+    /// Integer id for the clients that do not support UUIDs. Assigned when the article is saved
+    pub numeric_id: i64,
     pub feed_id: Uuid,
     pub title: String,
     pub author: Option<String>,
     pub guid: String,
     pub link: String,
+    /// Plain text teaser shown in the article lists
+    pub summary: Option<String>,
+    /// Cached thumbnail shown in the article lists
+    pub thumbnail_url: Option<String>,
     pub content: Option<String>,
     pub read: bool,
+    pub starred: bool,
     pub html_parsed: bool,
+    /// Date of the last update reported by the feed, only used to detect changes. It is the
+    /// publication date if the feed does not report updates
     pub last_updated: DateTime<Utc>,
+    pub published_at: DateTime<Utc>,
+    /// When the article was found in the feed, minus its position in it. Sorting by it keeps the
+    /// order of the feed
+    pub found_at: DateTime<Utc>,
+    /// Whether the feed did not include a valid date and the publication date was guessed
+    pub date_guessed: bool,
+    /// Hash of the title and content included in the feed
+    pub content_hash: Option<String>,
+    /// Whether the publisher updated the article and the user did not read the new version yet
+    pub has_update: bool,
+    /// When the last update of the article was detected
+    pub updated_at: Option<DateTime<Utc>>,
+    /// The article of another feed this one is a copy of
+    pub duplicate_of: Option<Uuid>,
+    /// Normalized link of the article (the canonical one if the web page declares it), used to
+    /// find its copies in other feeds
+    pub canonical_url: Option<String>,
+}
+
+impl Article {
+    /// Normalizes the link of an article so the copies published by different feeds match: the
+    /// fragment, the tracking params, the `www.` prefix and the trailing slash are removed, and
+    /// `http` is treated as `https`. Returns `None` if the link is not a valid absolute url
+    pub fn canonicalize_link(link: &str) -> Option<String> {
+        let mut url = Url::parse(link.trim()).ok()?;
+
+        if url.scheme() == "http" {
+            url.set_scheme("https").ok()?;
+        }
+        url.set_fragment(None);
+
+        if let Some(host) = url.host_str().and_then(|h| h.strip_prefix("www.")) {
+            let host = host.to_owned();
+            url.set_host(Some(&host)).ok()?;
+        }
+
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .into_owned()
+            .filter(|(key, _)| {
+                !TRACKING_PARAMS
+                    .iter()
+                    .any(|param| match param.strip_suffix('*') {
+                        Some(prefix) => key.starts_with(prefix),
+                        None => key == param,
+                    })
+            })
+            .collect();
+
+        if query.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(query);
+        }
+
+        let path = url.path().trim_end_matches('/').to_owned();
+        url.set_path(&path);
+
+        Some(url.to_string().trim_end_matches('/').to_owned())
+    }
+
+    /// HTML of the article shown by the apps and the exports: its saved content or, if it was not
+    /// downloaded, its plain text summary
+    pub fn content_or_summary_html(&self, content: Option<String>) -> String {
+        content
+            .or_else(|| {
+                self.summary.as_ref().map(|summary| {
+                    let summary = summary
+                        .replace('&', "&amp;")
+                        .replace('<', "&lt;")
+                        .replace('>', "&gt;");
+                    format!("<p>{summary}</p>")
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    /// Checks if two titles are (almost) the same, ignoring case, punctuation and a few changed
+    /// words, as aggregators usually copy the title of the original article
+    pub fn is_similar_title(a: &str, b: &str) -> bool {
+        fn words(title: &str) -> HashSet<String> {
+            title
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        }
+
+        let (a, b) = (words(a), words(b));
+        if a.len().min(b.len()) < TITLE_SIMILARITY_MIN_WORDS {
+            return false;
+        }
+
+        let shared = a.intersection(&b).count() as f64;
+        let total = a.union(&b).count() as f64;
+
+        shared / total >= TITLE_SIMILARITY_THRESHOLD
+    }
 }
 
 impl TryFrom<Row> for Article {
@@ -33,18 +153,118 @@ impl TryFrom<Row> for Article {
         let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
             .map_err(|e| RepositoryError::Deserialization(e.into()))?;
 
+        let last_updated = DateTime::from_str(row.read::<&str, _>("last_updated"))
+            .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?;
+
+        let read_date = |column: &str| {
+            row.read::<Option<&str>, _>(column)
+                .map(DateTime::from_str)
+                .transpose()
+                .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))
+        };
+
         Ok(Article {
             id,
+            numeric_id: row.read::<i64, _>("numeric_id"),
             feed_id,
             title: row.read::<&str, _>("title").into(),
             author: row.read::<Option<&str>, _>("author").map(|a| a.to_owned()),
             link: row.read::<&str, _>("link").into(),
             guid: row.read::<&str, _>("guid").into(),
+            summary: row.read::<Option<&str>, _>("summary").map(|s| s.to_owned()),
+            thumbnail_url: row
+                .read::<Option<&str>, _>("thumbnail_url")
+                .map(|s| s.to_owned()),
             content: row.read::<Option<&str>, _>("content").map(|s| s.to_owned()),
             read: row.read::<i64, _>("read") != 0,
+            starred: row.read::<i64, _>("starred") != 0,
             html_parsed: row.read::<i64, _>("html_parsed") != 0,
-            last_updated: DateTime::from_str(row.read::<&str, _>("last_updated"))
-                .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?,
+            last_updated,
+            published_at: read_date("published_at")?.unwrap_or(last_updated),
+            found_at: read_date("found_at")?.unwrap_or(last_updated),
+            date_guessed: row.read::<i64, _>("date_guessed") != 0,
+            content_hash: row
+                .read::<Option<&str>, _>("content_hash")
+                .map(|h| h.to_owned()),
+            has_update: row.read::<i64, _>("has_update") != 0,
+            updated_at: read_date("updated_at")?,
+            duplicate_of: row
+                .read::<Option<&str>, _>("duplicate_of")
+                .map(Uuid::from_str)
+                .transpose()
+                .map_err(|e| RepositoryError::Deserialization(e.into()))?,
+            canonical_url: row
+                .read::<Option<&str>, _>("canonical_url")
+                .map(|u| u.to_owned()),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::test_utils::test_article;
+
+    #[test]
+    fn the_summary_is_escaped_when_there_is_no_content() {
+        let mut article = test_article(Uuid::new_v4(), "article");
+        assert_eq!("", article.content_or_summary_html(None));
+
+        article.summary = Some("Use <script> & <style>".to_owned());
+        assert_eq!(
+            "<p>Use &lt;script&gt; &amp; &lt;style&gt;</p>",
+            article.content_or_summary_html(None)
+        );
+        assert_eq!(
+            "<p>Content</p>",
+            article.content_or_summary_html(Some("<p>Content</p>".to_owned()))
+        );
+    }
+
+    #[test]
+    fn links_are_canonicalized() {
+        let cases = [
+            ("https://example.com/post", Some("https://example.com/post")),
+            (
+                "http://www.example.com/post/?utm_source=rss&utm_medium=feed#comments",
+                Some("https://example.com/post"),
+            ),
+            (
+                "https://example.com/post?id=1&fbclid=abc",
+                Some("https://example.com/post?id=1"),
+            ),
+            (
+                "https://example.com/compare?ref=main&ref_src=twsrc",
+                Some("https://example.com/compare?ref=main"),
+            ),
+            ("https://example.com/", Some("https://example.com")),
+            ("/relative/post", None),
+        ];
+
+        for (link, expected) in cases {
+            assert_eq!(
+                expected.map(ToOwned::to_owned),
+                Article::canonicalize_link(link),
+                "{link}"
+            );
+        }
+    }
+
+    #[test]
+    fn similar_titles_are_detected() {
+        assert!(Article::is_similar_title(
+            "Rust 1.90 is out with a new borrow checker",
+            "Rust 1.90 is out with a new borrow checker!"
+        ));
+        assert!(Article::is_similar_title(
+            "Rust 1.90 is out with a brand new borrow checker",
+            "rust 1.90 is out, with a new borrow checker"
+        ));
+        assert!(!Article::is_similar_title(
+            "Rust 1.90 is out with a new borrow checker",
+            "Go 1.30 is out with a new garbage collector"
+        ));
+        // Short titles are too generic to be compared
+        assert!(!Article::is_similar_title("Weekly update", "Weekly update"));
+    }
+}
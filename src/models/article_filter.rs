@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Filters of an article query. Articles are sorted by publication date, the most recent first
+/// unless `oldest_first` is set
+#[derive(Debug, Clone, Default)]
+pub struct ArticleFilter {
+    /// Feeds the articles belong to. If it is None, the articles of all the feeds are returned
+    pub feed_ids: Option<Vec<Uuid>>,
+    pub read: Option<bool>,
+    pub starred: Option<bool>,
+    /// Name of a tag the articles have
+    pub tag: Option<String>,
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
+    /// Only the articles found by the reader after this date
+    pub found_after: Option<DateTime<Utc>>,
+    pub oldest_first: bool,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// A previous version of an article that was updated by the publisher
+#[derive(Serialize, Clone, Debug)]
+pub struct ArticleRevision {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub feed_id: Uuid,
+    pub title: String,
+    /// Path of the file with the content
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<Row> for ArticleRevision {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let article_id = Uuid::from_str(row.read::<&str, _>("article_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(ArticleRevision {
+            id,
+            article_id,
+            feed_id,
+            title: row.read::<&str, _>("title").into(),
+            content: row.read::<&str, _>("content").into(),
+            created_at: DateTime::from_str(row.read::<&str, _>("created_at"))
+                .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+#[derive(Serialize, Clone)]
+pub struct Category {
+    pub id: Uuid,
+    /// Integer id for the clients that do not support UUIDs. Assigned when the category is saved
+    pub numeric_id: i64,
+    pub name: String,
+    pub unread_count: u16,
+}
+
+impl TryFrom<Row> for Category {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(Category {
+            id,
+            numeric_id: row.read::<i64, _>("numeric_id"),
+            name: row.read::<&str, _>("name").into(),
+            unread_count: row.read::<i64, _>("unread_count").clamp(0, u16::MAX as i64) as u16,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// An edition of the digest, an EPUB book with the unread articles found since the previous one
+#[derive(Serialize, Clone, Debug)]
+pub struct Digest {
+    pub id: Uuid,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub article_count: usize,
+    /// Path of the EPUB file
+    pub file_path: String,
+}
+
+impl TryFrom<Row> for Digest {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let created_at = DateTime::from_str(row.read::<&str, _>("created_at"))
+            .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(Digest {
+            id,
+            title: row.read::<&str, _>("title").into(),
+            created_at,
+            article_count: row.read::<i64, _>("article_count") as usize,
+            file_path: row.read::<&str, _>("file_path").into(),
+        })
+    }
+}
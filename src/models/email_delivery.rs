@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use super::webhook::{read_date, DeliveryStatus, MAX_DELIVERY_ATTEMPTS};
+use crate::repositories::RepositoryError;
+
+/// Wait before the first retry of a failed email. It doubles with every attempt
+const FIRST_RETRY_DELAY_SECONDS: i64 = 5 * 60;
+
+/// A document sent to the e-reader by email, with an EPUB attachment. They are queued in the
+/// outbox and sent in the background
+#[derive(Serialize, Clone, Debug)]
+pub struct EmailDelivery {
+    pub id: Uuid,
+    /// Subject of the email, the title of the document
+    pub title: String,
+    /// Path of the attached EPUB file
+    pub file_path: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// Error of the last attempt
+    pub error: Option<String>,
+}
+
+impl EmailDelivery {
+    pub fn new(title: String, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            title,
+            file_path: String::new(),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            created_at: now,
+            next_attempt_at: now,
+            last_attempt_at: None,
+            error: None,
+        }
+    }
+
+    /// Records the result of an attempt. A failed email is retried with an exponential backoff
+    /// until it reaches `MAX_DELIVERY_ATTEMPTS`
+    pub fn record_attempt(&mut self, now: DateTime<Utc>, error: Option<String>) {
+        self.attempts += 1;
+        self.last_attempt_at = Some(now);
+
+        if error.is_none() {
+            self.status = DeliveryStatus::Delivered;
+            self.error = None;
+            return;
+        }
+
+        self.error = error;
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            let delay = FIRST_RETRY_DELAY_SECONDS << (self.attempts - 1);
+            self.next_attempt_at = now + TimeDelta::seconds(delay);
+        }
+    }
+}
+
+impl TryFrom<Row> for EmailDelivery {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let missing_date = |column: &str| {
+            RepositoryError::Deserialization(anyhow::anyhow!("missing {column} in email {id}"))
+        };
+
+        Ok(EmailDelivery {
+            id,
+            title: row.read::<&str, _>("title").into(),
+            file_path: row.read::<&str, _>("file_path").into(),
+            status: DeliveryStatus::from_str(row.read::<&str, _>("status"))
+                .map_err(RepositoryError::Deserialization)?,
+            attempts: row.read::<i64, _>("attempts") as u32,
+            created_at: read_date(&row, "created_at")?
+                .ok_or_else(|| missing_date("created_at"))?,
+            next_attempt_at: read_date(&row, "next_attempt_at")?
+                .ok_or_else(|| missing_date("next_attempt_at"))?,
+            last_attempt_at: read_date(&row, "last_attempt_at")?,
+            error: row.read::<Option<&str>, _>("error").map(String::from),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_emails_are_retried_with_backoff() {
+        let now = Utc::now();
+        let mut delivery = EmailDelivery::new("Article".to_owned(), now);
+
+        delivery.record_attempt(now, Some("connection refused".to_owned()));
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, now + TimeDelta::minutes(5));
+
+        delivery.record_attempt(now, Some("connection refused".to_owned()));
+        assert_eq!(delivery.next_attempt_at, now + TimeDelta::minutes(10));
+
+        delivery.record_attempt(now, None);
+        assert_eq!(delivery.status, DeliveryStatus::Delivered);
+        assert_eq!(delivery.attempts, 3);
+        assert_eq!(delivery.error, None);
+
+        let mut delivery = EmailDelivery::new("Article".to_owned(), now);
+        for _ in 0..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_attempt(now, Some("authentication failed".to_owned()));
+        }
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+    }
+}
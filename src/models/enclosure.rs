@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// A file attached to an article, usually a podcast episode
+#[derive(Serialize, Clone, Debug)]
+pub struct Enclosure {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub feed_id: Uuid,
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// Size in bytes, as declared by the feed
+    pub length: Option<u64>,
+    /// Duration in seconds
+    pub duration: Option<u32>,
+    /// If the enclosure was downloaded to the server, the url it is served from
+    pub cached_url: Option<String>,
+}
+
+impl Enclosure {
+    /// Parses an `itunes:duration`, that can be expressed in seconds, `MM:SS` or `HH:MM:SS`
+    pub fn parse_duration(duration: &str) -> Option<u32> {
+        duration
+            .trim()
+            .split(':')
+            .try_fold((0u32, 0), |(seconds, parts), part| {
+                let value = part.trim().parse::<u32>().ok()?;
+                Some((seconds.checked_mul(60)?.checked_add(value)?, parts + 1))
+            })
+            .filter(|(_, parts)| *parts <= 3)
+            .map(|(seconds, _)| seconds)
+    }
+}
+
+impl TryFrom<Row> for Enclosure {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let article_id = Uuid::from_str(row.read::<&str, _>("article_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(Enclosure {
+            id,
+            article_id,
+            feed_id,
+            url: row.read::<&str, _>("url").into(),
+            mime_type: row.read::<Option<&str>, _>("mime_type").map(Into::into),
+            length: row
+                .read::<Option<i64>, _>("length")
+                .and_then(|l| u64::try_from(l).ok()),
+            duration: row
+                .read::<Option<i64>, _>("duration")
+                .and_then(|d| u32::try_from(d).ok()),
+            cached_url: row.read::<Option<&str>, _>("cached_url").map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_itunes_duration() {
+        let cases = [
+            ("3600", Some(3600)),
+            ("05:30", Some(330)),
+            ("1:02:03", Some(3723)),
+            (" 42 ", Some(42)),
+            ("", None),
+            ("1:2:3:4", None),
+            ("ten minutes", None),
+        ];
+
+        for (duration, expected) in cases {
+            assert_eq!(expected, Enclosure::parse_duration(duration), "{duration}");
+        }
+    }
+}
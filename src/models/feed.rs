@@ -3,21 +3,45 @@
 use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlite::Row;
 use uuid::Uuid;
 
 use crate::repositories::RepositoryError;
 
-#[derive(Serialize)]
+/// Where the content of the articles of a feed comes from
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentMode {
+    /// Use the content included in the feed, download the web page only if there is none
+    #[default]
+    Feed,
+    /// Always download the web page of the article
+    Full,
+    /// Download the web page if the content included in the feed looks truncated
+    Auto,
+}
+
+impl_str_conversions!(
+    ContentMode,
+    ContentMode::Feed => "feed",
+    ContentMode::Full => "full",
+    ContentMode::Auto => "auto"
+);
+
+#[derive(Serialize, Clone)]
 pub struct Feed {
     pub id: Uuid,
+    /// Integer id for the clients that do not support UUIDs. Assigned when the feed is saved
+    pub numeric_id: i64,
     pub title: String,
     pub url: String,
     pub link: String,
     pub favicon_url: Option<String>,
     pub last_updated: DateTime<Utc>,
     pub unread_count: u16,
+    pub category_id: Option<Uuid>,
+    pub content_mode: ContentMode,
 }
 
 impl TryFrom<Row> for Feed {
@@ -27,8 +51,15 @@ impl TryFrom<Row> for Feed {
         let id = Uuid::from_str(row.read::<&str, _>("id"))
             .map_err(|e| RepositoryError::Deserialization(e.into()))?;
 
+        let category_id = row
+            .read::<Option<&str>, _>("category_id")
+            .map(Uuid::from_str)
+            .transpose()
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
         Ok(Feed {
             id,
+            numeric_id: row.read::<i64, _>("numeric_id"),
             title: row.read::<&str, _>("title").into(),
             url: row.read::<&str, _>("url").into(),
             link: row.read::<&str, _>("link").into(),
@@ -38,6 +69,9 @@ impl TryFrom<Row> for Feed {
             last_updated: DateTime::from_str(row.read::<&str, _>("last_updated"))
                 .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?,
             unread_count: row.read::<i64, _>("unread_count").clamp(0, u16::MAX as i64) as u16,
+            category_id,
+            content_mode: ContentMode::from_str(row.read::<&str, _>("content_mode"))
+                .map_err(RepositoryError::Deserialization)?,
         })
     }
 }
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// Field of the article the pattern of a rule is matched against
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterField {
+    Title,
+    Author,
+    Content,
+    Any,
+}
+
+/// How the pattern of a rule is matched
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterMatchType {
+    /// Case insensitive substring
+    Substring,
+    /// Case insensitive regular expression
+    Regex,
+}
+
+/// What to do with an article matching a rule when it is ingested
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterAction {
+    /// The article is not added to the feed
+    Skip,
+    MarkAsRead,
+    Star,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct FilterRule {
+    pub id: Uuid,
+    /// If it is `None` the rule applies to all the feeds
+    pub feed_id: Option<Uuid>,
+    pub field: FilterField,
+    pub match_type: FilterMatchType,
+    pub pattern: String,
+    pub action: FilterAction,
+}
+
+/// A filter rule with its pattern lowercased or compiled, to match it against many articles
+pub struct FilterMatcher {
+    pub field: FilterField,
+    pub action: FilterAction,
+    pattern: FilterPattern,
+}
+
+enum FilterPattern {
+    /// Lowercased substring
+    Substring(String),
+    Regex(Regex),
+    /// A regex that does not compile never matches
+    Invalid,
+}
+
+impl From<&FilterRule> for FilterMatcher {
+    fn from(rule: &FilterRule) -> Self {
+        let pattern = match rule.match_type {
+            FilterMatchType::Substring => FilterPattern::Substring(rule.pattern.to_lowercase()),
+            FilterMatchType::Regex => match RegexBuilder::new(&rule.pattern)
+                .case_insensitive(true)
+                .build()
+            {
+                Ok(regex) => FilterPattern::Regex(regex),
+                Err(e) => {
+                    tracing::warn!("invalid regex in filter rule {}: {e}", rule.id);
+                    FilterPattern::Invalid
+                }
+            },
+        };
+
+        Self {
+            field: rule.field,
+            action: rule.action,
+            pattern,
+        }
+    }
+}
+
+impl FilterMatcher {
+    /// Checks if the rule matches the article data. Content is the HTML of the article, if
+    /// available.
+    pub fn matches(&self, title: &str, author: Option<&str>, content: Option<&str>) -> bool {
+        let fields: Vec<&str> = match self.field {
+            FilterField::Title => vec![title],
+            FilterField::Author => author.into_iter().collect(),
+            FilterField::Content => content.into_iter().collect(),
+            FilterField::Any => [Some(title), author, content].into_iter().flatten().collect(),
+        };
+
+        match &self.pattern {
+            FilterPattern::Substring(pattern) => fields
+                .into_iter()
+                .any(|f| f.to_lowercase().contains(pattern)),
+            FilterPattern::Regex(regex) => fields.into_iter().any(|f| regex.is_match(f)),
+            FilterPattern::Invalid => false,
+        }
+    }
+}
+
+impl_str_conversions!(
+    FilterField,
+    FilterField::Title => "title",
+    FilterField::Author => "author",
+    FilterField::Content => "content",
+    FilterField::Any => "any"
+);
+
+impl_str_conversions!(
+    FilterMatchType,
+    FilterMatchType::Substring => "substring",
+    FilterMatchType::Regex => "regex"
+);
+
+impl_str_conversions!(
+    FilterAction,
+    FilterAction::Skip => "skip",
+    FilterAction::MarkAsRead => "mark_as_read",
+    FilterAction::Star => "star"
+);
+
+impl TryFrom<Row> for FilterRule {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = row
+            .read::<Option<&str>, _>("feed_id")
+            .map(Uuid::from_str)
+            .transpose()
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(FilterRule {
+            id,
+            feed_id,
+            field: FilterField::from_str(row.read::<&str, _>("field"))
+                .map_err(RepositoryError::Deserialization)?,
+            match_type: FilterMatchType::from_str(row.read::<&str, _>("match_type"))
+                .map_err(RepositoryError::Deserialization)?,
+            pattern: row.read::<&str, _>("pattern").into(),
+            action: FilterAction::from_str(row.read::<&str, _>("action"))
+                .map_err(RepositoryError::Deserialization)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(field: FilterField, match_type: FilterMatchType, pattern: &str) -> FilterMatcher {
+        FilterMatcher::from(&FilterRule {
+            id: Uuid::new_v4(),
+            feed_id: None,
+            field,
+            match_type,
+            pattern: pattern.to_owned(),
+            action: FilterAction::Skip,
+        })
+    }
+
+    #[test]
+    fn substring_is_case_insensitive() {
+        let rule = rule(FilterField::Title, FilterMatchType::Substring, "sponsored");
+
+        assert!(rule.matches("[SPONSORED] Buy this", None, None));
+        assert!(!rule.matches("A regular post", None, None));
+    }
+
+    #[test]
+    fn regex_matches_the_selected_field() {
+        let rule = rule(FilterField::Author, FilterMatchType::Regex, r"^(bot|ads)\b");
+
+        assert!(rule.matches("Title", Some("Ads team"), None));
+        assert!(!rule.matches("ads everywhere", Some("Jane"), None));
+        assert!(!rule.matches("Title", None, None));
+    }
+
+    #[test]
+    fn any_field_matches_title_author_and_content() {
+        let rule = rule(FilterField::Any, FilterMatchType::Substring, "crypto");
+
+        assert!(rule.matches("Crypto news", None, None));
+        assert!(rule.matches("News", Some("crypto desk"), None));
+        assert!(rule.matches("News", None, Some("<p>about crypto</p>")));
+        assert!(!rule.matches("News", Some("Jane"), Some("<p>about rust</p>")));
+    }
+
+    #[test]
+    fn invalid_regex_does_not_match() {
+        let rule = rule(FilterField::Title, FilterMatchType::Regex, "(unclosed");
+
+        assert!(!rule.matches("(unclosed", None, None));
+    }
+}
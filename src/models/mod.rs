@@ -1,6 +1,42 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+/// Implements `as_str` and `FromStr` for enums that are stored as text in the database
+macro_rules! impl_str_conversions {
+    ($type: ty, $($variant: path => $value: literal),+) => {
+        impl $type {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($variant => $value),+
+                }
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok($variant),)+
+                    _ => Err(anyhow::anyhow!("invalid value {s} for {}", stringify!($type))),
+                }
+            }
+        }
+    };
+}
+
 pub mod article;
+pub mod article_filter;
+pub mod article_revision;
+pub mod category;
+pub mod digest;
+pub mod email_delivery;
+pub mod enclosure;
 pub mod feed;
+pub mod filter_rule;
 pub mod parsed_feed;
 pub mod persisted_config;
+pub mod rewrite_rule;
+pub mod saved_article;
+pub mod tag;
+pub mod webhook;
+pub mod websub_subscription;
@@ -1,10 +1,15 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
 
 pub struct ParsedFeed {
     pub title: String,
     pub link: String,
+    /// WebSub hub the feed publishes its updates to (`<link rel="hub">`)
+    pub hub_url: Option<String>,
+    /// Canonical url of the feed (`<link rel="self">`), it is the topic of the WebSub subscription
+    pub self_url: Option<String>,
     pub items: Vec<ParsedItem>,
 }
 
@@ -12,7 +17,79 @@ pub struct ParsedItem {
     pub title: String,
     pub link: Option<String>,
     pub guid: Option<String>,
+    /// Full content of the item (RSS `content:encoded`, Atom `content`)
     pub content: Option<String>,
+    /// Summary of the item (RSS `description`, Atom `summary`). Many feeds include the full text
+    /// here instead of in the content
+    pub summary: Option<String>,
     pub author: Option<String>,
+    /// Publication date (RSS `pubDate`, Atom `published`)
     pub pub_date: Option<DateTime<Utc>>,
+    /// Date of the last update of the item (Atom `updated`)
+    pub updated: Option<DateTime<Utc>>,
+    pub enclosures: Vec<ParsedEnclosure>,
+    /// Preview image from the Media RSS elements of the item
+    pub thumbnail: Option<String>,
+    /// Categories of the entry (RSS `<category>`, Atom `<category term>`)
+    pub categories: Vec<String>,
+}
+
+pub struct ParsedEnclosure {
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// Size in bytes
+    pub length: Option<u64>,
+    /// Duration in seconds
+    pub duration: Option<u32>,
+}
+
+impl ParsedItem {
+    /// Returns the full content of the item, falling back to the summary if there is none
+    pub fn content_or_summary(&self) -> Option<&str> {
+        self.content.as_deref().or(self.summary.as_deref())
+    }
+
+    /// Hash of the title and content of the item, used to detect when the publisher updates it
+    pub fn content_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.title.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.content_or_summary().unwrap_or_default().as_bytes());
+
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, content: Option<&str>, summary: Option<&str>) -> ParsedItem {
+        ParsedItem {
+            title: title.to_owned(),
+            link: None,
+            guid: None,
+            content: content.map(Into::into),
+            summary: summary.map(Into::into),
+            author: None,
+            pub_date: None,
+            updated: None,
+            enclosures: vec![],
+            thumbnail: None,
+            categories: vec![],
+        }
+    }
+
+    #[test]
+    fn content_hash_changes_with_title_and_content() {
+        let original = item("Title", Some("<p>Content</p>"), None).content_hash();
+
+        assert_eq!(64, original.len());
+        assert_eq!(
+            original,
+            item("Title", Some("<p>Content</p>"), Some("Summary")).content_hash()
+        );
+        assert_ne!(original, item("Title 2", Some("<p>Content</p>"), None).content_hash());
+        assert_ne!(original, item("Title", Some("<p>Content!</p>"), None).content_hash());
+    }
 }
@@ -1,6 +1,216 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Local, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::article::Article;
+
+/// Order of the article lists
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ArticleSort {
+    /// By publication date, the most recent first
+    #[default]
+    Newest,
+    /// By publication date, the oldest first
+    Oldest,
+    /// In the order the articles appeared in their feed
+    FeedOrder,
+}
+
+impl ArticleSort {
+    pub fn sort(self, articles: &mut [Article]) {
+        match self {
+            ArticleSort::Newest => articles.sort_by_key(|a| Reverse(a.published_at)),
+            ArticleSort::Oldest => articles.sort_by_key(|a| a.published_at),
+            ArticleSort::FeedOrder => articles.sort_by_key(|a| Reverse(a.found_at)),
+        }
+    }
+}
+
+/// Settings of the daily digest
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DigestConfig {
+    /// Whether the digest is created every day
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Hour of the day the digest is created, in the local time of the server
+    #[serde(default = "default_digest_hour")]
+    pub hour: u32,
+
+    /// Feeds whose articles are included. If neither feeds nor categories are selected, all the
+    /// feeds are included
+    #[serde(default)]
+    pub feed_ids: Vec<Uuid>,
+
+    /// Categories whose feeds are included
+    #[serde(default)]
+    pub category_ids: Vec<Uuid>,
+
+    /// Number of digests kept, the older ones are deleted
+    #[serde(default = "default_digest_editions")]
+    pub editions: usize,
+
+    /// Whether the articles included in a digest are marked as read
+    #[serde(default)]
+    pub mark_as_read: bool,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hour: default_digest_hour(),
+            feed_ids: vec![],
+            category_ids: vec![],
+            editions: default_digest_editions(),
+            mark_as_read: false,
+        }
+    }
+}
+
+impl DigestConfig {
+    /// Checks if the articles of the feed are included in the digest
+    pub fn includes(&self, feed: &super::feed::Feed) -> bool {
+        (self.feed_ids.is_empty() && self.category_ids.is_empty())
+            || self.feed_ids.contains(&feed.id)
+            || feed
+                .category_id
+                .is_some_and(|category_id| self.category_ids.contains(&category_id))
+    }
+
+    /// Returns the last time a digest was scheduled for, until `now`
+    pub fn last_scheduled_time(&self, now: DateTime<Local>) -> DateTime<Utc> {
+        let scheduled = |days_ago: i64| {
+            (now.date_naive() - TimeDelta::days(days_ago))
+                .and_hms_opt(self.hour.min(23), 0, 0)
+                .and_then(|time| time.and_local_timezone(Local).earliest())
+                // The hour does not exist that day (the clocks are moved forward)
+                .unwrap_or(now)
+                .to_utc()
+        };
+
+        let today = scheduled(0);
+        if today <= now.to_utc() {
+            today
+        } else {
+            scheduled(1)
+        }
+    }
+}
+
+/// Security of the connection to the SMTP server
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Plain connection, only for servers in a trusted network
+    None,
+    /// Plain connection upgraded with STARTTLS, usually on port 587
+    StartTls,
+    /// TLS from the start of the connection, usually on port 465
+    #[default]
+    Tls,
+}
+
+/// SMTP server used to send documents to the e-reader by email (like Send to Kindle)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SmtpConfig {
+    #[serde(default)]
+    pub host: String,
+
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+
+    #[serde(default)]
+    pub security: SmtpSecurity,
+
+    /// If it is empty, the server is used without authentication
+    #[serde(default)]
+    pub username: String,
+
+    #[serde(default)]
+    pub password: String,
+
+    /// Sender of the emails. Amazon only accepts the addresses approved in the Kindle account
+    #[serde(default)]
+    pub from: String,
+
+    /// Email address of the device
+    #[serde(default)]
+    pub recipient: String,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: default_smtp_port(),
+            security: SmtpSecurity::default(),
+            username: String::new(),
+            password: String::new(),
+            from: String::new(),
+            recipient: String::new(),
+        }
+    }
+}
+
+impl SmtpConfig {
+    /// Checks if the documents can be sent
+    pub fn is_configured(&self) -> bool {
+        !self.host.is_empty() && !self.from.is_empty() && !self.recipient.is_empty()
+    }
+}
+
+/// Wallabag server the articles are saved to, with the credentials of one of its API clients
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct WallabagConfig {
+    /// Base url of the server, for example https://app.wallabag.it
+    #[serde(default)]
+    pub url: String,
+
+    #[serde(default)]
+    pub client_id: String,
+
+    #[serde(default)]
+    pub client_secret: String,
+
+    #[serde(default)]
+    pub username: String,
+
+    #[serde(default)]
+    pub password: String,
+}
+
+impl WallabagConfig {
+    /// Checks if the articles can be saved
+    pub fn is_configured(&self) -> bool {
+        [&self.url, &self.client_id, &self.client_secret, &self.username, &self.password]
+            .iter()
+            .all(|value| !value.is_empty())
+    }
+}
+
+/// Endpoint that receives the url of the saved articles with a POST
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct SaveEndpointConfig {
+    #[serde(default)]
+    pub url: String,
+
+    /// If it is set, it is sent as a bearer token in the `Authorization` header
+    #[serde(default)]
+    pub token: String,
+}
+
+impl SaveEndpointConfig {
+    /// Checks if the articles can be saved
+    pub fn is_configured(&self) -> bool {
+        !self.url.is_empty()
+    }
+}
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct PersistedConfig {
@@ -18,6 +228,24 @@ pub struct PersistedConfig {
 
     #[serde(default)]
     pub dont_invert_images: bool,
+
+    #[serde(default)]
+    pub show_thumbnails: bool,
+
+    #[serde(default)]
+    pub article_sort: ArticleSort,
+
+    #[serde(default)]
+    pub digest: DigestConfig,
+
+    #[serde(default)]
+    pub smtp: SmtpConfig,
+
+    #[serde(default)]
+    pub wallabag: WallabagConfig,
+
+    #[serde(default)]
+    pub save_endpoint: SaveEndpointConfig,
 }
 
 impl Default for PersistedConfig {
@@ -28,10 +256,96 @@ impl Default for PersistedConfig {
             toolbar_position_left: false,
             hide_article_header: false,
             dont_invert_images: false,
+            show_thumbnails: false,
+            article_sort: ArticleSort::default(),
+            digest: DigestConfig::default(),
+            smtp: SmtpConfig::default(),
+            wallabag: WallabagConfig::default(),
+            save_endpoint: SaveEndpointConfig::default(),
         }
     }
 }
 
+impl PersistedConfig {
+    /// Removes the passwords, to show the configuration
+    pub fn without_secrets(mut self) -> Self {
+        self.smtp.password = String::new();
+        self.wallabag.client_secret = String::new();
+        self.wallabag.password = String::new();
+        self.save_endpoint.token = String::new();
+        self
+    }
+}
+
 fn default_zoom() -> f64 {
     1.0_f64
 }
+
+fn default_digest_hour() -> u32 {
+    6
+}
+
+fn default_digest_editions() -> usize {
+    7
+}
+
+fn default_smtp_port() -> u16 {
+    465
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::feed::{ContentMode, Feed};
+    use chrono::TimeZone;
+
+    fn feed(category_id: Option<Uuid>) -> Feed {
+        Feed {
+            id: Uuid::new_v4(),
+            numeric_id: 1,
+            title: "Feed".to_owned(),
+            url: "http://example.com/feed".to_owned(),
+            link: "http://example.com".to_owned(),
+            favicon_url: None,
+            last_updated: Utc::now(),
+            unread_count: 0,
+            category_id,
+            content_mode: ContentMode::Auto,
+        }
+    }
+
+    #[test]
+    fn test_digest_includes() {
+        let category_id = Uuid::new_v4();
+        let selected = feed(None);
+        let in_category = feed(Some(category_id));
+        let other = feed(Some(Uuid::new_v4()));
+
+        // Without a selection every feed is included
+        let config = DigestConfig::default();
+        assert!(config.includes(&selected));
+        assert!(config.includes(&other));
+
+        let config = DigestConfig {
+            feed_ids: vec![selected.id],
+            category_ids: vec![category_id],
+            ..Default::default()
+        };
+        assert!(config.includes(&selected));
+        assert!(config.includes(&in_category));
+        assert!(!config.includes(&other));
+    }
+
+    #[test]
+    fn test_digest_last_scheduled_time() {
+        let config = DigestConfig {
+            hour: 6,
+            ..Default::default()
+        };
+        let at = |day: u32, hour: u32| Local.with_ymd_and_hms(2024, 3, day, hour, 0, 0).unwrap();
+
+        assert_eq!(at(10, 6).to_utc(), config.last_scheduled_time(at(10, 6)));
+        assert_eq!(at(10, 6).to_utc(), config.last_scheduled_time(at(10, 23)));
+        assert_eq!(at(9, 6).to_utc(), config.last_scheduled_time(at(10, 5)));
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// What a rewrite rule does with the HTML of an article
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum RewriteRuleKind {
+    /// Replaces the matches of a regex with the replacement
+    RegexReplace,
+    /// Removes the elements matched by a CSS selector
+    RemoveSelector,
+    /// Removes query params from the `href` and `src` attributes
+    StripUrlParams,
+}
+
+impl_str_conversions!(
+    RewriteRuleKind,
+    RewriteRuleKind::RegexReplace => "regex_replace",
+    RewriteRuleKind::RemoveSelector => "remove_selector",
+    RewriteRuleKind::StripUrlParams => "strip_url_params"
+);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct RewriteRule {
+    pub id: Uuid,
+    pub feed_id: Uuid,
+    pub kind: RewriteRuleKind,
+    /// Regex, CSS selector or comma separated list of query params, depending on the kind
+    pub pattern: String,
+    /// Only used by `RegexReplace`
+    pub replacement: Option<String>,
+}
+
+impl RewriteRule {
+    /// Returns the query params to strip of a `StripUrlParams` rule
+    pub fn url_params(&self) -> Vec<&str> {
+        self.pattern
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect()
+    }
+}
+
+impl TryFrom<Row> for RewriteRule {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(RewriteRule {
+            id,
+            feed_id,
+            kind: RewriteRuleKind::from_str(row.read::<&str, _>("kind"))
+                .map_err(RepositoryError::Deserialization)?,
+            pattern: row.read::<&str, _>("pattern").into(),
+            replacement: row.read::<Option<&str>, _>("replacement").map(Into::into),
+        })
+    }
+}
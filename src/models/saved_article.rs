@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// Read-it-later services the articles can be saved to
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SaveTarget {
+    /// A Wallabag server, through its API
+    Wallabag,
+    /// Any endpoint that accepts the url of the article with a POST
+    Endpoint,
+}
+
+impl_str_conversions!(
+    SaveTarget,
+    SaveTarget::Wallabag => "wallabag",
+    SaveTarget::Endpoint => "endpoint"
+);
+
+impl SaveTarget {
+    /// Name shown to the user
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SaveTarget::Wallabag => "Wallabag",
+            SaveTarget::Endpoint => "read-it-later endpoint",
+        }
+    }
+}
+
+/// Record of an article saved to a read-it-later service
+#[derive(Serialize, Clone, Debug)]
+pub struct SavedArticle {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub feed_id: Uuid,
+    pub target: SaveTarget,
+    pub saved_at: DateTime<Utc>,
+    /// Url of the article in the service, if it is known
+    pub url: Option<String>,
+}
+
+impl TryFrom<Row> for SavedArticle {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let article_id = Uuid::from_str(row.read::<&str, _>("article_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let saved_at = DateTime::from_str(row.read::<&str, _>("saved_at"))
+            .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(SavedArticle {
+            id,
+            article_id,
+            feed_id,
+            target: SaveTarget::from_str(row.read::<&str, _>("target"))
+                .map_err(RepositoryError::Deserialization)?,
+            saved_at,
+            url: row.read::<Option<&str>, _>("url").map(String::from),
+        })
+    }
+}
@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// Maximum length (in characters) of a tag name
+const TAG_NAME_MAX_LENGTH: usize = 50;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ArticleTag {
+    pub article_id: Uuid,
+    pub feed_id: Uuid,
+    pub name: String,
+    /// Whether the tag was added by the user or it came from the feed
+    pub user_defined: bool,
+}
+
+impl ArticleTag {
+    /// Normalizes a tag name so the same tag written differently by different feeds is only
+    /// stored once: whitespace is collapsed and it is lowercased. Returns `None` if the name is
+    /// empty
+    pub fn normalize_name(name: &str) -> Option<String> {
+        let name = name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+            .chars()
+            .take(TAG_NAME_MAX_LENGTH)
+            .collect::<String>();
+
+        (!name.is_empty()).then_some(name)
+    }
+}
+
+impl TryFrom<Row> for ArticleTag {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let article_id = Uuid::from_str(row.read::<&str, _>("article_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(ArticleTag {
+            article_id,
+            feed_id,
+            name: row.read::<&str, _>("name").into(),
+            user_defined: row.read::<i64, _>("user_defined") != 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_names_are_normalized() {
+        let cases = [
+            ("Rust", Some("rust".to_owned())),
+            ("  Open   Source\n", Some("open source".to_owned())),
+            ("   ", None),
+            (&"a".repeat(80), Some("a".repeat(TAG_NAME_MAX_LENGTH))),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(expected, ArticleTag::normalize_name(name), "{name}");
+        }
+    }
+}
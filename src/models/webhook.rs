@@ -0,0 +1,338 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::{
+    models::{article::Article, feed::Feed},
+    repositories::RepositoryError,
+};
+
+/// Times a delivery is attempted before giving up
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 6;
+
+/// Wait before the first retry of a failed delivery. It doubles with every attempt
+const FIRST_RETRY_DELAY_SECONDS: i64 = 60;
+
+/// An URL that is called with the new articles found in the feeds
+#[derive(Serialize, Clone, Debug)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: String,
+    /// If it is set, only the new articles of this feed are sent
+    pub feed_id: Option<Uuid>,
+    /// If it is set, only the new articles of the feeds of this category are sent
+    pub category_id: Option<Uuid>,
+    /// Key used to sign the payload (HMAC-SHA256)
+    pub secret: Option<String>,
+}
+
+impl Webhook {
+    /// Checks if the new articles of the feed must be sent to the webhook
+    pub fn applies_to(&self, feed: &Feed) -> bool {
+        self.feed_id.is_none_or(|feed_id| feed_id == feed.id)
+            && self
+                .category_id
+                .is_none_or(|category_id| feed.category_id == Some(category_id))
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting for its first attempt or a retry
+    Pending,
+    Delivered,
+    /// It ran out of attempts
+    Failed,
+}
+
+impl_str_conversions!(
+    DeliveryStatus,
+    DeliveryStatus::Pending => "pending",
+    DeliveryStatus::Delivered => "delivered",
+    DeliveryStatus::Failed => "failed"
+);
+
+/// A call to a webhook. They are queued when the articles are found and sent in the background
+#[derive(Serialize, Clone, Debug)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    /// JSON body sent to the webhook
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status code of the response of the last attempt
+    pub response_status: Option<u16>,
+    /// Error of the last attempt
+    pub error: Option<String>,
+}
+
+impl WebhookDelivery {
+    pub fn new(webhook_id: Uuid, payload: String, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            webhook_id,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            created_at: now,
+            next_attempt_at: now,
+            last_attempt_at: None,
+            response_status: None,
+            error: None,
+        }
+    }
+
+    /// Records the result of an attempt. A failed delivery is retried with an exponential backoff
+    /// until it reaches `MAX_DELIVERY_ATTEMPTS`
+    pub fn record_attempt(
+        &mut self,
+        now: DateTime<Utc>,
+        response_status: Option<u16>,
+        error: Option<String>,
+    ) {
+        self.attempts += 1;
+        self.last_attempt_at = Some(now);
+        self.response_status = response_status;
+
+        if error.is_none() && response_status.is_some_and(|status| (200..300).contains(&status)) {
+            self.status = DeliveryStatus::Delivered;
+            self.error = None;
+            return;
+        }
+
+        self.error = error.or_else(|| {
+            response_status.map(|status| format!("the webhook responded with status {status}"))
+        });
+
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            let delay = FIRST_RETRY_DELAY_SECONDS << (self.attempts - 1);
+            self.next_attempt_at = now + TimeDelta::seconds(delay);
+        }
+    }
+}
+
+/// Event sent when new articles are found in a feed
+pub const WEBHOOK_EVENT_NEW_ARTICLES: &str = "new_articles";
+
+/// JSON body of the calls to the webhooks
+#[derive(Serialize)]
+pub struct WebhookPayload<'a> {
+    event: &'static str,
+    feed: WebhookFeed<'a>,
+    articles: Vec<WebhookArticle<'a>>,
+}
+
+#[derive(Serialize)]
+struct WebhookFeed<'a> {
+    id: Uuid,
+    numeric_id: i64,
+    title: &'a str,
+    url: &'a str,
+    link: &'a str,
+    category_id: Option<Uuid>,
+}
+
+#[derive(Serialize)]
+struct WebhookArticle<'a> {
+    id: Uuid,
+    numeric_id: i64,
+    title: &'a str,
+    author: Option<&'a str>,
+    link: &'a str,
+    summary: Option<&'a str>,
+    published_at: DateTime<Utc>,
+    read: bool,
+    starred: bool,
+}
+
+impl<'a> WebhookPayload<'a> {
+    pub fn new_articles(feed: &'a Feed, articles: &[&'a Article]) -> Self {
+        Self {
+            event: WEBHOOK_EVENT_NEW_ARTICLES,
+            feed: WebhookFeed {
+                id: feed.id,
+                numeric_id: feed.numeric_id,
+                title: &feed.title,
+                url: &feed.url,
+                link: &feed.link,
+                category_id: feed.category_id,
+            },
+            articles: articles
+                .iter()
+                .map(|article| WebhookArticle {
+                    id: article.id,
+                    numeric_id: article.numeric_id,
+                    title: &article.title,
+                    author: article.author.as_deref(),
+                    link: &article.link,
+                    summary: article.summary.as_deref(),
+                    published_at: article.published_at,
+                    read: article.read,
+                    starred: article.starred,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub(super) fn read_date(
+    row: &Row,
+    column: &str,
+) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+    row.read::<Option<&str>, _>(column)
+        .map(DateTime::from_str)
+        .transpose()
+        .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))
+}
+
+impl TryFrom<Row> for Webhook {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let feed_id = row
+            .read::<Option<&str>, _>("feed_id")
+            .map(Uuid::from_str)
+            .transpose()
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let category_id = row
+            .read::<Option<&str>, _>("category_id")
+            .map(Uuid::from_str)
+            .transpose()
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        Ok(Webhook {
+            id,
+            url: row.read::<&str, _>("url").into(),
+            feed_id,
+            category_id,
+            secret: row.read::<Option<&str>, _>("secret").map(String::from),
+        })
+    }
+}
+
+impl TryFrom<Row> for WebhookDelivery {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let id = Uuid::from_str(row.read::<&str, _>("id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let webhook_id = Uuid::from_str(row.read::<&str, _>("webhook_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let missing_date = |column: &str| {
+            RepositoryError::Deserialization(anyhow::anyhow!("missing {column} in delivery {id}"))
+        };
+
+        Ok(WebhookDelivery {
+            id,
+            webhook_id,
+            payload: row.read::<&str, _>("payload").into(),
+            status: DeliveryStatus::from_str(row.read::<&str, _>("status"))
+                .map_err(RepositoryError::Deserialization)?,
+            attempts: row.read::<i64, _>("attempts") as u32,
+            created_at: read_date(&row, "created_at")?
+                .ok_or_else(|| missing_date("created_at"))?,
+            next_attempt_at: read_date(&row, "next_attempt_at")?
+                .ok_or_else(|| missing_date("next_attempt_at"))?,
+            last_attempt_at: read_date(&row, "last_attempt_at")?,
+            response_status: row
+                .read::<Option<i64>, _>("response_status")
+                .map(|status| status as u16),
+            error: row.read::<Option<&str>, _>("error").map(String::from),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::feed::ContentMode;
+
+    fn feed(category_id: Option<Uuid>) -> Feed {
+        Feed {
+            id: Uuid::new_v4(),
+            numeric_id: 1,
+            title: "Feed".to_owned(),
+            url: "http://example.com/feed".to_owned(),
+            link: "http://example.com".to_owned(),
+            favicon_url: None,
+            last_updated: Utc::now(),
+            unread_count: 0,
+            category_id,
+            content_mode: ContentMode::Auto,
+        }
+    }
+
+    fn webhook(feed_id: Option<Uuid>, category_id: Option<Uuid>) -> Webhook {
+        Webhook {
+            id: Uuid::new_v4(),
+            url: "http://example.com/hook".to_owned(),
+            feed_id,
+            category_id,
+            secret: None,
+        }
+    }
+
+    #[test]
+    fn webhook_applies_to_its_feed_or_category() {
+        let category_id = Uuid::new_v4();
+        let categorized = feed(Some(category_id));
+        let uncategorized = feed(None);
+
+        assert!(webhook(None, None).applies_to(&categorized));
+        assert!(webhook(None, None).applies_to(&uncategorized));
+        assert!(webhook(Some(categorized.id), None).applies_to(&categorized));
+        assert!(!webhook(Some(categorized.id), None).applies_to(&uncategorized));
+        assert!(webhook(None, Some(category_id)).applies_to(&categorized));
+        assert!(!webhook(None, Some(category_id)).applies_to(&uncategorized));
+    }
+
+    #[test]
+    fn successful_attempt_delivers() {
+        let now = Utc::now();
+        let mut delivery = WebhookDelivery::new(Uuid::new_v4(), "{}".to_owned(), now);
+        delivery.record_attempt(now, Some(204), None);
+
+        assert_eq!(delivery.status, DeliveryStatus::Delivered);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.error, None);
+    }
+
+    #[test]
+    fn failed_attempts_are_retried_with_backoff() {
+        let now = Utc::now();
+        let mut delivery = WebhookDelivery::new(Uuid::new_v4(), "{}".to_owned(), now);
+
+        delivery.record_attempt(now, Some(500), None);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, now + TimeDelta::minutes(1));
+        assert!(delivery.error.as_deref().is_some_and(|e| e.contains("500")));
+
+        delivery.record_attempt(now, None, Some("connection refused".to_owned()));
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, now + TimeDelta::minutes(2));
+
+        for _ in 2..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_attempt(now, None, Some("connection refused".to_owned()));
+        }
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, MAX_DELIVERY_ATTEMPTS);
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use sqlite::Row;
+use uuid::Uuid;
+
+use crate::repositories::RepositoryError;
+
+/// Lease requested to the hubs, they may grant a different one
+pub const REQUESTED_LEASE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Time before the lease expires when the subscription is renewed
+const RENEWAL_MARGIN_HOURS: i64 = 24;
+
+/// Time to wait for the verification of the hub before requesting the subscription again
+const VERIFICATION_TIMEOUT_HOURS: i64 = 1;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The feed advertises a hub, but the subscription was not requested yet
+    Discovered,
+    /// Waiting for the hub to verify the subscription
+    Requested,
+    Active,
+    /// The hub refused the subscription
+    Denied,
+}
+
+impl_str_conversions!(
+    SubscriptionStatus,
+    SubscriptionStatus::Discovered => "discovered",
+    SubscriptionStatus::Requested => "requested",
+    SubscriptionStatus::Active => "active",
+    SubscriptionStatus::Denied => "denied"
+);
+
+/// Subscription to the WebSub hub of a feed
+#[derive(Serialize, Clone, Debug)]
+pub struct WebSubSubscription {
+    pub feed_id: Uuid,
+    pub hub_url: String,
+    /// Url of the feed the subscription is for
+    pub topic_url: String,
+    /// Key the hub uses to sign the pushed content
+    pub secret: String,
+    pub status: SubscriptionStatus,
+    pub lease_expires_at: Option<DateTime<Utc>>,
+    pub requested_at: Option<DateTime<Utc>>,
+}
+
+impl WebSubSubscription {
+    pub fn new(feed_id: Uuid, hub_url: String, topic_url: String) -> Self {
+        Self {
+            feed_id,
+            hub_url,
+            topic_url,
+            secret: Uuid::new_v4().simple().to_string(),
+            status: SubscriptionStatus::Discovered,
+            lease_expires_at: None,
+            requested_at: None,
+        }
+    }
+
+    /// Checks if the hub is pushing the updates of the feed. If it is not, the feed is polled
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.status == SubscriptionStatus::Active
+            && self.lease_expires_at.is_some_and(|expires_at| expires_at > now)
+    }
+
+    /// Checks if the subscription must be requested to the hub: it was not requested yet, the hub
+    /// did not verify it or its lease is about to expire
+    pub fn needs_request(&self, now: DateTime<Utc>) -> bool {
+        let requested_before =
+            |hours: i64| self.requested_at.is_none_or(|at| at < now - TimeDelta::hours(hours));
+
+        match self.status {
+            SubscriptionStatus::Discovered => true,
+            SubscriptionStatus::Requested => requested_before(VERIFICATION_TIMEOUT_HOURS),
+            SubscriptionStatus::Active => {
+                self.lease_expires_at.is_none_or(|expires_at| {
+                    expires_at < now + TimeDelta::hours(RENEWAL_MARGIN_HOURS)
+                }) && requested_before(VERIFICATION_TIMEOUT_HOURS)
+            }
+            SubscriptionStatus::Denied => false,
+        }
+    }
+}
+
+impl TryFrom<Row> for WebSubSubscription {
+    type Error = RepositoryError;
+
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        let feed_id = Uuid::from_str(row.read::<&str, _>("feed_id"))
+            .map_err(|e| RepositoryError::Deserialization(e.into()))?;
+
+        let read_date = |column: &str| {
+            row.read::<Option<&str>, _>(column)
+                .map(DateTime::from_str)
+                .transpose()
+                .map_err(|e: chrono::ParseError| RepositoryError::Deserialization(e.into()))
+        };
+
+        Ok(WebSubSubscription {
+            feed_id,
+            hub_url: row.read::<&str, _>("hub_url").into(),
+            topic_url: row.read::<&str, _>("topic_url").into(),
+            secret: row.read::<&str, _>("secret").into(),
+            status: SubscriptionStatus::from_str(row.read::<&str, _>("status"))
+                .map_err(RepositoryError::Deserialization)?,
+            lease_expires_at: read_date("lease_expires_at")?,
+            requested_at: read_date("requested_at")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(status: SubscriptionStatus) -> WebSubSubscription {
+        WebSubSubscription {
+            status,
+            ..WebSubSubscription::new(
+                Uuid::new_v4(),
+                "https://hub.example.com/".to_owned(),
+                "https://example.com/feed.xml".to_owned(),
+            )
+        }
+    }
+
+    #[test]
+    fn active_subscription_lapses_when_the_lease_expires() {
+        let now = Utc::now();
+        let mut active = subscription(SubscriptionStatus::Active);
+        active.lease_expires_at = Some(now + TimeDelta::days(3));
+
+        assert!(active.is_active(now));
+        assert!(!active.is_active(now + TimeDelta::days(4)));
+        assert!(!subscription(SubscriptionStatus::Requested).is_active(now));
+    }
+
+    #[test]
+    fn subscription_is_requested_again_before_the_lease_expires() {
+        let now = Utc::now();
+        let mut active = subscription(SubscriptionStatus::Active);
+        active.requested_at = Some(now - TimeDelta::days(5));
+        active.lease_expires_at = Some(now + TimeDelta::days(3));
+        assert!(!active.needs_request(now));
+
+        active.lease_expires_at = Some(now + TimeDelta::hours(2));
+        assert!(active.needs_request(now));
+
+        // It was just renewed, waiting for the verification of the hub
+        active.requested_at = Some(now);
+        assert!(!active.needs_request(now));
+    }
+
+    #[test]
+    fn unverified_subscription_is_requested_again() {
+        let now = Utc::now();
+        let mut requested = subscription(SubscriptionStatus::Requested);
+        requested.requested_at = Some(now - TimeDelta::minutes(10));
+        assert!(!requested.needs_request(now));
+
+        requested.requested_at = Some(now - TimeDelta::hours(2));
+        assert!(requested.needs_request(now));
+
+        assert!(subscription(SubscriptionStatus::Discovered).needs_request(now));
+        assert!(!subscription(SubscriptionStatus::Denied).needs_request(now));
+    }
+}
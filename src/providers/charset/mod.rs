@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Detection of the character encoding of the downloaded feeds and web pages, so they can be
+//! transcoded to UTF-8 before processing them. The encoding is taken, in order, from the byte
+//! order mark, the `Content-Type` header and the document itself (the XML declaration or the
+//! HTML `<meta>` tags). If none declares it, UTF-8 is assumed unless the content is not valid
+//! UTF-8, in which case Windows-1252 (the most common legacy encoding) is used.
+use std::{borrow::Cow, sync::LazyLock};
+
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+use regex::bytes::Regex;
+
+/// Only the beginning of the document is looked at to find the declared encoding
+const PRESCAN_LENGTH: usize = 1024;
+
+static XML_DECLARATION_ENCODING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*<\?xml[^>]*\bencoding\s*=\s*["']([^"']+)["']"#)
+        .expect("invalid XML declaration regex")
+});
+
+/// Matches both `<meta charset="...">` and
+/// `<meta http-equiv="Content-Type" content="text/html; charset=...">`
+static HTML_META_CHARSET: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)<meta[^>]+charset\s*=\s*["']?\s*([a-z0-9_\-:.]+)"#)
+        .expect("invalid HTML meta charset regex")
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentKind {
+    Xml,
+    Html,
+}
+
+/// Decodes a downloaded document to UTF-8. `content_type` is the value of the `Content-Type`
+/// header of the response, if any
+pub fn decode_document(bytes: &[u8], content_type: Option<&str>, kind: DocumentKind) -> String {
+    let encoding = detect_encoding(bytes, content_type, kind);
+    let (decoded, _, had_errors) = encoding.decode(bytes);
+
+    if had_errors {
+        tracing::debug!("invalid {} characters found while decoding", encoding.name());
+    }
+
+    match kind {
+        // The XML parsers trust the declared encoding, but the content is already UTF-8. Even
+        // when it was UTF-8 the declaration can say otherwise, if the header won over it
+        DocumentKind::Xml => fix_xml_declaration(decoded),
+        _ => decoded.into_owned(),
+    }
+}
+
+fn detect_encoding(
+    bytes: &[u8],
+    content_type: Option<&str>,
+    kind: DocumentKind,
+) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+
+    let declared_in_header = content_type
+        .and_then(|content_type| {
+            content_type.split(';').find_map(|param| {
+                let (name, value) = param.split_once('=')?;
+                name.trim()
+                    .eq_ignore_ascii_case("charset")
+                    .then(|| value.trim().trim_matches(['"', '\'']))
+            })
+        })
+        .and_then(|label| Encoding::for_label(label.as_bytes()));
+
+    let prescan = &bytes[..bytes.len().min(PRESCAN_LENGTH)];
+    let declared_in_document = || {
+        let regex = match kind {
+            DocumentKind::Xml => &*XML_DECLARATION_ENCODING,
+            DocumentKind::Html => &*HTML_META_CHARSET,
+        };
+
+        regex
+            .captures(prescan)
+            .and_then(|captures| Encoding::for_label(&captures[1]))
+    };
+
+    // A document can only declare an ASCII compatible encoding in itself, if it says UTF-16 it
+    // was transcoded without updating the declaration
+    declared_in_header
+        .or_else(|| declared_in_document().map(Encoding::output_encoding))
+        .unwrap_or_else(|| {
+            if std::str::from_utf8(bytes).is_ok() {
+                UTF_8
+            } else {
+                WINDOWS_1252
+            }
+        })
+}
+
+/// Replaces the encoding of the XML declaration with UTF-8
+fn fix_xml_declaration(xml: Cow<'_, str>) -> String {
+    static DECLARATION: LazyLock<regex::Regex> = LazyLock::new(|| {
+        regex::Regex::new(r#"^(\s*<\?xml[^>]*\bencoding\s*=\s*["'])[^"']+(["'])"#)
+            .expect("invalid XML declaration regex")
+    });
+
+    DECLARATION.replace(&xml, "${1}UTF-8${2}").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_encoding_is_detected() {
+        // "Canción" in ISO-8859-1 / Windows-1252
+        let latin1_xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><title>Canci\xf3n</title>";
+        let latin1_html = b"<html><head><meta charset=\"windows-1252\"></head>Canci\xf3n</html>";
+        let http_equiv = b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=Shift_JIS\">\x93\xfa\x96\x7b";
+
+        let cases: [(&[u8], Option<&str>, DocumentKind, &str); 7] = [
+            (latin1_xml, None, DocumentKind::Xml, "windows-1252"),
+            (latin1_html, None, DocumentKind::Html, "windows-1252"),
+            (http_equiv, None, DocumentKind::Html, "Shift_JIS"),
+            // The header wins over the document
+            (
+                latin1_html,
+                Some("text/html; charset=\"ISO-8859-15\""),
+                DocumentKind::Html,
+                "ISO-8859-15",
+            ),
+            (b"\xef\xbb\xbf<p>BOM</p>", Some("text/html; charset=latin1"), DocumentKind::Html, "UTF-8"),
+            ("<p>Canción</p>".as_bytes(), None, DocumentKind::Html, "UTF-8"),
+            // Not valid UTF-8 and nothing declared
+            (b"<p>Canci\xf3n</p>", Some("text/html"), DocumentKind::Html, "windows-1252"),
+        ];
+
+        for (bytes, content_type, kind, expected) in cases {
+            assert_eq!(
+                expected,
+                detect_encoding(bytes, content_type, kind).name(),
+                "{}",
+                String::from_utf8_lossy(bytes)
+            );
+        }
+    }
+
+    #[test]
+    fn documents_are_decoded_to_utf8() {
+        let xml = b"<?xml version='1.0' encoding='ISO-8859-1'?><title>Canci\xf3n</title>";
+        assert_eq!(
+            "<?xml version='1.0' encoding='UTF-8'?><title>Canción</title>",
+            decode_document(xml, None, DocumentKind::Xml)
+        );
+
+        // The header says UTF-8, so the declaration is wrong
+        let xml = "<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><title>Canción</title>";
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><title>Canción</title>",
+            decode_document(
+                xml.as_bytes(),
+                Some("application/rss+xml; charset=utf-8"),
+                DocumentKind::Xml
+            )
+        );
+
+        let html = b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=Shift_JIS\">\x93\xfa\x96\x7b";
+        assert!(decode_document(html, None, DocumentKind::Html).ends_with("日本"));
+    }
+}
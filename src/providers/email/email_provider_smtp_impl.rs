@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::time::Duration;
+
+use axum::async_trait;
+use lettre::{
+    message::{header::ContentType, Attachment, Mailbox, MultiPart, SinglePart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use super::{EmailProvider, EmailProviderError, Result};
+use crate::{
+    models::persisted_config::{SmtpConfig, SmtpSecurity},
+    providers::epub::EPUB_MIME_TYPE,
+};
+
+/// Time to wait for each response of the SMTP server
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct EmailProviderSmtpImpl;
+
+fn parse_mailbox(address: &str) -> Result<Mailbox> {
+    address
+        .parse()
+        .map_err(|e: lettre::address::AddressError| {
+            EmailProviderError::InvalidAddress(address.to_owned(), e.to_string())
+        })
+}
+
+#[async_trait]
+impl EmailProvider for EmailProviderSmtpImpl {
+    async fn send_epub(
+        &self,
+        smtp: &SmtpConfig,
+        subject: &str,
+        file_name: &str,
+        epub: Vec<u8>,
+    ) -> Result<()> {
+        let content_type = ContentType::parse(EPUB_MIME_TYPE)
+            .map_err(|e| EmailProviderError::MessageError(e.to_string()))?;
+        let message = Message::builder()
+            .from(parse_mailbox(&smtp.from)?)
+            .to(parse_mailbox(&smtp.recipient)?)
+            .subject(subject)
+            .multipart(
+                MultiPart::mixed()
+                    .singlepart(SinglePart::plain(format!(
+                        "{subject}\n\nSent by Kindly RSS Reader."
+                    )))
+                    .singlepart(Attachment::new(file_name.to_owned()).body(epub, content_type)),
+            )
+            .map_err(|e| EmailProviderError::MessageError(e.to_string()))?;
+
+        let builder = match smtp.security {
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host),
+            SmtpSecurity::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)
+            }
+            SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                &smtp.host,
+            )),
+        }
+        .map_err(|e| EmailProviderError::SmtpError(e.to_string()))?
+        .port(smtp.port)
+        .timeout(Some(SMTP_TIMEOUT));
+
+        let transport = if smtp.username.is_empty() {
+            builder.build()
+        } else {
+            builder
+                .credentials(Credentials::new(smtp.username.clone(), smtp.password.clone()))
+                .build()
+        };
+
+        transport
+            .send(message)
+            .await
+            .map_err(|e| EmailProviderError::SmtpError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum EmailProviderError {
+    #[error("Invalid email address {0}: {1}")]
+    InvalidAddress(String, String),
+
+    #[error("Message error: {0}")]
+    MessageError(String),
+
+    #[error("SMTP error: {0}")]
+    SmtpError(String),
+}
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod email_provider_smtp_impl;
+mod error;
+
+pub use email_provider_smtp_impl::EmailProviderSmtpImpl;
+pub use error::EmailProviderError;
+
+use axum::async_trait;
+
+use crate::models::persisted_config::SmtpConfig;
+
+pub(crate) type Result<T> = std::result::Result<T, EmailProviderError>;
+
+#[async_trait]
+pub trait EmailProvider: Sync + Send {
+    /// Sends an email with the EPUB file attached to the recipient of the settings
+    async fn send_epub(
+        &self,
+        smtp: &SmtpConfig,
+        subject: &str,
+        file_name: &str,
+        epub: Vec<u8>,
+    ) -> Result<()>;
+}
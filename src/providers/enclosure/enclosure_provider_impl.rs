@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{EnclosureProvider, EnclosureProviderError, Result};
+use crate::{config::Config, models::enclosure::Enclosure};
+use axum::async_trait;
+use std::{path::PathBuf, sync::Arc};
+use tokio::{fs, io::AsyncWriteExt};
+use tracing::info;
+
+pub struct EnclosureProviderImpl {
+    config: Arc<Config>,
+    articles_router_path: &'static str,
+}
+
+impl EnclosureProviderImpl {
+    const CACHEABLE_MIME_TYPES: [&str; 2] = ["audio/", "application/pdf"];
+
+    pub fn new(config: Arc<Config>, articles_router_path: &'static str) -> Self {
+        Self {
+            config,
+            articles_router_path,
+        }
+    }
+
+    fn is_cacheable(mime_type: Option<&str>) -> bool {
+        mime_type.is_some_and(|mime_type| {
+            Self::CACHEABLE_MIME_TYPES
+                .iter()
+                .any(|cacheable| mime_type.starts_with(cacheable))
+        })
+    }
+
+    /// Returns the extension of the file, taken from the url or, if it has none, from the MIME
+    /// type.
+    fn get_extension(enclosure: &Enclosure) -> String {
+        let from_url = reqwest::Url::parse(&enclosure.url).ok().and_then(|url| {
+            url.path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .and_then(|file_name| file_name.rsplit_once('.'))
+                .map(|(_, extension)| extension.to_lowercase())
+                .filter(|e| e.len() <= 5 && e.chars().all(|c| c.is_ascii_alphanumeric()))
+        });
+
+        from_url.unwrap_or_else(|| {
+            match enclosure.mime_type.as_deref() {
+                Some("audio/mpeg") => "mp3",
+                Some("audio/mp4" | "audio/x-m4a") => "m4a",
+                Some("audio/ogg") => "ogg",
+                Some("application/pdf") => "pdf",
+                _ => "bin",
+            }
+            .to_owned()
+        })
+    }
+
+    fn get_enclosures_dir(&self, enclosure: &Enclosure) -> PathBuf {
+        PathBuf::from(&self.config.data_path)
+            .join("articles")
+            .join(enclosure.feed_id.to_string())
+            .join("enclosures")
+    }
+
+    /// Downloads the file to `path`, aborting if it gets bigger than `max_size`. Returns `false`
+    /// if the download was aborted.
+    async fn download(&self, url: &str, path: &PathBuf, max_size: u64) -> Result<bool> {
+        let mut response = reqwest::get(url)
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| EnclosureProviderError::RequestError(e.to_string()))?;
+
+        // Do not trust the length declared by the feed, the server may know better
+        if response
+            .content_length()
+            .is_some_and(|length| length > max_size)
+        {
+            return Ok(false);
+        }
+
+        let mut file = fs::File::create(path)
+            .await
+            .map_err(|e| EnclosureProviderError::IoError(e.to_string()))?;
+
+        let mut size = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| EnclosureProviderError::RequestError(e.to_string()))?
+        {
+            size += chunk.len() as u64;
+            if size > max_size {
+                return Ok(false);
+            }
+
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| EnclosureProviderError::IoError(e.to_string()))?;
+        }
+
+        file.flush()
+            .await
+            .map_err(|e| EnclosureProviderError::IoError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl EnclosureProvider for EnclosureProviderImpl {
+    async fn cache_enclosure(&self, enclosure: &Enclosure) -> Result<Option<String>> {
+        let Some(max_size) = self.config.max_enclosure_size_to_cache else {
+            return Ok(None);
+        };
+
+        if !Self::is_cacheable(enclosure.mime_type.as_deref())
+            || enclosure.length.is_some_and(|length| length > max_size)
+        {
+            return Ok(None);
+        }
+
+        let enclosures_dir = self.get_enclosures_dir(enclosure);
+        fs::create_dir_all(&enclosures_dir)
+            .await
+            .map_err(|e| EnclosureProviderError::IoError(e.to_string()))?;
+
+        let file_name = format!("{}.{}", enclosure.id, Self::get_extension(enclosure));
+        let file_path = enclosures_dir.join(&file_name);
+
+        let downloaded = self.download(&enclosure.url, &file_path, max_size).await;
+        if !matches!(downloaded, Ok(true)) {
+            // Do not leave half downloaded files around
+            let _ = fs::remove_file(&file_path).await;
+        }
+
+        if !downloaded? {
+            info!(
+                "Enclosure {} is bigger than {max_size} bytes, not caching it",
+                enclosure.url
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "{}/{}/enclosures/{file_name}",
+            self.articles_router_path, enclosure.feed_id
+        )))
+    }
+}
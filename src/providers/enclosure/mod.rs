@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod enclosure_provider_impl;
+mod error;
+
+pub use enclosure_provider_impl::EnclosureProviderImpl;
+pub use error::EnclosureProviderError;
+
+use axum::async_trait;
+
+use crate::models::enclosure::Enclosure;
+
+pub(crate) type Result<T> = std::result::Result<T, EnclosureProviderError>;
+
+#[async_trait]
+pub trait EnclosureProvider: Sync + Send {
+    /// Downloads the enclosure to the article data directory so it is available offline.
+    ///
+    /// Only small audio and PDF files are cached. Returns the url the cached file is served from,
+    /// or `None` if the enclosure was not cached.
+    async fn cache_enclosure(&self, enclosure: &Enclosure) -> Result<Option<String>>;
+}
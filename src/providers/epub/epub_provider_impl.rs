@@ -0,0 +1,609 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use super::{EpubProvider, EpubProviderError, Result, EPUB_MIME_TYPE};
+use crate::{
+    config::Config,
+    models::{article::Article, feed::Feed},
+};
+use axum::async_trait;
+use chrono::Utc;
+use image::ImageFormat;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::{
+    collections::HashMap,
+    io::{Cursor, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+use tokio::fs;
+use uuid::Uuid;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+    <rootfiles>
+        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+    </rootfiles>
+</container>
+"#;
+
+const STYLE_CSS: &str = r#"body { font-family: serif; line-height: 1.4; }
+h1 { font-size: 1.4em; margin-bottom: 0.2em; }
+.byline { font-size: 0.85em; font-style: italic; margin-top: 0; }
+.source { font-size: 0.85em; margin-top: 2em; }
+img { max-width: 100%; height: auto; }
+pre { white-space: pre-wrap; }
+"#;
+
+/// Image embedded in the book
+struct EpubImage {
+    id: String,
+    /// Path of the image in the book, relative to the chapters
+    href: String,
+    media_type: &'static str,
+    data: Vec<u8>,
+}
+
+pub struct EpubProviderImpl {
+    config: Arc<Config>,
+    articles_router_path: &'static str,
+}
+
+impl EpubProviderImpl {
+    /// Elements removed with their content, as they can not be rendered by the e-readers
+    const REMOVED_ELEMENTS: [&str; 24] = [
+        "audio", "base", "button", "canvas", "embed", "form", "head", "iframe", "input", "link",
+        "math", "meta", "noscript", "object", "script", "select", "source", "style", "svg",
+        "template", "textarea", "title", "track", "video",
+    ];
+
+    const VOID_ELEMENTS: [&str; 5] = ["area", "br", "col", "hr", "wbr"];
+
+    /// Attributes removed from the elements, the style of the book is the one of the e-reader
+    const REMOVED_ATTRIBUTES: [&str; 6] = ["class", "sizes", "srcset", "style", "xmlns", "lang"];
+
+    pub fn new(config: Arc<Config>, articles_router_path: &'static str) -> Self {
+        Self {
+            config,
+            articles_router_path,
+        }
+    }
+
+    /// Returns the path in the filesystem of an image cached by the app, from its url
+    /// (`<articles router path>/<feed id>/static/<file name>`)
+    fn get_cached_image_path(&self, src: &str) -> Option<PathBuf> {
+        let relative_path = src.strip_prefix(self.articles_router_path)?.strip_prefix('/')?;
+
+        match relative_path.split('/').collect::<Vec<_>>()[..] {
+            [feed_id, "static", file_name]
+                if Uuid::parse_str(feed_id).is_ok()
+                    && !file_name.starts_with('.')
+                    && file_name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c)) =>
+            {
+                Some(
+                    PathBuf::from(&self.config.data_path)
+                        .join("articles")
+                        .join(feed_id)
+                        .join("static")
+                        .join(file_name),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the media type and extension of an image from its content, as the cached images
+    /// are saved without extension. Only the image types supported by EPUB are returned
+    fn get_media_type(data: &[u8]) -> Option<(&'static str, &'static str)> {
+        match image::guess_format(data).ok()? {
+            ImageFormat::Jpeg => Some(("image/jpeg", "jpg")),
+            ImageFormat::Png => Some(("image/png", "png")),
+            ImageFormat::Gif => Some(("image/gif", "gif")),
+            ImageFormat::WebP => Some(("image/webp", "webp")),
+            _ => None,
+        }
+    }
+
+    /// Reads the cached images of the articles, indexed by their url
+    async fn load_images(
+        &self,
+        articles: &[(Article, Feed, String)],
+    ) -> HashMap<String, EpubImage> {
+        let selector = Selector::parse("img[src]").expect("invalid image selector");
+        let mut images = HashMap::new();
+
+        for (_, _, html) in articles {
+            let sources = Html::parse_fragment(html)
+                .select(&selector)
+                .filter_map(|img| img.value().attr("src").map(ToOwned::to_owned))
+                .collect::<Vec<_>>();
+
+            for src in sources {
+                if images.contains_key(&src) {
+                    continue;
+                }
+
+                let Some(path) = self.get_cached_image_path(&src) else {
+                    continue;
+                };
+                let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+
+                let data = match fs::read(&path).await {
+                    Ok(data) => data,
+                    Err(e) => {
+                        tracing::warn!("unable to read the cached image {path:?}: {e}");
+                        continue;
+                    }
+                };
+                let Some((media_type, extension)) = Self::get_media_type(&data) else {
+                    continue;
+                };
+
+                let image = EpubImage {
+                    id: format!("image-{}", images.len() + 1),
+                    href: format!("images/{file_name}.{extension}"),
+                    media_type,
+                    data,
+                };
+                images.insert(src, image);
+            }
+        }
+
+        images
+    }
+
+    /// Converts the HTML content of an article to XHTML. The embedded images point to the book
+    /// and the ones that were not cached are removed
+    fn to_xhtml(html: &str, images: &HashMap<String, EpubImage>) -> String {
+        let fragment = Html::parse_fragment(html);
+        let mut xhtml = String::new();
+        Self::write_children(fragment.root_element(), images, &mut xhtml);
+
+        xhtml
+    }
+
+    fn write_children(element: ElementRef, images: &HashMap<String, EpubImage>, out: &mut String) {
+        for child in element.children() {
+            let element = match child.value() {
+                Node::Text(text) => {
+                    out.push_str(&escape_xml(text));
+                    continue;
+                }
+                Node::Element(element) => element,
+                _ => continue,
+            };
+            let Some(child) = ElementRef::wrap(child) else {
+                continue;
+            };
+
+            let name = element.name();
+            if Self::REMOVED_ELEMENTS.contains(&name) {
+                continue;
+            }
+
+            // Unknown elements are replaced by their content
+            if !is_xml_name(name) {
+                Self::write_children(child, images, out);
+                continue;
+            }
+
+            if name == "img" {
+                if let Some(image) = element.attr("src").and_then(|src| images.get(src)) {
+                    let alt = element.attr("alt").unwrap_or_default();
+                    out.push_str(&format!(
+                        r#"<img src="{}" alt="{}"/>"#,
+                        image.href,
+                        escape_xml(alt)
+                    ));
+                }
+                continue;
+            }
+
+            out.push('<');
+            out.push_str(name);
+            for (attribute, value) in element.attrs() {
+                if !is_xml_name(attribute)
+                    || attribute.starts_with("on")
+                    || Self::REMOVED_ATTRIBUTES.contains(&attribute)
+                {
+                    continue;
+                }
+                out.push_str(&format!(r#" {attribute}="{}""#, escape_xml(value)));
+            }
+
+            if Self::VOID_ELEMENTS.contains(&name) {
+                out.push_str("/>");
+            } else {
+                out.push('>');
+                Self::write_children(child, images, out);
+                out.push_str(&format!("</{name}>"));
+            }
+        }
+    }
+
+    fn chapter(article: &Article, feed: &Feed, content: &str) -> String {
+        let title = escape_xml(&article.title);
+        let mut byline = vec![escape_xml(&feed.title)];
+        if let Some(author) = &article.author {
+            byline.push(escape_xml(author));
+        }
+        byline.push(article.published_at.format("%Y-%m-%d %H:%M").to_string());
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<head>
+<meta charset="UTF-8"/>
+<title>{title}</title>
+<link rel="stylesheet" type="text/css" href="style.css"/>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="byline">{}</p>
+{content}
+<p class="source"><a href="{}">Original article</a></p>
+</body>
+</html>
+"#,
+            byline.join(" · "),
+            escape_xml(&article.link),
+        )
+    }
+
+    fn navigation(title: &str, articles: &[(Article, Feed, String)]) -> String {
+        let items = articles
+            .iter()
+            .enumerate()
+            .map(|(i, (article, feed, _))| {
+                format!(
+                    r#"<li><a href="chapter-{}.xhtml">{}</a> ({})</li>"#,
+                    i + 1,
+                    escape_xml(&article.title),
+                    escape_xml(&feed.title)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<head>
+<meta charset="UTF-8"/>
+<title>{title}</title>
+<link rel="stylesheet" type="text/css" href="style.css"/>
+</head>
+<body>
+<nav epub:type="toc" id="toc">
+<h1>{title}</h1>
+<ol>
+{items}
+</ol>
+</nav>
+</body>
+</html>
+"#,
+            title = escape_xml(title)
+        )
+    }
+
+    /// Table of contents of EPUB 2, used by the older e-readers
+    fn ncx(identifier: &str, title: &str, articles: &[(Article, Feed, String)]) -> String {
+        let nav_points = articles
+            .iter()
+            .enumerate()
+            .map(|(i, (article, _, _))| {
+                format!(
+                    r#"<navPoint id="chapter-{n}" playOrder="{n}"><navLabel><text>{}</text></navLabel><content src="chapter-{n}.xhtml"/></navPoint>"#,
+                    escape_xml(&article.title),
+                    n = i + 1
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
+<head><meta name="dtb:uid" content="{identifier}"/></head>
+<docTitle><text>{}</text></docTitle>
+<navMap>
+{nav_points}
+</navMap>
+</ncx>
+"#,
+            escape_xml(title)
+        )
+    }
+
+    fn package(
+        identifier: &str,
+        title: &str,
+        chapters: usize,
+        images: &HashMap<String, EpubImage>,
+    ) -> String {
+        let modified = Utc::now().format("%Y-%m-%dT%H:%M:%SZ");
+
+        let mut manifest = vec![
+            r#"<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>"#.to_owned(),
+            r#"<item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>"#.to_owned(),
+            r#"<item id="style" href="style.css" media-type="text/css"/>"#.to_owned(),
+        ];
+        let mut spine = vec![r#"<itemref idref="nav"/>"#.to_owned()];
+        for n in 1..=chapters {
+            manifest.push(format!(
+                r#"<item id="chapter-{n}" href="chapter-{n}.xhtml" media-type="application/xhtml+xml"/>"#
+            ));
+            spine.push(format!(r#"<itemref idref="chapter-{n}"/>"#));
+        }
+
+        let mut images = images.values().collect::<Vec<_>>();
+        images.sort_by(|a, b| a.href.cmp(&b.href));
+        for image in images {
+            manifest.push(format!(
+                r#"<item id="{}" href="{}" media-type="{}"/>"#,
+                image.id, image.href, image.media_type
+            ));
+        }
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="book-id">{identifier}</dc:identifier>
+<dc:title>{}</dc:title>
+<dc:language>en</dc:language>
+<dc:creator>Kindly RSS Reader</dc:creator>
+<meta property="dcterms:modified">{modified}</meta>
+</metadata>
+<manifest>
+{}
+</manifest>
+<spine toc="ncx">
+{}
+</spine>
+</package>
+"#,
+            escape_xml(title),
+            manifest.join("\n"),
+            spine.join("\n"),
+        )
+    }
+}
+
+#[async_trait]
+impl EpubProvider for EpubProviderImpl {
+    async fn build_epub(
+        &self,
+        title: &str,
+        articles: &[(Article, Feed, String)],
+    ) -> Result<Vec<u8>> {
+        let images = self.load_images(articles).await;
+        let identifier = format!("urn:uuid:{}", Uuid::new_v4());
+
+        let mut files = vec![
+            ("META-INF/container.xml".to_owned(), CONTAINER_XML.to_owned()),
+            (
+                "OEBPS/content.opf".to_owned(),
+                Self::package(&identifier, title, articles.len(), &images),
+            ),
+            ("OEBPS/nav.xhtml".to_owned(), Self::navigation(title, articles)),
+            ("OEBPS/toc.ncx".to_owned(), Self::ncx(&identifier, title, articles)),
+            ("OEBPS/style.css".to_owned(), STYLE_CSS.to_owned()),
+        ];
+        for (i, (article, feed, html)) in articles.iter().enumerate() {
+            let content = Self::to_xhtml(html, &images);
+            files.push((
+                format!("OEBPS/chapter-{}.xhtml", i + 1),
+                Self::chapter(article, feed, &content),
+            ));
+        }
+
+        let zip_error = |e: zip::result::ZipError| EpubProviderError::ZipError(e.to_string());
+        let io_error = |e: std::io::Error| EpubProviderError::IoError(e.to_string());
+
+        // The mimetype must be the first file and it can not be compressed
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        zip.start_file("mimetype", stored).map_err(zip_error)?;
+        zip.write_all(EPUB_MIME_TYPE.as_bytes()).map_err(io_error)?;
+
+        for (path, content) in files {
+            zip.start_file(path, deflated).map_err(zip_error)?;
+            zip.write_all(content.as_bytes()).map_err(io_error)?;
+        }
+
+        // The images are already compressed
+        for image in images.values() {
+            zip.start_file(format!("OEBPS/{}", image.href), stored)
+                .map_err(zip_error)?;
+            zip.write_all(&image.data).map_err(io_error)?;
+        }
+
+        Ok(zip.finish().map_err(zip_error)?.into_inner())
+    }
+}
+
+/// Checks that the name of an element or attribute is valid in XML (without namespaces)
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+}
+
+/// Escapes the text for XML, removing the control characters it does not allow
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() && (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::feed::ContentMode;
+    use std::io::Read;
+
+    fn config(data_path: &str) -> Arc<Config> {
+        Arc::new(
+            envy::from_iter::<_, Config>([("DATA_PATH".to_owned(), data_path.to_owned())])
+                .unwrap(),
+        )
+    }
+
+    fn feed() -> Feed {
+        Feed {
+            id: Uuid::new_v4(),
+            numeric_id: 1,
+            title: "Feed & Co".to_owned(),
+            url: "http://example.com/feed".to_owned(),
+            link: "http://example.com".to_owned(),
+            favicon_url: None,
+            last_updated: Utc::now(),
+            unread_count: 0,
+            category_id: None,
+            content_mode: ContentMode::Auto,
+        }
+    }
+
+    #[test]
+    fn html_is_converted_to_xhtml() {
+        let mut images = HashMap::new();
+        images.insert(
+            "/articles/cached.png".to_owned(),
+            EpubImage {
+                id: "image-1".to_owned(),
+                href: "images/cached.png".to_owned(),
+                media_type: "image/png",
+                data: vec![],
+            },
+        );
+
+        let html = r#"<p class="x" onclick="f()">A &amp; B&nbsp;<br>
+            <img src="/articles/cached.png" alt="Cached"><img src="http://example.com/a.png">
+            </p><script>alert(1)</script><custom:tag>kept</custom:tag>"#;
+        let xhtml = EpubProviderImpl::to_xhtml(html, &images);
+
+        assert_eq!(
+            "<p>A &amp; B\u{a0}<br/>\n            \
+            <img src=\"images/cached.png\" alt=\"Cached\"/>\n            </p>kept",
+            xhtml
+        );
+    }
+
+    #[test]
+    fn only_cached_images_are_embedded() {
+        let provider = EpubProviderImpl::new(config("/data"), "/articles");
+        let feed_id = Uuid::new_v4();
+
+        let image_id = Uuid::new_v4();
+
+        assert_eq!(
+            Some(PathBuf::from(format!("/data/articles/{feed_id}/static/{image_id}"))),
+            provider.get_cached_image_path(&format!("/articles/{feed_id}/static/{image_id}"))
+        );
+        assert_eq!(
+            None,
+            provider.get_cached_image_path(&format!("/articles/{feed_id}/static/../../db.sqlite"))
+        );
+        assert_eq!(
+            None,
+            provider.get_cached_image_path("/static/error_processing_image.png")
+        );
+        assert_eq!(
+            None,
+            provider.get_cached_image_path("http://example.com/image.jpg")
+        );
+    }
+
+    #[tokio::test]
+    async fn epub_is_built() {
+        let data_path = std::env::temp_dir().join(format!("kindle-rss-reader-{}", Uuid::new_v4()));
+        let feed = feed();
+        let static_path = data_path.join("articles").join(feed.id.to_string()).join("static");
+        std::fs::create_dir_all(&static_path).unwrap();
+        let image_id = Uuid::new_v4();
+        std::fs::write(static_path.join(image_id.to_string()), b"\xFF\xD8\xFFjpeg").unwrap();
+        std::fs::write(static_path.join("text"), b"not an image").unwrap();
+
+        let provider = EpubProviderImpl::new(config(data_path.to_str().unwrap()), "/articles");
+        let article = Article {
+            id: Uuid::new_v4(),
+            numeric_id: 1,
+            feed_id: feed.id,
+            title: "First <article>".to_owned(),
+            author: None,
+            guid: "first".to_owned(),
+            link: "http://example.com/first".to_owned(),
+            summary: None,
+            thumbnail_url: None,
+            content: None,
+            read: false,
+            starred: false,
+            html_parsed: true,
+            last_updated: Utc::now(),
+            published_at: Utc::now(),
+            found_at: Utc::now(),
+            date_guessed: false,
+            content_hash: None,
+            has_update: false,
+            updated_at: None,
+            duplicate_of: None,
+            canonical_url: None,
+        };
+        let static_url = format!("/articles/{}/static", feed.id);
+        let html = format!(
+            r#"<p>Hello</p><img src="{static_url}/{image_id}"><img src="{static_url}/text">"#
+        );
+        let epub = provider
+            .build_epub("Articles", &[(article, feed, html)])
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&data_path).unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(epub)).unwrap();
+        let mimetype = archive.by_index(0).unwrap();
+        assert_eq!("mimetype", mimetype.name());
+        assert_eq!(CompressionMethod::Stored, mimetype.compression());
+        drop(mimetype);
+
+        let mut read = |name: &str| {
+            let mut content = String::new();
+            archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+            content
+        };
+        let image = format!(r#"href="images/{image_id}.jpg" media-type="image/jpeg""#);
+        assert!(read("OEBPS/content.opf").contains(&image));
+        assert!(read("OEBPS/nav.xhtml").contains("First &lt;article&gt;</a> (Feed &amp; Co)"));
+        // The files that are not images are not embedded
+        let chapter = read("OEBPS/chapter-1.xhtml");
+        let image = format!(r#"<img src="images/{image_id}.jpg" alt=""/>"#);
+        assert!(chapter.contains(&format!("<p>Hello</p>{image}\n")));
+
+        let mut data = vec![];
+        let mut file = archive.by_name(&format!("OEBPS/images/{image_id}.jpg")).unwrap();
+        file.read_to_end(&mut data).unwrap();
+        assert_eq!(b"\xFF\xD8\xFFjpeg", data.as_slice());
+    }
+}
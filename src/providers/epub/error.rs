@@ -0,0 +1,12 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum EpubProviderError {
+    #[error("IO error: {0}")]
+    IoError(String),
+
+    #[error("Zip error: {0}")]
+    ZipError(String),
+}
@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod epub_provider_impl;
+mod error;
+
+pub use epub_provider_impl::EpubProviderImpl;
+pub use error::EpubProviderError;
+
+use axum::async_trait;
+
+use crate::models::{article::Article, feed::Feed};
+
+pub(crate) type Result<T> = std::result::Result<T, EpubProviderError>;
+
+/// MIME type of the EPUB files
+pub const EPUB_MIME_TYPE: &str = "application/epub+zip";
+
+#[async_trait]
+pub trait EpubProvider: Sync + Send {
+    /// Builds an EPUB 3 book with a chapter for each article (with its feed and its HTML content)
+    /// and a table of contents.
+    ///
+    /// The images cached by the app are embedded in the book. The other ones are removed, as the
+    /// book is read offline.
+    async fn build_epub(&self, title: &str, articles: &[(Article, Feed, String)])
+    -> Result<Vec<u8>>;
+}
@@ -4,19 +4,50 @@
 //
 // Original implementation by Keheliya Gallaba. Subsequent
 // modifications by Nicolás Antinori (AGPL-3.0-only).
-use super::{error::FeedParserError, FeedParser, Result};
+use super::{date::parse_date, error::FeedParserError, get_media_thumbnail, FeedParser, Result};
 use crate::models::parsed_feed::ParsedFeed;
+use crate::models::parsed_feed::ParsedEnclosure;
 use crate::models::parsed_feed::ParsedItem;
 use anyhow::anyhow;
 use atom_syndication::Feed;
 use chrono::Utc;
+use regex::bytes::{Captures, Regex};
+use std::borrow::Cow;
 use std::io::BufReader;
+use std::sync::LazyLock;
+
+/// Date elements of an Atom feed, with or without a namespace prefix
+static ATOM_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"<((?:[\w-]+:)?(?:updated|published))>([^<]*)</((?:[\w-]+:)?(?:updated|published))>",
+    )
+    .expect("invalid Atom date regex")
+});
 
 pub struct AtomParserImpl;
 
+impl AtomParserImpl {
+    /// atom_syndication only exposes the dates it parsed itself and rejects the whole feed when
+    /// one of them is not valid RFC 3339, so the dates we recognize are rewritten in that format
+    /// before reading the feed
+    fn normalize_dates(content: &[u8]) -> Cow<'_, [u8]> {
+        ATOM_DATE.replace_all(content, |captures: &Captures| {
+            let date = std::str::from_utf8(&captures[2]).ok().and_then(parse_date);
+            match date {
+                Some(date) if captures[1] == captures[3] => {
+                    let tag = String::from_utf8_lossy(&captures[1]);
+                    format!("<{tag}>{}</{tag}>", date.to_rfc3339()).into_bytes()
+                }
+                _ => captures[0].to_vec(),
+            }
+        })
+    }
+}
+
 impl FeedParser for AtomParserImpl {
     fn parse_feed(&self, content: &[u8]) -> Result<ParsedFeed> {
-        let reader = BufReader::new(content);
+        let content = Self::normalize_dates(content);
+        let reader = BufReader::new(content.as_ref());
         let feed = Feed::read_from(reader).map_err(|e| FeedParserError::ParseError(anyhow!(e)))?;
 
         // Find the alternate link (usually the website URL)
@@ -27,6 +58,16 @@ impl FeedParser for AtomParserImpl {
             .map(|link| link.href().to_owned())
             .ok_or_else(|| FeedParserError::MissingField("link"))?;
 
+        // WebSub links
+        let feed_link = |rel: &str| {
+            feed.links()
+                .iter()
+                .find(|link| link.rel() == rel)
+                .map(|link| link.href().to_owned())
+        };
+        let hub_url = feed_link("hub");
+        let self_url = feed_link("self");
+
         let items = feed
             .entries()
             .iter()
@@ -38,10 +79,28 @@ impl FeedParser for AtomParserImpl {
                     .find(|link| link.rel() == "alternate")
                     .map(|link| link.href().to_owned());
 
-                // Get content or summary
+                // Get content and summary
                 let content = entry
                     .content()
                     .and_then(|c| c.value.as_ref().map(|v| v.to_owned()));
+                let summary = entry.summary().map(|s| s.value.to_owned());
+
+                let enclosures = entry
+                    .links()
+                    .iter()
+                    .filter(|link| link.rel() == "enclosure")
+                    .map(|link| ParsedEnclosure {
+                        url: link.href().to_owned(),
+                        mime_type: link.mime_type().map(|m| m.to_owned()),
+                        length: link.length().and_then(|l| l.trim().parse().ok()),
+                        duration: None,
+                    })
+                    .collect();
+
+                let thumbnail = entry
+                    .extensions()
+                    .get("media")
+                    .and_then(get_media_thumbnail);
 
                 // Get author name if available
                 let author = entry.authors().first().map(|a| a.name().to_owned());
@@ -51,8 +110,22 @@ impl FeedParser for AtomParserImpl {
                     link: entry_link,
                     guid: Some(entry.id().to_owned()),
                     content,
+                    summary,
                     author,
-                    pub_date: Some(entry.updated().with_timezone(&Utc)),
+                    // <published> is optional, <updated> is not
+                    pub_date: entry
+                        .published()
+                        .unwrap_or(entry.updated())
+                        .with_timezone(&Utc)
+                        .into(),
+                    updated: Some(entry.updated().with_timezone(&Utc)),
+                    enclosures,
+                    thumbnail,
+                    categories: entry
+                        .categories()
+                        .iter()
+                        .map(|category| category.term().to_owned())
+                        .collect(),
                 }
             })
             .collect();
@@ -60,6 +133,8 @@ impl FeedParser for AtomParserImpl {
         Ok(ParsedFeed {
             title: feed.title().to_string(),
             link,
+            hub_url,
+            self_url,
             items,
         })
     }
@@ -91,6 +166,142 @@ mod tests {
         assert!(AtomParserImpl.parse_feed(atom_content).is_ok());
     }
 
+    #[test]
+    fn test_summary_is_used_when_there_is_no_content() {
+        let atom_content = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Test Atom Feed</title>
+            <link href="https://example.com/feed"/>
+            <updated>2023-01-01T12:00:00Z</updated>
+            <id>https://example.com/feed</id>
+            <entry>
+                <title>Summary only</title>
+                <link rel="alternate" href="https://example.com/entry1"/>
+                <id>https://example.com/entry1</id>
+                <updated>2023-01-01T12:00:00Z</updated>
+                <summary type="html">&lt;p&gt;The full text&lt;/p&gt;</summary>
+            </entry>
+            <entry>
+                <title>Content and summary</title>
+                <link rel="alternate" href="https://example.com/entry2"/>
+                <id>https://example.com/entry2</id>
+                <updated>2023-01-01T12:00:00Z</updated>
+                <summary>A teaser</summary>
+                <content type="html">&lt;p&gt;The content&lt;/p&gt;</content>
+            </entry>
+        </feed>
+        "#
+        .as_bytes();
+
+        let parsed_feed = AtomParserImpl.parse_feed(atom_content).unwrap();
+
+        assert_eq!(
+            Some("<p>The full text</p>"),
+            parsed_feed.items[0].content_or_summary()
+        );
+        assert_eq!(
+            Some("<p>The content</p>"),
+            parsed_feed.items[1].content_or_summary()
+        );
+        assert_eq!(Some("A teaser"), parsed_feed.items[1].summary.as_deref());
+    }
+
+    #[test]
+    fn test_enclosure_links_are_parsed() {
+        let atom_content = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Test Atom Feed</title>
+            <link href="https://example.com/feed"/>
+            <updated>2023-01-01T12:00:00Z</updated>
+            <id>https://example.com/feed</id>
+            <entry>
+                <title>Test Entry</title>
+                <link rel="alternate" href="https://example.com/entry1"/>
+                <link rel="enclosure" href="https://example.com/entry1.pdf" type="application/pdf" length="2048"/>
+                <category term="rust" label="Rust"/>
+                <category term="web"/>
+                <id>https://example.com/entry1</id>
+                <updated>2023-01-01T12:00:00Z</updated>
+            </entry>
+        </feed>
+        "#
+        .as_bytes();
+
+        let parsed_feed = AtomParserImpl.parse_feed(atom_content).unwrap();
+        let item = &parsed_feed.items[0];
+
+        assert_eq!(Some("https://example.com/entry1"), item.link.as_deref());
+        assert_eq!(1, item.enclosures.len());
+        assert_eq!("https://example.com/entry1.pdf", item.enclosures[0].url);
+        assert_eq!(
+            Some("application/pdf"),
+            item.enclosures[0].mime_type.as_deref()
+        );
+        assert_eq!(Some(2048), item.enclosures[0].length);
+        assert_eq!(vec!["rust", "web"], item.categories);
+    }
+
+    #[test]
+    fn test_published_and_updated_dates_are_parsed() {
+        let atom_content = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Test Atom Feed</title>
+            <link href="https://example.com/feed"/>
+            <updated>2023-01-01T12:00:00Z</updated>
+            <id>https://example.com/feed</id>
+            <entry>
+                <title>Edited entry</title>
+                <id>https://example.com/entry1</id>
+                <published>2022-06-01T08:00:00Z</published>
+                <updated>2023-01-01T12:00:00Z</updated>
+            </entry>
+            <entry>
+                <title>Entry without publication date</title>
+                <id>https://example.com/entry2</id>
+                <updated>2023-01-02T12:00:00Z</updated>
+            </entry>
+        </feed>
+        "#
+        .as_bytes();
+
+        let items = AtomParserImpl.parse_feed(atom_content).unwrap().items;
+        let date = |d: &str| Some(d.parse::<chrono::DateTime<Utc>>().unwrap());
+
+        assert_eq!(date("2022-06-01T08:00:00Z"), items[0].pub_date);
+        assert_eq!(date("2023-01-01T12:00:00Z"), items[0].updated);
+        assert_eq!(date("2023-01-02T12:00:00Z"), items[1].pub_date);
+        assert_eq!(date("2023-01-02T12:00:00Z"), items[1].updated);
+    }
+
+    #[test]
+    fn test_invalid_dates_are_parsed_leniently() {
+        let atom_content = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Test Atom Feed</title>
+            <link href="https://example.com/feed"/>
+            <updated>2023-01-01 12:00:00</updated>
+            <id>https://example.com/feed</id>
+            <entry>
+                <title>Entry with a named timezone</title>
+                <id>https://example.com/entry1</id>
+                <published>Monday, 1 January 2024 12:00 CEST</published>
+                <updated>Mon, 01 Jan 24 14:00:00 CEST</updated>
+            </entry>
+        </feed>
+        "#
+        .as_bytes();
+
+        let items = AtomParserImpl.parse_feed(atom_content).unwrap().items;
+        let date = |d: &str| Some(d.parse::<chrono::DateTime<Utc>>().unwrap());
+
+        assert_eq!(date("2024-01-01T10:00:00Z"), items[0].pub_date);
+        assert_eq!(date("2024-01-01T12:00:00Z"), items[0].updated);
+    }
+
     #[test]
     fn test_cannot_parse_rss() {
         let rss_content = r#"
@@ -113,4 +324,28 @@ mod tests {
 
         assert!(AtomParserImpl.parse_feed(rss_content).is_err());
     }
+
+    #[test]
+    fn test_websub_links_are_parsed() {
+        let atom_content = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Test Atom Feed</title>
+            <link rel="alternate" href="https://example.com"/>
+            <link rel="self" href="https://example.com/feed.atom"/>
+            <link rel="hub" href="https://hub.example.com/"/>
+            <updated>2023-01-01T12:00:00Z</updated>
+            <id>https://example.com/feed</id>
+        </feed>
+        "#
+        .as_bytes();
+
+        let parsed_feed = AtomParserImpl.parse_feed(atom_content).unwrap();
+
+        assert_eq!(Some("https://hub.example.com/"), parsed_feed.hub_url.as_deref());
+        assert_eq!(
+            Some("https://example.com/feed.atom"),
+            parsed_feed.self_url.as_deref()
+        );
+    }
 }
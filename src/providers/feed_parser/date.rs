@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Lenient parsing of the dates found in feeds. Many feeds do not follow the format required by
+//! the spec (RFC 2822 for RSS, RFC 3339 for Atom): they use named timezones, omit or misspell the
+//! weekday, use ISO 8601 in RSS or two digit years.
+use std::sync::LazyLock;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
+
+/// RFC 2822 like dates: an optional weekday, the day, the month name, the year, the time and an
+/// optional timezone
+static RFC2822_LIKE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(?:[a-z]+\.?,?\s+)?(\d{1,2})(?:st|nd|rd|th)?\s+([a-z]+)\.?,?\s+(\d{2}|\d{4}),?\s+(\d{1,2}):(\d{2})(?::(\d{2}))?(?:\.\d+)?\s*(.*)$",
+    )
+    .expect("invalid RFC 2822 date regex")
+});
+
+const ISO8601_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+const ISO8601_OFFSET_FORMATS: [&str; 3] = [
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S%.f %z",
+];
+
+/// Offset (in minutes) of the timezone abbreviations usually found in feeds
+const NAMED_TIMEZONES: [(&str, i32); 32] = [
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("WET", 0),
+    ("WEST", 60),
+    ("BST", 60),
+    ("CET", 60),
+    ("MET", 60),
+    ("CEST", 120),
+    ("MEST", 120),
+    ("EET", 120),
+    ("EEST", 180),
+    ("MSK", 180),
+    ("IST", 330),
+    ("SGT", 480),
+    ("HKT", 480),
+    ("AWST", 480),
+    ("JST", 540),
+    ("KST", 540),
+    ("AEST", 600),
+    ("AEDT", 660),
+    ("NZST", 720),
+    ("NZDT", 780),
+    ("EST", -300),
+    ("EDT", -240),
+    ("CST", -360),
+    ("CDT", -300),
+    ("MST", -420),
+    ("MDT", -360),
+    ("PST", -480),
+    ("PDT", -420),
+];
+
+/// Parses a date of a feed, returning `None` if it is not recognized
+pub(super) fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    let date = date.trim();
+    if date.is_empty() {
+        return None;
+    }
+
+    if let Ok(parsed) = DateTime::parse_from_rfc2822(date) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(date) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+
+    parse_rfc2822_like(date).or_else(|| parse_iso8601(date))
+}
+
+fn parse_rfc2822_like(date: &str) -> Option<DateTime<Utc>> {
+    let captures = RFC2822_LIKE.captures(date)?;
+    let number = |index: usize| captures.get(index).and_then(|m| m.as_str().parse::<u32>().ok());
+
+    let year = match captures[3].len() {
+        // Two digit years, as RFC 2822 obsolete syntax
+        2 => {
+            let year = captures[3].parse::<i32>().ok()?;
+            if year < 50 { 2000 + year } else { 1900 + year }
+        }
+        _ => captures[3].parse::<i32>().ok()?,
+    };
+
+    let date = NaiveDate::from_ymd_opt(year, parse_month(&captures[2])?, number(1)?)?
+        .and_hms_opt(number(4)?, number(5)?, number(6).unwrap_or(0))?;
+
+    Some(parse_offset(&captures[7])?.from_local_datetime(&date).single()?.with_timezone(&Utc))
+}
+
+fn parse_iso8601(date: &str) -> Option<DateTime<Utc>> {
+    // A space before the "Z" is also found
+    let date = date.replace(" Z", "Z");
+    let date = date.strip_suffix('Z').map(|d| format!("{d}+0000")).unwrap_or(date);
+
+    ISO8601_OFFSET_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(&date, format).ok())
+        .map(|parsed| parsed.with_timezone(&Utc))
+        .or_else(|| {
+            // Without a timezone we assume UTC
+            ISO8601_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(&date, format).ok())
+                .or_else(|| {
+                    NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                        .ok()
+                        .and_then(|d| d.and_hms_opt(0, 0, 0))
+                })
+                .map(|parsed| parsed.and_utc())
+        })
+}
+
+fn parse_month(month: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    let prefix = month.get(..3)?.to_lowercase();
+    MONTHS
+        .iter()
+        .position(|m| *m == prefix)
+        .map(|index| index as u32 + 1)
+}
+
+/// Parses a numeric (`+0200`, `+02:00`) or named timezone. A missing or unknown timezone is taken
+/// as UTC
+fn parse_offset(timezone: &str) -> Option<FixedOffset> {
+    // Some feeds add the name after the offset, e.g. "+0000 (UTC)"
+    let timezone = timezone.split_whitespace().next().unwrap_or_default();
+
+    let minutes = if let Some((sign, offset)) = timezone
+        .strip_prefix('+')
+        .map(|offset| (1, offset))
+        .or_else(|| timezone.strip_prefix('-').map(|offset| (-1, offset)))
+    {
+        let digits: String = offset.chars().filter(char::is_ascii_digit).collect();
+        let (hours, minutes) = match digits.len() {
+            1 | 2 => (digits.parse::<i32>().ok()?, 0),
+            4 => (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?),
+            _ => return None,
+        };
+
+        sign * (hours * 60 + minutes)
+    } else {
+        NAMED_TIMEZONES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(timezone))
+            .map(|(_, minutes)| *minutes)
+            .unwrap_or(0)
+    };
+
+    FixedOffset::east_opt(minutes * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_parsed() {
+        let cases = [
+            // Valid RFC 2822 and RFC 3339
+            ("Mon, 01 Jan 2024 12:00:00 GMT", Some("2024-01-01T12:00:00+00:00")),
+            ("Mon, 01 Jan 2024 12:00:00 +0200", Some("2024-01-01T10:00:00+00:00")),
+            ("2024-01-01T12:00:00Z", Some("2024-01-01T12:00:00+00:00")),
+            ("2024-01-01T12:00:00.123-03:00", Some("2024-01-01T15:00:00.123+00:00")),
+            // Named timezones
+            ("Mon, 01 Jan 2024 12:00:00 CEST", Some("2024-01-01T10:00:00+00:00")),
+            ("Mon, 01 Jan 2024 12:00:00 IST", Some("2024-01-01T06:30:00+00:00")),
+            ("Mon, 01 Jan 2024 12:00:00 UTC", Some("2024-01-01T12:00:00+00:00")),
+            ("Mon, 01 Jan 2024 12:00:00 +0000 (UTC)", Some("2024-01-01T12:00:00+00:00")),
+            // Missing, wrong or long weekday and month names
+            ("01 Jan 2024 12:00:00 GMT", Some("2024-01-01T12:00:00+00:00")),
+            ("Tue, 01 Jan 2024 12:00:00 GMT", Some("2024-01-01T12:00:00+00:00")),
+            ("Monday, 1 January 2024 12:00 GMT", Some("2024-01-01T12:00:00+00:00")),
+            ("Fri, 1 Sept 2023 08:05:09 -05:00", Some("2023-09-01T13:05:09+00:00")),
+            // Two digit years
+            ("Mon, 01 Jan 24 12:00:00 GMT", Some("2024-01-01T12:00:00+00:00")),
+            ("Fri, 01 Jan 99 12:00:00 GMT", Some("1999-01-01T12:00:00+00:00")),
+            // Missing timezone
+            ("Mon, 01 Jan 2024 12:00:00", Some("2024-01-01T12:00:00+00:00")),
+            // ISO 8601 variants in RSS
+            ("2024-01-01 12:00:00", Some("2024-01-01T12:00:00+00:00")),
+            ("2024-01-01T12:00", Some("2024-01-01T12:00:00+00:00")),
+            ("2024-01-01 12:00:00 +0100", Some("2024-01-01T11:00:00+00:00")),
+            ("2024-01-01", Some("2024-01-01T00:00:00+00:00")),
+            // Invalid dates
+            ("", None),
+            ("yesterday", None),
+            ("Mon, 32 Jan 2024 12:00:00 GMT", None),
+            ("Mon, 01 Foo 2024 12:00:00 GMT", None),
+        ];
+
+        for (date, expected) in cases {
+            assert_eq!(
+                expected.map(|e| DateTime::parse_from_rfc3339(e).unwrap().with_timezone(&Utc)),
+                parse_date(date),
+                "{date}"
+            );
+        }
+    }
+}
@@ -14,9 +14,6 @@ pub enum FeedParserError {
     #[error("Missing required field: {0}")]
     MissingField(&'static str),
 
-    #[error("Failed to parse date: {0}")]
-    DateParseError(#[from] chrono::ParseError),
-
     #[error("Unexpected error: {0}")]
     Unexpected(#[from] anyhow::Error),
 }
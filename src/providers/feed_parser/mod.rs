@@ -5,6 +5,7 @@
 // Original implementation by Keheliya Gallaba. Subsequent
 // modifications by Nicolás Antinori (AGPL-3.0-only).
 mod atom_parser_impl;
+mod date;
 mod error;
 mod rss_parser_impl;
 
@@ -12,6 +13,8 @@ pub use atom_parser_impl::AtomParserImpl;
 pub use error::FeedParserError;
 pub use rss_parser_impl::RssParserImpl;
 
+use std::collections::BTreeMap;
+
 use crate::models::parsed_feed::ParsedFeed;
 
 pub(crate) type Result<T> = std::result::Result<T, FeedParserError>;
@@ -21,3 +24,62 @@ pub trait FeedParser: Send + Sync {
     /// Parse feed content and return feed metadata
     fn parse_feed(&self, content: &[u8]) -> Result<ParsedFeed>;
 }
+
+/// Common interface of the extension elements of the RSS and Atom crates
+trait ExtensionElement: Sized {
+    fn attrs(&self) -> &BTreeMap<String, String>;
+
+    fn children(&self) -> &BTreeMap<String, Vec<Self>>;
+}
+
+impl ExtensionElement for rss::extension::Extension {
+    fn attrs(&self) -> &BTreeMap<String, String> {
+        self.attrs()
+    }
+
+    fn children(&self) -> &BTreeMap<String, Vec<Self>> {
+        self.children()
+    }
+}
+
+impl ExtensionElement for atom_syndication::extension::Extension {
+    fn attrs(&self) -> &BTreeMap<String, String> {
+        self.attrs()
+    }
+
+    fn children(&self) -> &BTreeMap<String, Vec<Self>> {
+        self.children()
+    }
+}
+
+/// Returns the url of the thumbnail of an item from its Media RSS elements: a `media:thumbnail`,
+/// the thumbnail of a `media:content` or an image `media:content`, also looking inside
+/// `media:group`
+fn get_media_thumbnail<E: ExtensionElement>(media: &BTreeMap<String, Vec<E>>) -> Option<String> {
+    let url = |element: &E| element.attrs().get("url").cloned();
+
+    let thumbnail = media
+        .get("thumbnail")
+        .and_then(|thumbnails| thumbnails.iter().find_map(url));
+
+    let content = || {
+        media.get("content")?.iter().find_map(|content| {
+            let is_image = content.attrs().get("medium").is_some_and(|m| m == "image")
+                || content
+                    .attrs()
+                    .get("type")
+                    .is_some_and(|t| t.starts_with("image/"));
+
+            get_media_thumbnail(content.children()).or_else(|| is_image.then(|| url(content))?)
+        })
+    };
+
+    let group = || {
+        media
+            .get("group")?
+            .iter()
+            .find_map(|group| get_media_thumbnail(group.children()))
+    };
+
+    thumbnail.or_else(content).or_else(group)
+}
@@ -4,24 +4,17 @@
 //
 // Original implementation by Keheliya Gallaba. Subsequent
 // modifications by Nicolás Antinori (AGPL-3.0-only).
-use super::{error::FeedParserError, FeedParser, Result};
-use crate::models::parsed_feed::{ParsedFeed, ParsedItem};
+use super::{date::parse_date, error::FeedParserError, get_media_thumbnail, FeedParser, Result};
+use crate::models::{
+    enclosure::Enclosure,
+    parsed_feed::{ParsedEnclosure, ParsedFeed, ParsedItem},
+};
 use anyhow::anyhow;
-use chrono::{DateTime, Utc};
 use rss::Channel;
 use std::io::BufReader;
 
 pub struct RssParserImpl;
 
-impl RssParserImpl {
-    // TODO: This should be in other struct
-    fn parse_date(&self, date_str: &str) -> Result<DateTime<Utc>> {
-        DateTime::parse_from_rfc2822(date_str)
-            .map(|dt| dt.with_timezone(&Utc))
-            .map_err(FeedParserError::DateParseError)
-    }
-}
-
 impl FeedParser for RssParserImpl {
     fn parse_feed(&self, content: &[u8]) -> Result<ParsedFeed> {
         let reader = BufReader::new(content);
@@ -32,19 +25,72 @@ impl FeedParser for RssParserImpl {
             .items()
             .iter()
             .map(|item| {
-                let pub_date = item.pub_date().and_then(|date| self.parse_date(date).ok());
+                // Some feeds only include the Dublin Core date
+                let pub_date = item.pub_date().and_then(parse_date).or_else(|| {
+                    item.dublin_core_ext()
+                        .and_then(|dc| dc.dates().iter().find_map(|date| parse_date(date)))
+                });
+
+                // RSS allows only one enclosure per item
+                let enclosures = item
+                    .enclosure()
+                    .map(|enclosure| ParsedEnclosure {
+                        url: enclosure.url().to_owned(),
+                        mime_type: Some(enclosure.mime_type().to_owned())
+                            .filter(|m| !m.is_empty()),
+                        // Some feeds set the length to 0 when they do not know it
+                        length: enclosure.length().trim().parse().ok().filter(|l| *l > 0),
+                        duration: item
+                            .itunes_ext()
+                            .and_then(|itunes| itunes.duration())
+                            .and_then(Enclosure::parse_duration),
+                    })
+                    .into_iter()
+                    .collect();
 
                 ParsedItem {
                     title: item.title().unwrap_or("Unknown title").to_owned(),
                     link: item.link().map(|s| s.to_owned()),
                     guid: item.guid().map(|g| g.value().to_owned()),
                     content: item.content().map(|s| s.to_owned()),
+                    summary: item.description().map(|s| s.to_owned()),
                     author: item.author().map(|s| s.to_owned()),
                     pub_date,
+                    // Some feeds include the Atom <updated> element
+                    updated: item
+                        .extensions()
+                        .get("atom")
+                        .and_then(|atom| atom.get("updated"))
+                        .and_then(|updated| updated.first())
+                        .and_then(|updated| updated.value())
+                        .and_then(parse_date),
+                    enclosures,
+                    thumbnail: item.extensions().get("media").and_then(get_media_thumbnail),
+                    categories: item
+                        .categories()
+                        .iter()
+                        .map(|category| category.name().to_owned())
+                        .collect(),
                 }
             })
             .collect();
 
+        // The WebSub links are Atom <link> elements of the channel
+        let atom_link = |rel: &str| {
+            channel
+                .extensions()
+                .get("atom")
+                .and_then(|atom| atom.get("link"))
+                .and_then(|links| {
+                    links
+                        .iter()
+                        .find(|link| link.attrs().get("rel").is_some_and(|r| r == rel))
+                })
+                .and_then(|link| link.attrs().get("href").cloned())
+        };
+        let hub_url = atom_link("hub");
+        let self_url = atom_link("self");
+
         let link = channel.link().to_owned();
         let channel_title = if channel.title().is_empty() {
             &link
@@ -55,6 +101,8 @@ impl FeedParser for RssParserImpl {
         Ok(ParsedFeed {
             title: channel_title.to_owned(),
             link,
+            hub_url,
+            self_url,
             items,
         })
     }
@@ -87,6 +135,145 @@ mod tests {
         assert!(RssParserImpl.parse_feed(rss_content).is_ok());
     }
 
+    #[test]
+    fn test_description_is_parsed_as_summary() {
+        let rss_content = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0">
+            <channel>
+                <title>Test RSS Feed</title>
+                <link>https://example.com/feed</link>
+                <description>A test RSS feed</description>
+                <item>
+                    <title>Test Item</title>
+                    <link>https://example.com/item1</link>
+                    <description>&lt;p&gt;The full text&lt;/p&gt;</description>
+                </item>
+            </channel>
+        </rss>
+        "#
+        .as_bytes();
+
+        let parsed_feed = RssParserImpl.parse_feed(rss_content).unwrap();
+        let item = &parsed_feed.items[0];
+
+        assert_eq!(None, item.content);
+        assert_eq!(Some("<p>The full text</p>"), item.content_or_summary());
+    }
+
+    #[test]
+    fn test_categories_are_parsed() {
+        let rss_content = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0">
+            <channel>
+                <title>Test RSS Feed</title>
+                <link>https://example.com/feed</link>
+                <description>A test RSS feed</description>
+                <item>
+                    <title>Test Item</title>
+                    <link>https://example.com/item1</link>
+                    <category>Rust</category>
+                    <category domain="https://example.com/tags">Web Development</category>
+                </item>
+            </channel>
+        </rss>
+        "#
+        .as_bytes();
+
+        let parsed_feed = RssParserImpl.parse_feed(rss_content).unwrap();
+
+        assert_eq!(
+            vec!["Rust", "Web Development"],
+            parsed_feed.items[0].categories
+        );
+    }
+
+    #[test]
+    fn test_enclosure_is_parsed() {
+        let rss_content = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel>
+                <title>Test Podcast</title>
+                <link>https://example.com/feed</link>
+                <description>A test podcast</description>
+                <item>
+                    <title>Episode 1</title>
+                    <link>https://example.com/episode1</link>
+                    <enclosure url="https://example.com/episode1.mp3" length="1048576" type="audio/mpeg"/>
+                    <itunes:duration>12:34</itunes:duration>
+                </item>
+            </channel>
+        </rss>
+        "#
+        .as_bytes();
+
+        let parsed_feed = RssParserImpl.parse_feed(rss_content).unwrap();
+        let enclosure = &parsed_feed.items[0].enclosures[0];
+
+        assert_eq!("https://example.com/episode1.mp3", enclosure.url);
+        assert_eq!(Some("audio/mpeg"), enclosure.mime_type.as_deref());
+        assert_eq!(Some(1048576), enclosure.length);
+        assert_eq!(Some(754), enclosure.duration);
+    }
+
+    #[test]
+    fn test_media_thumbnail_is_parsed() {
+        let rss_content = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+            <channel>
+                <title>Test Feed</title>
+                <link>https://example.com/feed</link>
+                <description>A test feed</description>
+                <item>
+                    <title>Thumbnail</title>
+                    <link>https://example.com/article1</link>
+                    <media:thumbnail url="https://example.com/thumb1.jpg"/>
+                </item>
+                <item>
+                    <title>Image content</title>
+                    <link>https://example.com/article2</link>
+                    <media:content url="https://example.com/video.mp4" type="video/mp4"/>
+                    <media:content url="https://example.com/image2.jpg" medium="image"/>
+                </item>
+                <item>
+                    <title>Group</title>
+                    <link>https://example.com/article3</link>
+                    <media:group>
+                        <media:content url="https://example.com/video.mp4" type="video/mp4">
+                            <media:thumbnail url="https://example.com/thumb3.jpg"/>
+                        </media:content>
+                    </media:group>
+                </item>
+                <item>
+                    <title>No media</title>
+                    <link>https://example.com/article4</link>
+                </item>
+            </channel>
+        </rss>
+        "#
+        .as_bytes();
+
+        let parsed_feed = RssParserImpl.parse_feed(rss_content).unwrap();
+        let thumbnails: Vec<Option<&str>> = parsed_feed
+            .items
+            .iter()
+            .map(|item| item.thumbnail.as_deref())
+            .collect();
+
+        assert_eq!(
+            vec![
+                Some("https://example.com/thumb1.jpg"),
+                Some("https://example.com/image2.jpg"),
+                Some("https://example.com/thumb3.jpg"),
+                None,
+            ],
+            thumbnails
+        );
+    }
+
     #[test]
     fn test_cannot_parse_atom() {
         let atom_content = r#"
@@ -108,4 +295,29 @@ mod tests {
 
         assert!(RssParserImpl.parse_feed(atom_content).is_err());
     }
+
+    #[test]
+    fn test_websub_links_are_parsed() {
+        let rss_content = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+            <channel>
+                <title>Test RSS Feed</title>
+                <link>https://example.com</link>
+                <description>A test RSS feed</description>
+                <atom:link rel="hub" href="https://hub.example.com/"/>
+                <atom:link rel="self" href="https://example.com/feed.xml" type="application/rss+xml"/>
+            </channel>
+        </rss>
+        "#
+        .as_bytes();
+
+        let parsed_feed = RssParserImpl.parse_feed(rss_content).unwrap();
+
+        assert_eq!(Some("https://hub.example.com/"), parsed_feed.hub_url.as_deref());
+        assert_eq!(
+            Some("https://example.com/feed.xml"),
+            parsed_feed.self_url.as_deref()
+        );
+    }
 }
@@ -1,11 +1,15 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 //! TODO: Implement a proper parser!!!
-use crate::providers::image_processor::ImageProcessor;
+use crate::{
+    models::rewrite_rule::{RewriteRule, RewriteRuleKind},
+    providers::image_processor::ImageProcessor,
+};
 
 use super::{error::HtmlProcessorError, Result};
 use axum::async_trait;
-use regex::Regex;
+use regex::{Captures, Regex};
+use reqwest::Url;
 use scraper::{Html, Selector};
 
 use super::HtmlProcessor;
@@ -16,6 +20,8 @@ pub struct HtmlProcessorImpl {
     tag_removal_regex: Regex,
     attr_removal_regex: Regex,
     favicon_url: Regex,
+    url_attr_regex: Regex,
+    read_more_regex: Regex,
 }
 
 impl HtmlProcessorImpl {
@@ -33,8 +39,63 @@ impl HtmlProcessorImpl {
                 r#"(?i)<link[^>]*rel=["'][^"']*icon[^"']*["'][^>]*href=["']([^"']+)["']"#,
             )
             .map_err(|e| HtmlProcessorError::Unexpected(e.into()))?,
+            url_attr_regex: Regex::new(r#"(?i)\b(?:href|src)\s*=\s*['"]([^'"]+)['"]"#)
+                .map_err(|e| HtmlProcessorError::Unexpected(e.into()))?,
+            read_more_regex: Regex::new(
+                r"(?i)\b(read|continue|keep)\s+(more|reading)\b|\bread\s+the\s+(rest|full)\b|^\s*\[?\s*(…|\.\.\.)\s*\]?\s*$",
+            )
+            .map_err(|e| HtmlProcessorError::Unexpected(e.into()))?,
         })
     }
+
+    /// Removes the elements matched by the selector. If nothing matches, the HTML is returned as
+    /// is instead of serializing it again.
+    fn remove_elements(html: &str, selector: &Selector) -> String {
+        let mut fragment = Html::parse_fragment(html);
+        let matched: Vec<_> = fragment.select(selector).map(|e| e.id()).collect();
+
+        if matched.is_empty() {
+            return html.to_owned();
+        }
+
+        for id in matched {
+            if let Some(mut node) = fragment.tree.get_mut(id) {
+                node.detach();
+            }
+        }
+
+        fragment.root_element().inner_html()
+    }
+
+    /// Removes the query params from an URL. A param ending with `*` matches all the params with
+    /// that prefix. Returns `None` if the URL is not absolute or nothing was removed.
+    fn strip_params_from_url(url: &str, params: &[&str]) -> Option<String> {
+        // Attribute values are HTML encoded, so the params separator is usually written as &amp;
+        let mut url = Url::parse(&url.replace("&amp;", "&")).ok()?;
+
+        let query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        let kept: Vec<&(String, String)> = query
+            .iter()
+            .filter(|(key, _)| {
+                !params.iter().any(|param| match param.strip_suffix('*') {
+                    Some(prefix) => key.starts_with(prefix),
+                    None => key == param,
+                })
+            })
+            .collect();
+
+        if kept.len() == query.len() {
+            return None;
+        }
+
+        if kept.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+
+        Some(url.to_string().replace('&', "&amp;"))
+    }
 }
 
 #[async_trait]
@@ -148,6 +209,123 @@ impl HtmlProcessor for HtmlProcessorImpl {
         Ok(fixed_html)
     }
 
+    fn apply_rewrite_rules(&self, html: &str, rules: &[RewriteRule]) -> Result<String> {
+        let mut html = html.to_owned();
+
+        for rule in rules {
+            html = match rule.kind {
+                RewriteRuleKind::RegexReplace => match Regex::new(&rule.pattern) {
+                    Ok(regex) => regex
+                        .replace_all(&html, rule.replacement.as_deref().unwrap_or_default())
+                        .into_owned(),
+                    Err(e) => {
+                        tracing::warn!("invalid regex in rewrite rule {}: {e}", rule.id);
+                        continue;
+                    }
+                },
+                RewriteRuleKind::RemoveSelector => match Selector::parse(&rule.pattern) {
+                    Ok(selector) => Self::remove_elements(&html, &selector),
+                    Err(e) => {
+                        tracing::warn!("invalid selector in rewrite rule {}: {e:?}", rule.id);
+                        continue;
+                    }
+                },
+                RewriteRuleKind::StripUrlParams => {
+                    let params = rule.url_params();
+                    self.url_attr_regex
+                        .replace_all(&html, |cap: &Captures| {
+                            match Self::strip_params_from_url(&cap[1], &params) {
+                                Some(url) => cap[0].replace(&cap[1], &url),
+                                None => cap[0].to_owned(),
+                            }
+                        })
+                        .into_owned()
+                }
+            };
+        }
+
+        Ok(html)
+    }
+
+    fn is_content_truncated(&self, html: &str, min_length: usize) -> bool {
+        let fragment = Html::parse_fragment(html);
+
+        let text_length: usize = fragment
+            .root_element()
+            .text()
+            .map(|text| text.trim().chars().count())
+            .sum();
+
+        if text_length < min_length {
+            return true;
+        }
+
+        let selector = Selector::parse("a").unwrap();
+        fragment.select(&selector).any(|link| {
+            self.read_more_regex
+                .is_match(&link.text().collect::<String>())
+        })
+    }
+
+    fn get_teaser(&self, html: &str, max_chars: usize) -> Option<String> {
+        let fragment = Html::parse_fragment(html);
+        // Text nodes are joined with spaces so text of contiguous blocks does not get mixed
+        let text = fragment.root_element().text().collect::<Vec<_>>().join(" ");
+
+        let mut teaser = String::new();
+        let mut teaser_length = 0;
+        for word in text.split_whitespace() {
+            let word_length = word.chars().count();
+            if teaser_length + word_length + 1 > max_chars {
+                teaser.push('…');
+                break;
+            }
+
+            if !teaser.is_empty() {
+                teaser.push(' ');
+                teaser_length += 1;
+            }
+            teaser.push_str(word);
+            teaser_length += word_length;
+        }
+
+        (!teaser.is_empty()).then_some(teaser)
+    }
+
+    fn get_lead_image_url(&self, html: &str, link: &str) -> Option<String> {
+        let fragment = Html::parse_fragment(html);
+        let selector = Selector::parse("img[src]").unwrap();
+        let base_url = reqwest::Url::parse(link).ok();
+
+        fragment.select(&selector).find_map(|img| {
+            let src = img.value().attr("src")?.trim();
+            let is_pixel = ["width", "height"]
+                .iter()
+                .any(|attr| img.value().attr(attr).is_some_and(|v| v.trim() == "1"));
+
+            if src.is_empty() || src.starts_with("data:") || is_pixel {
+                return None;
+            }
+
+            match reqwest::Url::parse(src) {
+                Ok(url) => Some(url.into()),
+                Err(_) => base_url.as_ref()?.join(src).ok().map(Into::into),
+            }
+        })
+    }
+
+    fn get_canonical_url(&self, html: &str, link: &str) -> Option<String> {
+        let document = Html::parse_document(html);
+        let selector = Selector::parse(r#"link[rel~="canonical"][href]"#).unwrap();
+
+        let href = document.select(&selector).next()?.value().attr("href")?.trim();
+        if href.is_empty() {
+            return None;
+        }
+
+        Url::parse(link).ok()?.join(href).ok().map(Into::into)
+    }
+
     fn sanitize(&self, html: &str) -> Result<String> {
         // Step 1: Remove harmful tags like <script> or <iframe>
         let sanitized_html = self.tag_removal_regex.replace_all(html, "");
@@ -161,10 +339,10 @@ impl HtmlProcessor for HtmlProcessorImpl {
 
     // Function to extract favicon URL from the HTML
     fn get_favicon_url(&self, html: &str) -> Option<String> {
-        if let Some(captures) = self.favicon_url.captures(html) {
-            if let Some(href) = captures.get(1) {
-                return Some(href.as_str().to_string());
-            }
+        if let Some(captures) = self.favicon_url.captures(html)
+            && let Some(href) = captures.get(1)
+        {
+            return Some(href.as_str().to_string());
         }
 
         None
@@ -174,6 +352,162 @@ impl HtmlProcessor for HtmlProcessorImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use uuid::Uuid;
+
+    fn rewrite_rule(kind: RewriteRuleKind, pattern: &str, replacement: Option<&str>) -> RewriteRule {
+        RewriteRule {
+            id: Uuid::new_v4(),
+            feed_id: Uuid::new_v4(),
+            kind,
+            pattern: pattern.to_owned(),
+            replacement: replacement.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    fn short_content_is_truncated() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+
+        assert!(processor.is_content_truncated("<p>Just a summary</p>", 100));
+        assert!(!processor.is_content_truncated("<p>Just a summary</p>", 10));
+    }
+
+    #[test]
+    fn content_with_read_more_link_is_truncated() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+        let html = r#"<p>A long enough introduction.</p><a href="https://example.com/post">Continue reading</a>"#;
+
+        assert!(processor.is_content_truncated(html, 10));
+        assert!(processor.is_content_truncated(
+            r#"<p>A long enough introduction.</p><a href="https://example.com/post">[…]</a>"#,
+            10
+        ));
+        assert!(!processor.is_content_truncated(
+            r#"<p>A long enough introduction.</p><a href="https://example.com">more about rust</a>"#,
+            10
+        ));
+    }
+
+    #[test]
+    fn teaser_is_plain_text() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+
+        assert_eq!(
+            Some("A short post with some text".to_owned()),
+            processor.get_teaser("<p>A short <b>post</b></p>\n<p>with some   text</p>", 100)
+        );
+        assert_eq!(None, processor.get_teaser("<p> </p><img src=\"a.png\">", 100));
+    }
+
+    #[test]
+    fn long_teaser_is_cut_at_a_word_boundary() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+
+        assert_eq!(
+            Some("The quick brown…".to_owned()),
+            processor.get_teaser("<p>The quick brown fox jumps</p>", 18)
+        );
+    }
+
+    #[test]
+    fn lead_image_is_the_first_real_image() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+        let html = r#"
+            <img src="data:image/png;base64,AAAA">
+            <img src="/pixel.gif" width="1" height="1">
+            <p><img src="images/lead.jpg"></p>
+            <img src="https://cdn.example.com/other.jpg">
+        "#;
+
+        assert_eq!(
+            Some("https://example.com/posts/images/lead.jpg".to_owned()),
+            processor.get_lead_image_url(html, "https://example.com/posts/first")
+        );
+        assert_eq!(
+            None,
+            processor.get_lead_image_url("<p>No images</p>", "https://example.com")
+        );
+    }
+
+    #[test]
+    fn rewrite_regex_replace() {
+        let html = r#"<noscript><img src="http://images.example.com/a.png"></noscript>"#;
+        let rules = [
+            rewrite_rule(
+                RewriteRuleKind::RegexReplace,
+                r"(?s)<noscript>(.*?)</noscript>",
+                Some("$1"),
+            ),
+            rewrite_rule(
+                RewriteRuleKind::RegexReplace,
+                r#"src="http://"#,
+                Some(r#"src="https://"#),
+            ),
+        ];
+
+        let result = HtmlProcessorImpl::new()
+            .unwrap()
+            .apply_rewrite_rules(html, &rules);
+
+        assert_eq!(
+            r#"<img src="https://images.example.com/a.png">"#,
+            result.unwrap()
+        );
+    }
+
+    #[test]
+    fn rewrite_remove_selector() {
+        let html = r#"<div><p>Content</p><p class="footer">Subscribe!</p></div>"#;
+        let rules = [rewrite_rule(RewriteRuleKind::RemoveSelector, "p.footer", None)];
+
+        let result = HtmlProcessorImpl::new()
+            .unwrap()
+            .apply_rewrite_rules(html, &rules);
+
+        assert_eq!("<div><p>Content</p></div>", result.unwrap());
+    }
+
+    #[test]
+    fn rewrite_strip_url_params() {
+        let html = concat!(
+            r#"<a href="https://example.com/post?id=1&amp;utm_source=rss&amp;utm_medium=feed">"#,
+            r#"<img src="https://example.com/a.png?fbclid=abc"></a>"#,
+            r#"<a href="/relative?utm_source=rss">"#,
+        );
+        let rules = [rewrite_rule(
+            RewriteRuleKind::StripUrlParams,
+            "utm_*, fbclid",
+            None,
+        )];
+
+        let result = HtmlProcessorImpl::new()
+            .unwrap()
+            .apply_rewrite_rules(html, &rules);
+
+        assert_eq!(
+            concat!(
+                r#"<a href="https://example.com/post?id=1">"#,
+                r#"<img src="https://example.com/a.png"></a>"#,
+                r#"<a href="/relative?utm_source=rss">"#,
+            ),
+            result.unwrap()
+        );
+    }
+
+    #[test]
+    fn rewrite_skips_invalid_rules() {
+        let html = "<p>Content</p>";
+        let rules = [
+            rewrite_rule(RewriteRuleKind::RegexReplace, "(unclosed", Some("")),
+            rewrite_rule(RewriteRuleKind::RemoveSelector, "p[", None),
+        ];
+
+        let result = HtmlProcessorImpl::new()
+            .unwrap()
+            .apply_rewrite_rules(html, &rules);
+
+        assert_eq!(html, result.unwrap());
+    }
 
     #[test]
     fn remove_iframe() {
@@ -197,6 +531,24 @@ mod tests {
         assert_eq!(expected, result.unwrap());
     }
 
+    #[test]
+    fn canonical_url_is_resolved() {
+        let processor = HtmlProcessorImpl::new().unwrap();
+        let link = "https://example.com/feed/post?utm_source=rss";
+
+        assert_eq!(
+            Some("https://example.com/post".to_owned()),
+            processor.get_canonical_url(
+                r#"<html><head><link rel="canonical" href="/post"></head></html>"#,
+                link
+            )
+        );
+        assert_eq!(
+            None,
+            processor.get_canonical_url("<html><head></head><body></body></html>", link)
+        );
+    }
+
     #[test]
     fn remove_scripts() {
         let iframe = r#"
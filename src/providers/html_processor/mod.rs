@@ -8,6 +8,7 @@ use error::HtmlProcessorError;
 pub use html_processor_impl::HtmlProcessorImpl;
 
 use super::image_processor::ImageProcessor;
+use crate::models::rewrite_rule::RewriteRule;
 
 type Result<T> = std::result::Result<T, HtmlProcessorError>;
 
@@ -25,6 +26,27 @@ pub trait HtmlProcessor: Sync + Send {
     where
         P: ImageProcessor + ?Sized;
 
+    /// Applies the rewrite rules of a feed to the HTML, in order.
+    /// Rules with an invalid regex or selector are skipped. URL params are only stripped from
+    /// absolute URLs.
+    fn apply_rewrite_rules(&self, html: &str, rules: &[RewriteRule]) -> Result<String>;
+
+    /// Checks if the content of an article looks truncated: its text is shorter than
+    /// `min_length` characters or it contains a "read more" link
+    fn is_content_truncated(&self, html: &str, min_length: usize) -> bool;
+
+    /// Returns the text of the HTML, with the whitespace collapsed and cut at a word boundary if
+    /// it is longer than `max_chars`. Returns `None` if there is no text
+    fn get_teaser(&self, html: &str, max_chars: usize) -> Option<String>;
+
+    /// Returns the absolute url of the first image of the HTML, skipping inline images and
+    /// tracking pixels. Relative urls are resolved against `link`
+    fn get_lead_image_url(&self, html: &str, link: &str) -> Option<String>;
+
+    /// Returns the absolute url declared by the `<link rel="canonical">` of a web page, if any.
+    /// Relative urls are resolved against `link`
+    fn get_canonical_url(&self, html: &str, link: &str) -> Option<String>;
+
     /// Sanitizes the HTML
     /// Removes potentially harmful tags such as <iframe> and <script>
     fn sanitize(&self, html: &str) -> Result<String>;
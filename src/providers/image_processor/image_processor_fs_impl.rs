@@ -1,14 +1,21 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 //! This implementation will download the images and place them in thh file system
+use std::io::Cursor;
 use std::path::Path;
 
 use super::Result;
-use axum::async_trait;
+use axum::{async_trait, body::Bytes};
+use image::{DynamicImage, codecs::jpeg::JpegEncoder};
 use tokio::{fs, io::AsyncWriteExt};
 use uuid::Uuid;
 
-use super::{ImageProcessor, ImageProcessorError};
+use super::{ImageProcessor, ImageProcessorError, THUMBNAIL_EXTENSION};
+
+/// Maximum width and height of the thumbnails, in pixels
+const THUMBNAIL_SIZE: u32 = 160;
+
+const THUMBNAIL_JPEG_QUALITY: u8 = 75;
 
 pub struct ImageProcessorFsImpl<P>
 where
@@ -28,35 +35,31 @@ where
             article_fs_path,
         }
     }
-}
-
-#[async_trait]
-impl<P> ImageProcessor for ImageProcessorFsImpl<P>
-where
-    P: AsRef<Path> + Sync + Send,
-{
-    async fn process_image_url(&self, url: &str) -> Result<String> {
-        let mut image_path = self.article_fs_path.as_ref().join("static/");
-        fs::create_dir_all(&image_path)
-            .await
-            .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
 
-        let image_data = reqwest::get(url)
+    async fn download_image(url: &str) -> Result<Bytes> {
+        reqwest::get(url)
             .await
             .map_err(|e| ImageProcessorError::UnableToDownload(url.to_owned(), e))?
             .bytes()
+            .await
+            .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))
+    }
+
+    /// Saves the image in the static directory of the feed and returns the path it is served from
+    async fn save_image(&self, image_file_name: &str, image_data: &[u8]) -> Result<String> {
+        let mut image_path = self.article_fs_path.as_ref().join("static/");
+        fs::create_dir_all(&image_path)
             .await
             .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
 
-        let image_file_name = Uuid::new_v4().to_string();
-        image_path.push(&image_file_name);
+        image_path.push(image_file_name);
 
         // Create the file and write the content
         let mut file = fs::File::create(&image_path)
             .await
             .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
 
-        fs::File::write(&mut file, &image_data)
+        fs::File::write(&mut file, image_data)
             .await
             .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
 
@@ -68,4 +71,74 @@ where
                 "unable to convert the path {image_path:?} to string"
             })))
     }
+
+    /// Scales down the image to fit in a `THUMBNAIL_SIZE` square and converts it to a grayscale
+    /// JPEG, that is what e-ink screens show anyway
+    fn create_thumbnail(image_data: &[u8]) -> Result<Vec<u8>> {
+        let image = image::load_from_memory(image_data)
+            .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
+
+        let thumbnail =
+            DynamicImage::ImageLuma8(image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE).into_luma8());
+
+        let mut thumbnail_data = Cursor::new(Vec::new());
+        thumbnail
+            .write_with_encoder(JpegEncoder::new_with_quality(
+                &mut thumbnail_data,
+                THUMBNAIL_JPEG_QUALITY,
+            ))
+            .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))?;
+
+        Ok(thumbnail_data.into_inner())
+    }
+}
+
+#[async_trait]
+impl<P> ImageProcessor for ImageProcessorFsImpl<P>
+where
+    P: AsRef<Path> + Sync + Send,
+{
+    async fn process_image_url(&self, url: &str) -> Result<String> {
+        let image_data = Self::download_image(url).await?;
+
+        self.save_image(&Uuid::new_v4().to_string(), &image_data)
+            .await
+    }
+
+    async fn process_thumbnail_url(&self, url: &str) -> Result<String> {
+        let image_data = Self::download_image(url).await?;
+
+        // Decoding and resizing the image is CPU bound, so it should not block the runtime
+        let thumbnail_data =
+            tokio::task::spawn_blocking(move || Self::create_thumbnail(&image_data))
+                .await
+                .map_err(|e| ImageProcessorError::UnableToProcess(e.into()))??;
+
+        self.save_image(&format!("{}{THUMBNAIL_EXTENSION}", Uuid::new_v4()), &thumbnail_data)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, RgbImage};
+
+    #[test]
+    fn thumbnail_is_small_and_grayscale() {
+        let mut image_data = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(800, 400, image::Rgb([200, 30, 30])))
+            .write_to(&mut image_data, ImageFormat::Png)
+            .unwrap();
+
+        let thumbnail_data =
+            ImageProcessorFsImpl::<&str>::create_thumbnail(image_data.get_ref()).unwrap();
+        let thumbnail = image::load_from_memory(&thumbnail_data).unwrap();
+
+        assert_eq!(
+            (THUMBNAIL_SIZE, THUMBNAIL_SIZE / 2),
+            (thumbnail.width(), thumbnail.height())
+        );
+        assert_eq!(image::ColorType::L8, thumbnail.color());
+    }
 }
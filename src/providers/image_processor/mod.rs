@@ -9,6 +9,9 @@ pub use image_processor_fs_impl::ImageProcessorFsImpl;
 
 type Result<T> = std::result::Result<T, ImageProcessorError>;
 
+/// Extension of the thumbnail files. The other images are saved without one
+pub const THUMBNAIL_EXTENSION: &str = ".jpg";
+
 #[async_trait]
 pub trait ImageProcessor: Sync + Send {
     /// Process an image url and returns the result of the processing.
@@ -18,4 +21,8 @@ pub trait ImageProcessor: Sync + Send {
     ///   - We can also embed the image information in the src attribute of the img tag, in that
     ///   case it should return the encoded image information
     async fn process_image_url(&self, url: &str) -> Result<String>;
+
+    /// Same as `process_image_url`, but the image is turned into a small grayscale thumbnail to
+    /// show in the article lists
+    async fn process_thumbnail_url(&self, url: &str) -> Result<String>;
 }
@@ -1,7 +1,12 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+pub mod charset;
+pub mod enclosure;
+pub mod email;
+pub mod epub;
 pub mod favicon;
 pub mod feed_parser;
 pub mod html_processor;
 pub mod image_processor;
 pub mod persisted_config;
+pub mod save_to;
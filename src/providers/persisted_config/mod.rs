@@ -4,7 +4,9 @@
 mod error;
 mod persisted_config_fs_impl;
 
-use crate::models::persisted_config::PersistedConfig;
+use crate::models::persisted_config::{
+    ArticleSort, DigestConfig, PersistedConfig, SaveEndpointConfig, SmtpConfig, WallabagConfig,
+};
 use axum::async_trait;
 pub use error::PersistedConfigProviderError;
 pub use persisted_config_fs_impl::PersistedConfigProviderImpl;
@@ -24,4 +26,16 @@ pub trait PersistedConfigProvider: Sync + Send {
     async fn set_hide_article_header(&self, value: bool) -> Result<PersistedConfig>;
 
     async fn set_dont_invert_images(&self, value: bool) -> Result<PersistedConfig>;
+
+    async fn set_show_thumbnails(&self, value: bool) -> Result<PersistedConfig>;
+
+    async fn set_article_sort(&self, value: ArticleSort) -> Result<PersistedConfig>;
+
+    async fn set_digest(&self, value: DigestConfig) -> Result<PersistedConfig>;
+
+    async fn set_smtp(&self, value: SmtpConfig) -> Result<PersistedConfig>;
+
+    async fn set_wallabag(&self, value: WallabagConfig) -> Result<PersistedConfig>;
+
+    async fn set_save_endpoint(&self, value: SaveEndpointConfig) -> Result<PersistedConfig>;
 }
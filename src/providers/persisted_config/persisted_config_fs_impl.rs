@@ -3,7 +3,9 @@
 use axum::async_trait;
 use tokio::sync::RwLock;
 
-use crate::models::persisted_config::PersistedConfig;
+use crate::models::persisted_config::{
+    ArticleSort, DigestConfig, PersistedConfig, SaveEndpointConfig, SmtpConfig, WallabagConfig,
+};
 
 use super::{PersistedConfigProvider, Result};
 
@@ -54,4 +56,40 @@ impl PersistedConfigProvider for PersistedConfigProviderImpl {
         config.dont_invert_images = value;
         Ok(config.clone())
     }
+
+    async fn set_show_thumbnails(&self, value: bool) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.show_thumbnails = value;
+        Ok(config.clone())
+    }
+
+    async fn set_article_sort(&self, value: ArticleSort) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.article_sort = value;
+        Ok(config.clone())
+    }
+
+    async fn set_digest(&self, value: DigestConfig) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.digest = value;
+        Ok(config.clone())
+    }
+
+    async fn set_smtp(&self, value: SmtpConfig) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.smtp = value;
+        Ok(config.clone())
+    }
+
+    async fn set_wallabag(&self, value: WallabagConfig) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.wallabag = value;
+        Ok(config.clone())
+    }
+
+    async fn set_save_endpoint(&self, value: SaveEndpointConfig) -> Result<PersistedConfig> {
+        let mut config = self.loaded_configuration.write().await;
+        config.save_endpoint = value;
+        Ok(config.clone())
+    }
 }
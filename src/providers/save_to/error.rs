@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SaveToProviderError {
+    #[error("Request error: {0}")]
+    RequestError(String),
+
+    #[error("The service responded with status {0}")]
+    UnexpectedStatus(u16),
+
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+impl From<reqwest::Error> for SaveToProviderError {
+    fn from(error: reqwest::Error) -> Self {
+        match error.status() {
+            Some(status) => Self::UnexpectedStatus(status.as_u16()),
+            None => Self::RequestError(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Read-it-later services the articles can be saved to. Each one implements `SaveToProvider`
+//! with its own settings
+mod error;
+mod save_endpoint_provider_impl;
+mod wallabag_provider_impl;
+
+pub use error::SaveToProviderError;
+pub use save_endpoint_provider_impl::SaveEndpointProviderImpl;
+pub use wallabag_provider_impl::WallabagProviderImpl;
+
+use std::time::Duration;
+
+use axum::async_trait;
+
+use crate::models::article::Article;
+
+pub(crate) type Result<T> = std::result::Result<T, SaveToProviderError>;
+
+/// Time to wait for the responses of the services
+const SAVE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[async_trait]
+pub trait SaveToProvider: Sync + Send {
+    /// Settings of the service, like its url and credentials
+    type Settings: Sync + Send;
+
+    /// Saves the article in the service and returns its url there, if it is known
+    async fn save(&self, settings: &Self::Settings, article: &Article) -> Result<Option<String>>;
+}
@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use axum::async_trait;
+use reqwest::{header::CONTENT_TYPE, Client};
+use serde_json::json;
+
+use super::{Result, SaveToProvider, SAVE_TIMEOUT};
+use crate::models::{article::Article, persisted_config::SaveEndpointConfig};
+
+/// Sends the url and the title of the article as JSON to the configured endpoint
+pub struct SaveEndpointProviderImpl {
+    client: Client,
+}
+
+impl SaveEndpointProviderImpl {
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(SAVE_TIMEOUT)
+            .build()
+            .expect("unable to initialize the save endpoint http client");
+
+        Self { client }
+    }
+}
+
+impl Default for SaveEndpointProviderImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SaveToProvider for SaveEndpointProviderImpl {
+    type Settings = SaveEndpointConfig;
+
+    async fn save(
+        &self,
+        settings: &SaveEndpointConfig,
+        article: &Article,
+    ) -> Result<Option<String>> {
+        let mut request = self
+            .client
+            .post(&settings.url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(json!({ "url": article.link, "title": article.title }).to_string());
+        if !settings.token.is_empty() {
+            request = request.bearer_auth(&settings.token);
+        }
+
+        request.send().await?.error_for_status()?;
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use axum::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::{Result, SaveToProvider, SaveToProviderError, SAVE_TIMEOUT};
+use crate::models::{article::Article, persisted_config::WallabagConfig};
+
+/// Saves the articles through the API of a Wallabag server. The access token is requested with
+/// the OAuth password grant every time, as they expire after an hour
+pub struct WallabagProviderImpl {
+    client: Client,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct EntryResponse {
+    id: i64,
+}
+
+impl WallabagProviderImpl {
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(SAVE_TIMEOUT)
+            .build()
+            .expect("unable to initialize the wallabag http client");
+
+        Self { client }
+    }
+}
+
+impl Default for WallabagProviderImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SaveToProvider for WallabagProviderImpl {
+    type Settings = WallabagConfig;
+
+    async fn save(&self, settings: &WallabagConfig, article: &Article) -> Result<Option<String>> {
+        let base_url = settings.url.trim_end_matches('/');
+
+        let response = self
+            .client
+            .post(format!("{base_url}/oauth/v2/token"))
+            .form(&[
+                ("grant_type", "password"),
+                ("client_id", &settings.client_id),
+                ("client_secret", &settings.client_secret),
+                ("username", &settings.username),
+                ("password", &settings.password),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let token: TokenResponse = serde_json::from_slice(&response)
+            .map_err(|e| SaveToProviderError::InvalidResponse(e.to_string()))?;
+
+        let response = self
+            .client
+            .post(format!("{base_url}/api/entries.json"))
+            .bearer_auth(&token.access_token)
+            .form(&[("url", &article.link), ("title", &article.title)])
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let entry: EntryResponse = serde_json::from_slice(&response)
+            .map_err(|e| SaveToProviderError::InvalidResponse(e.to_string()))?;
+
+        Ok(Some(format!("{base_url}/view/{}", entry.id)))
+    }
+}
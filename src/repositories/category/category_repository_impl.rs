@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{
+    models::{article::Article, category::Category},
+    repositories::{next_numeric_id, RepositoryError},
+    transaction,
+};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{CategoryRepository, Result};
+
+#[derive(Clone)]
+pub struct CategoryRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl CategoryRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryRepositoryImpl {
+    async fn get_category_list(&self) -> Result<Vec<Category>> {
+        self.connection
+            .prepare(
+                "SELECT c.*,
+                (SELECT COUNT(*) FROM article a JOIN feed f ON a.feed_id = f.id
+                 WHERE f.category_id = c.id AND a.read = 0) as unread_count
+                FROM category c ORDER BY c.name COLLATE NOCASE;",
+            )?
+            .into_iter()
+            .flat_map(|r| r.map(Category::try_from))
+            .collect()
+    }
+
+    async fn get_category(&self, category_id: Uuid) -> Result<Option<Category>> {
+        self.connection
+            .prepare(
+                "SELECT c.*,
+                (SELECT COUNT(*) FROM article a JOIN feed f ON a.feed_id = f.id
+                 WHERE f.category_id = c.id AND a.read = 0) as unread_count
+                FROM category c WHERE c.id = ?;",
+            )?
+            .into_iter()
+            .bind((1, category_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Category::try_from)
+            })
+            .transpose()
+    }
+
+    async fn get_category_by_name(&self, name: &str) -> Result<Option<Category>> {
+        self.connection
+            .prepare("SELECT c.*, 0 as unread_count FROM category c WHERE c.name = ?;")?
+            .into_iter()
+            .bind((1, name))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Category::try_from)
+            })
+            .transpose()
+    }
+
+    async fn add_category(&self, category: &Category) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("INSERT INTO category (id, name, numeric_id) VALUES (?, ?, ?);")?;
+            stmt.bind((1, category.id.to_string().as_str()))?;
+            stmt.bind((2, category.name.as_str()))?;
+            stmt.bind((3, next_numeric_id(&self.connection, "category")?))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn rename_category(&self, category_id: Uuid, name: &str) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("UPDATE category SET name = ? WHERE id = ?")?;
+            stmt.bind((1, name))?;
+            stmt.bind((2, category_id.to_string().as_str()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_category(&self, category_id: Uuid) -> Result<()> {
+        transaction!(self, {
+            // First detach the feeds from the category
+            let mut stmt = self
+                .connection
+                .prepare("UPDATE feed SET category_id = NULL WHERE category_id = ?")?;
+            stmt.bind((1, category_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the webhooks that only apply to this category, with their deliveries
+            let mut stmt = self.connection.prepare(
+                r#"
+                    DELETE FROM webhook_delivery
+                    WHERE webhook_id IN (SELECT id FROM webhook WHERE category_id = ?)
+                "#,
+            )?;
+            stmt.bind((1, category_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM webhook WHERE category_id = ?")?;
+            stmt.bind((1, category_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Then delete the category itself
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM category WHERE id = ?")?;
+            stmt.bind((1, category_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_category_articles(&self, category_id: Uuid) -> Result<Vec<Article>> {
+        self.connection
+            .prepare(
+                "SELECT a.* FROM article a JOIN feed f ON a.feed_id = f.id
+                WHERE f.category_id = ?",
+            )?
+            .into_iter()
+            .bind((1, category_id.to_string().as_str()))?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        feed::{FeedRepository, FeedRepositoryImpl},
+        test_utils::{test_article, test_database, test_feed},
+    };
+
+    fn category(name: &str) -> Category {
+        Category {
+            id: Uuid::new_v4(),
+            numeric_id: 0,
+            name: name.to_owned(),
+            unread_count: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn categories_are_added_renamed_and_listed_by_name() {
+        let connection = Arc::new(test_database(None));
+        let repository = CategoryRepositoryImpl::new(connection);
+
+        let news = category("news");
+        repository.add_category(&news).await.unwrap();
+        repository.add_category(&category("Blogs")).await.unwrap();
+
+        let list = repository.get_category_list().await.unwrap();
+        let names: Vec<&str> = list.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(vec!["Blogs", "news"], names);
+        assert!(list[0].numeric_id != list[1].numeric_id);
+
+        repository.rename_category(news.id, "World").await.unwrap();
+        let renamed = repository.get_category(news.id).await.unwrap().unwrap();
+        assert_eq!("World", renamed.name);
+        assert!(repository.get_category_by_name("news").await.unwrap().is_none());
+        assert!(repository.get_category_by_name("World").await.unwrap().is_some());
+        assert!(repository.get_category(Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn categories_count_the_unread_articles_of_their_feeds() {
+        let connection = Arc::new(test_database(None));
+        let repository = CategoryRepositoryImpl::new(connection.clone());
+        let feed_repository = FeedRepositoryImpl::new(connection);
+
+        let news = category("news");
+        repository.add_category(&news).await.unwrap();
+        let feed = test_feed("news", Some(news.id));
+        let other_feed = test_feed("other", None);
+        feed_repository.add_feed(feed.clone()).await.unwrap();
+        feed_repository.add_feed(other_feed.clone()).await.unwrap();
+
+        let mut read = test_article(feed.id, "read");
+        read.read = true;
+        let unread = test_article(feed.id, "unread");
+        let other = test_article(other_feed.id, "other");
+        feed_repository.add_articles(feed.id, &[&read, &unread]).await.unwrap();
+        feed_repository.add_articles(other_feed.id, &[&other]).await.unwrap();
+
+        assert_eq!(1, repository.get_category(news.id).await.unwrap().unwrap().unread_count);
+        assert_eq!(2, repository.get_category_articles(news.id).await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn deleting_a_category_keeps_its_feeds() {
+        let connection = Arc::new(test_database(None));
+        let repository = CategoryRepositoryImpl::new(connection.clone());
+        let feed_repository = FeedRepositoryImpl::new(connection);
+
+        let news = category("news");
+        repository.add_category(&news).await.unwrap();
+        let feed = test_feed("news", Some(news.id));
+        feed_repository.add_feed(feed.clone()).await.unwrap();
+
+        repository.delete_category(news.id).await.unwrap();
+
+        assert!(repository.get_category_list().await.unwrap().is_empty());
+        let feed = feed_repository.get_feed(feed.id).await.unwrap().unwrap();
+        assert_eq!(None, feed.category_id);
+    }
+}
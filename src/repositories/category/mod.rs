@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the categories (folders) used to group feeds
+mod category_repository_impl;
+
+use crate::models::{article::Article, category::Category};
+use axum::async_trait;
+
+pub use category_repository_impl::CategoryRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait CategoryRepository: Sync + Send {
+    async fn get_category_list(&self) -> Result<Vec<Category>>;
+
+    async fn get_category(&self, category_id: Uuid) -> Result<Option<Category>>;
+
+    async fn get_category_by_name(&self, name: &str) -> Result<Option<Category>>;
+
+    async fn add_category(&self, category: &Category) -> Result<()>;
+
+    async fn rename_category(&self, category_id: Uuid, name: &str) -> Result<()>;
+
+    /// Deletes the category. The feeds that belonged to it are left without category
+    async fn delete_category(&self, category_id: Uuid) -> Result<()>;
+
+    /// Returns the articles of all the feeds that belong to the category
+    async fn get_category_articles(&self, category_id: Uuid) -> Result<Vec<Article>>;
+}
@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{config::Config, models::digest::Digest, repositories::RepositoryError, transaction};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use tokio::fs;
+use uuid::Uuid;
+
+use super::{DigestRepository, Result};
+
+pub struct DigestRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+    config: Arc<Config>,
+}
+
+impl DigestRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>, config: Arc<Config>) -> Self {
+        Self { connection, config }
+    }
+}
+
+#[async_trait]
+impl DigestRepository for DigestRepositoryImpl {
+    async fn get_digest_list(&self) -> Result<Vec<Digest>> {
+        self.connection
+            .prepare("SELECT * FROM digest ORDER BY created_at DESC")?
+            .into_iter()
+            .flat_map(|r| r.map(Digest::try_from))
+            .collect()
+    }
+
+    async fn get_digest(&self, digest_id: Uuid) -> Result<Option<Digest>> {
+        self.connection
+            .prepare("SELECT * FROM digest WHERE id = ?")?
+            .into_iter()
+            .bind((1, digest_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Digest::try_from)
+            })
+            .transpose()
+    }
+
+    async fn get_digest_file(&self, digest: &Digest) -> Result<Vec<u8>> {
+        fs::read(&digest.file_path)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))
+    }
+
+    async fn add_digest(&self, digest: &mut Digest, epub: &[u8]) -> Result<()> {
+        let directory = format!("{}/digests", self.config.data_path);
+        fs::create_dir_all(&directory)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+        digest.file_path = format!("{directory}/{}.epub", digest.id);
+        fs::write(&digest.file_path, epub)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO digest (id, title, created_at, article_count, file_path)
+                    VALUES (?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, digest.id.to_string().as_str()))?;
+            stmt.bind((2, digest.title.as_str()))?;
+            stmt.bind((3, digest.created_at.to_rfc3339().as_str()))?;
+            stmt.bind((4, digest.article_count as i64))?;
+            stmt.bind((5, digest.file_path.as_str()))?;
+
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_digest(&self, digest: &Digest) -> Result<()> {
+        match fs::remove_file(&digest.file_path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(RepositoryError::Unexpected(e.into())),
+        }
+
+        transaction!(self, {
+            let mut stmt = self.connection.prepare("DELETE FROM digest WHERE id = ?")?;
+            stmt.bind((1, digest.id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the editions of the digest and their EPUB files
+mod digest_repository_impl;
+
+use crate::models::digest::Digest;
+use axum::async_trait;
+
+pub use digest_repository_impl::DigestRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait DigestRepository: Sync + Send {
+    /// Returns the digests, the most recent first
+    async fn get_digest_list(&self) -> Result<Vec<Digest>>;
+
+    async fn get_digest(&self, digest_id: Uuid) -> Result<Option<Digest>>;
+
+    /// Returns the content of the EPUB file of the digest
+    async fn get_digest_file(&self, digest: &Digest) -> Result<Vec<u8>>;
+
+    /// Saves the digest with the content of its EPUB file. The path of the file is set by the
+    /// repository
+    async fn add_digest(&self, digest: &mut Digest, epub: &[u8]) -> Result<()>;
+
+    /// Deletes the digest and its EPUB file
+    async fn delete_digest(&self, digest: &Digest) -> Result<()>;
+}
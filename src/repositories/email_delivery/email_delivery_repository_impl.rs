@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{
+    config::Config,
+    models::{email_delivery::EmailDelivery, webhook::DeliveryStatus},
+    repositories::RepositoryError,
+    transaction,
+};
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use tokio::fs;
+
+use super::{EmailDeliveryRepository, Result};
+
+pub struct EmailDeliveryRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+    config: Arc<Config>,
+}
+
+impl EmailDeliveryRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>, config: Arc<Config>) -> Self {
+        Self { connection, config }
+    }
+}
+
+#[async_trait]
+impl EmailDeliveryRepository for EmailDeliveryRepositoryImpl {
+    async fn add_delivery(&self, delivery: &mut EmailDelivery, epub: &[u8]) -> Result<()> {
+        let directory = format!("{}/outbox", self.config.data_path);
+        fs::create_dir_all(&directory)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+        delivery.file_path = format!("{directory}/{}.epub", delivery.id);
+        fs::write(&delivery.file_path, epub)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO email_delivery
+                        (id, title, file_path, status, attempts, created_at, next_attempt_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, delivery.id.to_string().as_str()))?;
+            stmt.bind((2, delivery.title.as_str()))?;
+            stmt.bind((3, delivery.file_path.as_str()))?;
+            stmt.bind((4, delivery.status.as_str()))?;
+            stmt.bind((5, i64::from(delivery.attempts)))?;
+            stmt.bind((6, delivery.created_at.to_rfc3339().as_str()))?;
+            stmt.bind((7, delivery.next_attempt_at.to_rfc3339().as_str()))?;
+
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_delivery_file(&self, delivery: &EmailDelivery) -> Result<Vec<u8>> {
+        fs::read(&delivery.file_path)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))
+    }
+
+    async fn get_due_deliveries(&self, now: DateTime<Utc>) -> Result<Vec<EmailDelivery>> {
+        self.connection
+            .prepare(
+                r#"
+                    SELECT * FROM email_delivery
+                    WHERE status = ? AND next_attempt_at <= ?
+                    ORDER BY created_at
+                "#,
+            )?
+            .into_iter()
+            .bind((1, DeliveryStatus::Pending.as_str()))?
+            .bind((2, now.to_rfc3339().as_str()))?
+            .flat_map(|r| r.map(EmailDelivery::try_from))
+            .collect()
+    }
+
+    async fn update_delivery(&self, delivery: &EmailDelivery) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE email_delivery
+                    SET status = ?, attempts = ?, next_attempt_at = ?, last_attempt_at = ?,
+                        error = ?
+                    WHERE id = ?
+                "#,
+            )?;
+            stmt.bind((1, delivery.status.as_str()))?;
+            stmt.bind((2, i64::from(delivery.attempts)))?;
+            stmt.bind((3, delivery.next_attempt_at.to_rfc3339().as_str()))?;
+            stmt.bind((4, delivery.last_attempt_at.map(|d| d.to_rfc3339()).as_deref()))?;
+            stmt.bind((5, delivery.error.as_deref()))?;
+            stmt.bind((6, delivery.id.to_string().as_str()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok::<_, RepositoryError>(())
+        })?;
+
+        if delivery.status != DeliveryStatus::Pending {
+            match fs::remove_file(&delivery.file_path).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(RepositoryError::Unexpected(e.into())),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_delivery_list(&self, limit: u32) -> Result<Vec<EmailDelivery>> {
+        self.connection
+            .prepare("SELECT * FROM email_delivery ORDER BY created_at DESC LIMIT ?")?
+            .into_iter()
+            .bind((1, i64::from(limit)))?
+            .flat_map(|r| r.map(EmailDelivery::try_from))
+            .collect()
+    }
+
+    async fn delete_deliveries_before(&self, date: DateTime<Utc>) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM email_delivery WHERE status != ? AND created_at < ?")?;
+            stmt.bind((1, DeliveryStatus::Pending.as_str()))?;
+            stmt.bind((2, date.to_rfc3339().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the outbox of the documents sent by email and their attachments
+mod email_delivery_repository_impl;
+
+use crate::models::email_delivery::EmailDelivery;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+
+pub use email_delivery_repository_impl::EmailDeliveryRepositoryImpl;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait EmailDeliveryRepository: Sync + Send {
+    /// Queues the email with the content of its EPUB attachment. The path of the file is set by
+    /// the repository
+    async fn add_delivery(&self, delivery: &mut EmailDelivery, epub: &[u8]) -> Result<()>;
+
+    /// Returns the content of the EPUB attachment of the email
+    async fn get_delivery_file(&self, delivery: &EmailDelivery) -> Result<Vec<u8>>;
+
+    /// Returns the pending emails whose next attempt is due, the oldest first
+    async fn get_due_deliveries(&self, now: DateTime<Utc>) -> Result<Vec<EmailDelivery>>;
+
+    /// Saves the status and the result of the last attempt of an email. The attachment is deleted
+    /// when the email is not pending anymore
+    async fn update_delivery(&self, delivery: &EmailDelivery) -> Result<()>;
+
+    /// Returns the most recent emails, the newest first
+    async fn get_delivery_list(&self, limit: u32) -> Result<Vec<EmailDelivery>>;
+
+    /// Deletes the finished (delivered or failed) emails created before the date
+    async fn delete_deliveries_before(&self, date: DateTime<Utc>) -> Result<()>;
+}
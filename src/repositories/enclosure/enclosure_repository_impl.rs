@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{models::enclosure::Enclosure, transaction};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{EnclosureRepository, Result};
+
+#[derive(Clone)]
+pub struct EnclosureRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl EnclosureRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl EnclosureRepository for EnclosureRepositoryImpl {
+    async fn get_article_enclosures(&self, article_id: Uuid) -> Result<Vec<Enclosure>> {
+        self.connection
+            .prepare("SELECT * FROM enclosure WHERE article_id = ? ORDER BY rowid")?
+            .into_iter()
+            .bind((1, article_id.to_string().as_str()))?
+            .flat_map(|r| r.map(Enclosure::try_from))
+            .collect()
+    }
+
+    async fn add_enclosures(&self, enclosures: &[Enclosure]) -> Result<()> {
+        transaction!(self, {
+            for enclosure in enclosures {
+                let mut stmt = self.connection.prepare(
+                    r#"
+                        INSERT INTO enclosure (id, article_id, feed_id, url, mime_type, length, duration, cached_url)
+                        VALUES (:id, :article_id, :feed_id, :url, :mime_type, :length, :duration, :cached_url);
+                    "#,
+                )?;
+                stmt.bind((":id", enclosure.id.to_string().as_str()))?;
+                stmt.bind((":article_id", enclosure.article_id.to_string().as_str()))?;
+                stmt.bind((":feed_id", enclosure.feed_id.to_string().as_str()))?;
+                stmt.bind((":url", enclosure.url.as_str()))?;
+                stmt.bind((":mime_type", enclosure.mime_type.as_deref()))?;
+                stmt.bind((
+                    ":length",
+                    enclosure.length.and_then(|l| i64::try_from(l).ok()),
+                ))?;
+                stmt.bind((":duration", enclosure.duration.map(i64::from)))?;
+                stmt.bind((":cached_url", enclosure.cached_url.as_deref()))?;
+
+                // Execute the statement
+                stmt.next()?;
+                stmt.reset()?;
+                drop(stmt);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn set_cached_url(&self, enclosure_id: Uuid, cached_url: &str) -> Result<()> {
+        let mut stmt = self
+            .connection
+            .prepare("UPDATE enclosure SET cached_url = ? WHERE id = ?")?;
+        stmt.bind((1, cached_url))?;
+        stmt.bind((2, enclosure_id.to_string().as_str()))?;
+        stmt.next()?;
+
+        Ok(())
+    }
+}
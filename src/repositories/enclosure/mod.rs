@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the files attached to the articles (podcast episodes, PDFs, etc)
+mod enclosure_repository_impl;
+
+use crate::models::enclosure::Enclosure;
+use axum::async_trait;
+
+pub use enclosure_repository_impl::EnclosureRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait EnclosureRepository: Sync + Send {
+    async fn get_article_enclosures(&self, article_id: Uuid) -> Result<Vec<Enclosure>>;
+
+    async fn add_enclosures(&self, enclosures: &[Enclosure]) -> Result<()>;
+
+    /// Sets the url the enclosure is served from once it is downloaded to the server
+    async fn set_cached_url(&self, enclosure_id: Uuid, cached_url: &str) -> Result<()>;
+}
@@ -1,8 +1,8 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use crate::{
-    models::article::Article,
-    repositories::{feed::Feed, RepositoryError},
+    models::{article::Article, article_filter::ArticleFilter, feed::ContentMode},
+    repositories::{feed::Feed, next_numeric_id, RepositoryError},
     transaction,
 };
 use axum::async_trait;
@@ -60,8 +60,8 @@ impl FeedRepository for FeedRepositoryImpl {
         transaction!(self, {
             let mut stmt = self.connection.prepare(
                 r#"
-                    INSERT INTO feed (id, title, url, link, favicon_path, last_updated)
-                    VALUES (?, ?, ?, ?, ?, ?);
+                    INSERT INTO feed (id, title, url, link, favicon_path, last_updated, category_id, content_mode, numeric_id)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);
                 "#,
             )?;
             stmt.bind((1, feed.id.to_string().as_str()))?;
@@ -70,6 +70,9 @@ impl FeedRepository for FeedRepositoryImpl {
             stmt.bind((4, feed.link.as_str()))?;
             stmt.bind((5, feed.favicon_url.as_deref()))?;
             stmt.bind((6, feed.last_updated.to_rfc3339().as_str()))?;
+            stmt.bind((7, feed.category_id.map(|c| c.to_string()).as_deref()))?;
+            stmt.bind((8, feed.content_mode.as_str()))?;
+            stmt.bind((9, next_numeric_id(&self.connection, "feed")?))?;
 
             // Execute the statement
             stmt.next()?;
@@ -107,6 +110,221 @@ impl FeedRepository for FeedRepositoryImpl {
             .transpose()
     }
 
+    async fn get_articles(&self, filter: &ArticleFilter) -> Result<Vec<Article>, RepositoryError> {
+        let mut conditions = vec![];
+        let mut values: Vec<sqlite::Value> = vec![];
+
+        if let Some(feed_ids) = &filter.feed_ids {
+            if feed_ids.is_empty() {
+                return Ok(vec![]);
+            }
+
+            conditions.push(format!(
+                "feed_id IN ({})",
+                vec!["?"; feed_ids.len()].join(", ")
+            ));
+            values.extend(feed_ids.iter().map(|id| id.to_string().into()));
+        }
+        if let Some(read) = filter.read {
+            conditions.push("read = ?".to_owned());
+            values.push(i64::from(read).into());
+        }
+        if let Some(starred) = filter.starred {
+            conditions.push("starred = ?".to_owned());
+            values.push(i64::from(starred).into());
+        }
+        if let Some(tag) = &filter.tag {
+            conditions.push("id IN (SELECT article_id FROM article_tag WHERE name = ?)".to_owned());
+            values.push(tag.as_str().into());
+        }
+        if let Some(after) = filter.published_after {
+            conditions.push("published_at > ?".to_owned());
+            values.push(after.to_rfc3339().into());
+        }
+        if let Some(before) = filter.published_before {
+            conditions.push("published_at < ?".to_owned());
+            values.push(before.to_rfc3339().into());
+        }
+        if let Some(after) = filter.found_after {
+            conditions.push("found_at > ?".to_owned());
+            values.push(after.to_rfc3339().into());
+        }
+
+        let conditions = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let order = if filter.oldest_first { "ASC" } else { "DESC" };
+        // A negative limit means no limit in SQLite
+        values.push(filter.limit.map_or(-1, |limit| limit as i64).into());
+        values.push((filter.offset as i64).into());
+
+        self.connection
+            .prepare(format!(
+                "SELECT * FROM article {conditions}
+                ORDER BY published_at {order}, numeric_id {order}
+                LIMIT ? OFFSET ?"
+            ))?
+            .into_iter()
+            .bind(&values[..])?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+
+    async fn get_article_by_numeric_id(
+        &self,
+        numeric_id: i64,
+    ) -> Result<Option<Article>, RepositoryError> {
+        self.connection
+            .prepare("SELECT * FROM article WHERE numeric_id = ?")?
+            .into_iter()
+            .bind((1, numeric_id))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Article::try_from)
+            })
+            .transpose()
+    }
+
+    async fn get_articles_by_numeric_ids(
+        &self,
+        numeric_ids: &[i64],
+    ) -> Result<Vec<Article>, RepositoryError> {
+        if numeric_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = vec!["?"; numeric_ids.len()].join(", ");
+        let mut cursor = self
+            .connection
+            .prepare(format!(
+                "SELECT * FROM article WHERE numeric_id IN ({placeholders}) ORDER BY numeric_id"
+            ))?
+            .into_iter();
+
+        for (index, numeric_id) in numeric_ids.iter().enumerate() {
+            cursor = cursor.bind((index + 1, *numeric_id))?;
+        }
+
+        cursor.flat_map(|r| r.map(Article::try_from)).collect()
+    }
+
+    async fn get_articles_since_numeric_id(
+        &self,
+        since_id: i64,
+        limit: usize,
+    ) -> Result<Vec<Article>, RepositoryError> {
+        self.connection
+            .prepare("SELECT * FROM article WHERE numeric_id > ? ORDER BY numeric_id LIMIT ?")?
+            .into_iter()
+            .bind((1, since_id))?
+            .bind((2, limit as i64))?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+
+    async fn get_articles_before_numeric_id(
+        &self,
+        max_id: i64,
+        limit: usize,
+    ) -> Result<Vec<Article>, RepositoryError> {
+        self.connection
+            .prepare(
+                "SELECT * FROM article WHERE numeric_id < ? ORDER BY numeric_id DESC LIMIT ?",
+            )?
+            .into_iter()
+            .bind((1, max_id))?
+            .bind((2, limit as i64))?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+
+    async fn get_article_count(&self) -> Result<usize, RepositoryError> {
+        self.connection
+            .prepare("SELECT COUNT(*) AS count FROM article")?
+            .into_iter()
+            .nth(0)
+            .ok_or(RepositoryError::Unexpected(anyhow::anyhow!(
+                "unable to count the articles"
+            )))?
+            .map(|row| row.read::<i64, _>("count") as usize)
+            .map_err(|e| RepositoryError::Unexpected(e.into()))
+    }
+
+    async fn get_unread_article_numeric_ids(&self) -> Result<Vec<i64>, RepositoryError> {
+        self.connection
+            .prepare("SELECT numeric_id FROM article WHERE read = 0 ORDER BY numeric_id")?
+            .into_iter()
+            .map(|r| {
+                r.map(|row| row.read::<i64, _>("numeric_id"))
+                    .map_err(|e| RepositoryError::Unexpected(e.into()))
+            })
+            .collect()
+    }
+
+    async fn get_starred_article_numeric_ids(&self) -> Result<Vec<i64>, RepositoryError> {
+        self.connection
+            .prepare("SELECT numeric_id FROM article WHERE starred = 1 ORDER BY numeric_id")?
+            .into_iter()
+            .map(|r| {
+                r.map(|row| row.read::<i64, _>("numeric_id"))
+                    .map_err(|e| RepositoryError::Unexpected(e.into()))
+            })
+            .collect()
+    }
+
+    async fn find_duplicate_article(
+        &self,
+        feed_id: Uuid,
+        canonical_url: &str,
+        link: &str,
+    ) -> Result<Option<Article>, RepositoryError> {
+        self.connection
+            .prepare(
+                r#"
+                    SELECT * FROM article
+                    WHERE (canonical_url = :canonical_url OR link = :link OR link = :canonical_url)
+                        AND feed_id != :feed_id AND duplicate_of IS NULL
+                    ORDER BY published_at
+                    LIMIT 1
+                "#,
+            )?
+            .into_iter()
+            .bind((":canonical_url", canonical_url))?
+            .bind((":link", link))?
+            .bind((":feed_id", feed_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Article::try_from)
+            })
+            .transpose()
+    }
+
+    async fn get_duplicate_candidates(
+        &self,
+        feed_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Article>, RepositoryError> {
+        self.connection
+            .prepare(
+                r#"
+                    SELECT * FROM article
+                    WHERE feed_id != ? AND duplicate_of IS NULL
+                        AND published_at >= ? AND published_at <= ?
+                "#,
+            )?
+            .into_iter()
+            .bind((1, feed_id.to_string().as_str()))?
+            .bind((2, from.to_rfc3339().as_str()))?
+            .bind((3, to.to_rfc3339().as_str()))?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+
     async fn add_articles(
         &self,
         feed_id: Uuid,
@@ -117,9 +335,9 @@ impl FeedRepository for FeedRepositoryImpl {
             for article in articles {
                 let mut stmt = self.connection.prepare(
                 r#"
-                    INSERT INTO article (id, feed_id, title, author, guid, link, last_updated, html_parsed, content, read)
-                    VALUES (:id, :feed_id, :title, :author, :guid, :link, :last_updated, :html_parsed, :content, 0)
-                    ON CONFLICT(guid) DO NOTHING;
+                    INSERT INTO article (id, feed_id, title, author, guid, link, summary, thumbnail_url, last_updated, html_parsed, content, read, starred, content_hash, duplicate_of, canonical_url, date_guessed, published_at, found_at, numeric_id)
+                    VALUES (:id, :feed_id, :title, :author, :guid, :link, :summary, :thumbnail_url, :last_updated, :html_parsed, :content, :read, :starred, :content_hash, :duplicate_of, :canonical_url, :date_guessed, :published_at, :found_at, :numeric_id)
+                    ON CONFLICT(feed_id, guid) DO NOTHING;
                 )"#)?;
                 stmt.bind((":id", article.id.to_string().as_str()))?;
                 stmt.bind((":feed_id", feed_id.as_str()))?;
@@ -127,9 +345,23 @@ impl FeedRepository for FeedRepositoryImpl {
                 stmt.bind((":author", article.author.as_deref()))?;
                 stmt.bind((":guid", article.guid.as_str()))?;
                 stmt.bind((":link", article.link.as_str()))?;
+                stmt.bind((":summary", article.summary.as_deref()))?;
+                stmt.bind((":thumbnail_url", article.thumbnail_url.as_deref()))?;
                 stmt.bind((":last_updated", article.last_updated.to_rfc3339().as_str()))?;
                 stmt.bind((":html_parsed", if article.html_parsed { 1 } else { 0 }))?;
                 stmt.bind((":content", article.content.as_deref()))?;
+                stmt.bind((":read", if article.read { 1 } else { 0 }))?;
+                stmt.bind((":starred", if article.starred { 1 } else { 0 }))?;
+                stmt.bind((":content_hash", article.content_hash.as_deref()))?;
+                stmt.bind((
+                    ":duplicate_of",
+                    article.duplicate_of.map(|id| id.to_string()).as_deref(),
+                ))?;
+                stmt.bind((":canonical_url", article.canonical_url.as_deref()))?;
+                stmt.bind((":date_guessed", if article.date_guessed { 1 } else { 0 }))?;
+                stmt.bind((":published_at", article.published_at.to_rfc3339().as_str()))?;
+                stmt.bind((":found_at", article.found_at.to_rfc3339().as_str()))?;
+                stmt.bind((":numeric_id", next_numeric_id(&self.connection, "article")?))?;
 
                 // Execute the statement
                 stmt.next()?;
@@ -151,6 +383,39 @@ impl FeedRepository for FeedRepositoryImpl {
         })
     }
 
+    async fn update_article(&self, article: &Article) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE article
+                    SET title = :title, author = :author, summary = :summary,
+                        last_updated = :last_updated, read = :read, content_hash = :content_hash,
+                        has_update = :has_update, updated_at = :updated_at
+                    WHERE id = :id AND feed_id = :feed_id
+                "#,
+            )?;
+            stmt.bind((":title", article.title.as_str()))?;
+            stmt.bind((":author", article.author.as_deref()))?;
+            stmt.bind((":summary", article.summary.as_deref()))?;
+            stmt.bind((":last_updated", article.last_updated.to_rfc3339().as_str()))?;
+            stmt.bind((":read", if article.read { 1 } else { 0 }))?;
+            stmt.bind((":content_hash", article.content_hash.as_deref()))?;
+            stmt.bind((":has_update", if article.has_update { 1 } else { 0 }))?;
+            stmt.bind((
+                ":updated_at",
+                article.updated_at.map(|d| d.to_rfc3339()).as_deref(),
+            ))?;
+            stmt.bind((":id", article.id.to_string().as_str()))?;
+            stmt.bind((":feed_id", article.feed_id.to_string().as_str()))?;
+
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
     async fn update_last_updated(
         &self,
         feed_id: Uuid,
@@ -176,13 +441,126 @@ impl FeedRepository for FeedRepositoryImpl {
         &self,
         feed_id: Uuid,
         article_id: Uuid,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            // The copies of the article in other feeds are marked as read too: the original one,
+            // the copies of it and the ones it is a copy of
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE article SET read = 1, has_update = 0
+                    WHERE (feed_id = :feed_id AND id = :id)
+                        OR duplicate_of = :id
+                        OR id = (SELECT duplicate_of FROM article WHERE id = :id)
+                        OR duplicate_of = (SELECT duplicate_of FROM article WHERE id = :id)
+                "#,
+            )?;
+            stmt.bind((":feed_id", feed_id.to_string().as_str()))?;
+            stmt.bind((":id", article_id.to_string().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn mark_article_as_unread(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE article SET read = 0
+                    WHERE (feed_id = :feed_id AND id = :id)
+                        OR duplicate_of = :id
+                        OR id = (SELECT duplicate_of FROM article WHERE id = :id)
+                        OR duplicate_of = (SELECT duplicate_of FROM article WHERE id = :id)
+                "#,
+            )?;
+            stmt.bind((":feed_id", feed_id.to_string().as_str()))?;
+            stmt.bind((":id", article_id.to_string().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn mark_articles_as_read(
+        &self,
+        feed_id: Option<Uuid>,
+        before: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            // Same as mark_article_as_read, the copies of the articles are marked as read too
+            let mut stmt = self.connection.prepare(
+                r#"
+                    WITH marked AS (
+                        SELECT id, duplicate_of FROM article
+                        WHERE (:feed_id IS NULL OR feed_id = :feed_id) AND found_at <= :before
+                    )
+                    UPDATE article SET read = 1, has_update = 0
+                    WHERE id IN (SELECT id FROM marked)
+                        OR duplicate_of IN (SELECT id FROM marked)
+                        OR id IN (SELECT duplicate_of FROM marked)
+                        OR duplicate_of IN (SELECT duplicate_of FROM marked)
+                "#,
+            )?;
+            stmt.bind((":feed_id", feed_id.map(|id| id.to_string()).as_deref()))?;
+            stmt.bind((":before", before.to_rfc3339().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn set_article_starred(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        starred: bool,
     ) -> Result<(), RepositoryError> {
         transaction!(self, {
             let mut stmt = self
                 .connection
-                .prepare("UPDATE article SET read = 1 WHERE feed_id = ? and id = ?")?;
-            stmt.bind((1, feed_id.to_string().as_str()))?;
-            stmt.bind((2, article_id.to_string().as_str()))?;
+                .prepare("UPDATE article SET starred = ? WHERE feed_id = ? and id = ?")?;
+            stmt.bind((1, if starred { 1 } else { 0 }))?;
+            stmt.bind((2, feed_id.to_string().as_str()))?;
+            stmt.bind((3, article_id.to_string().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn set_article_html_parsed(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        html_parsed: bool,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("UPDATE article SET html_parsed = ? WHERE feed_id = ? and id = ?")?;
+            stmt.bind((1, if html_parsed { 1 } else { 0 }))?;
+            stmt.bind((2, feed_id.to_string().as_str()))?;
+            stmt.bind((3, article_id.to_string().as_str()))?;
 
             // execute the statement
             stmt.next()?;
@@ -195,6 +573,18 @@ impl FeedRepository for FeedRepositoryImpl {
 
     async fn delete_feed(&self, feed_id: Uuid) -> Result<(), RepositoryError> {
         transaction!(self, {
+            // The copies of the feed articles in other feeds are no longer duplicates
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE article SET duplicate_of = NULL
+                    WHERE duplicate_of IN (SELECT id FROM article WHERE feed_id = ?)
+                "#,
+            )?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
             // First delete all articles related to this feed
             let mut stmt = self
                 .connection
@@ -204,6 +594,89 @@ impl FeedRepository for FeedRepositoryImpl {
             stmt.reset()?;
             drop(stmt);
 
+            // Delete the filter rules that only apply to this feed
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM filter_rule WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the rewrite rules of the feed
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM rewrite_rule WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the enclosures of the feed articles
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM enclosure WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the tags of the feed articles
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM article_tag WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the previous versions of the feed articles
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM article_revision WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the record of the feed articles saved to read-it-later services
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM saved_article WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the webhooks that only apply to this feed, with their deliveries
+            let mut stmt = self.connection.prepare(
+                r#"
+                    DELETE FROM webhook_delivery
+                    WHERE webhook_id IN (SELECT id FROM webhook WHERE feed_id = ?)
+                "#,
+            )?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM webhook WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Delete the WebSub subscription of the feed
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM websub_subscription WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
             // Then delete the feed itself
             let mut stmt = self.connection.prepare("DELETE FROM feed WHERE id = ?")?;
             stmt.bind((1, feed_id.to_string().as_str()))?;
@@ -235,4 +708,198 @@ impl FeedRepository for FeedRepositoryImpl {
             Ok(())
         })
     }
+
+    async fn update_feed_category(
+        &self,
+        feed_id: Uuid,
+        category_id: Option<Uuid>,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("UPDATE feed SET category_id = ? WHERE id = ?")?;
+            stmt.bind((1, category_id.map(|c| c.to_string()).as_deref()))?;
+            stmt.bind((2, feed_id.to_string().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn update_feed_content_mode(
+        &self,
+        feed_id: Uuid,
+        content_mode: ContentMode,
+    ) -> Result<(), RepositoryError> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("UPDATE feed SET content_mode = ? WHERE id = ?")?;
+            stmt.bind((1, content_mode.as_str()))?;
+            stmt.bind((2, feed_id.to_string().as_str()))?;
+
+            // execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::test_utils::{
+        migrate_test_database, test_article, test_database, test_feed,
+    };
+
+    #[tokio::test]
+    async fn articles_survive_the_guid_per_feed_migration() {
+        let connection = test_database(Some("20261027_article_revision.sql"));
+        connection
+            .execute(
+                r#"
+                    INSERT INTO feed (id, title, url, link, last_updated)
+                    VALUES ('feed', 'Feed', 'http://example.com/feed.xml', 'http://example.com',
+                        '2024-01-01T00:00:00+00:00');
+                    INSERT INTO article (id, title, guid, link, content, read, html_parsed,
+                        last_updated, feed_id, starred, summary, content_hash, has_update)
+                    VALUES ('00000000-0000-0000-0000-000000000001', 'First', 'first',
+                        'http://example.com/first', '/articles/feed/first.html', 1, 0,
+                        '2024-01-01T00:00:00+00:00', 'feed', 1, 'The summary', 'hash', 1);
+                "#,
+            )
+            .unwrap();
+        migrate_test_database(&connection);
+
+        let article: Vec<(String, String, i64, i64, String)> = connection
+            .prepare("SELECT id, guid, read, starred, summary FROM article WHERE feed_id = 'feed'")
+            .unwrap()
+            .into_iter()
+            .map(|row| {
+                let row = row.unwrap();
+                (
+                    row.read::<&str, _>("id").to_owned(),
+                    row.read::<&str, _>("guid").to_owned(),
+                    row.read::<i64, _>("read"),
+                    row.read::<i64, _>("starred"),
+                    row.read::<&str, _>("summary").to_owned(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![(
+                "00000000-0000-0000-0000-000000000001".to_owned(),
+                "first".to_owned(),
+                1,
+                1,
+                "The summary".to_owned()
+            )],
+            article
+        );
+    }
+
+    #[tokio::test]
+    async fn guids_are_unique_per_feed() {
+        let repository = FeedRepositoryImpl::new(Arc::new(test_database(None)));
+        let first_feed = test_feed("first", None);
+        let second_feed = test_feed("second", None);
+        repository.add_feed(first_feed.clone()).await.unwrap();
+        repository.add_feed(second_feed.clone()).await.unwrap();
+
+        // Both feeds can have an article with the same guid
+        let first = test_article(first_feed.id, "1");
+        let second = test_article(second_feed.id, "1");
+        repository.add_articles(first_feed.id, &[&first]).await.unwrap();
+        repository.add_articles(second_feed.id, &[&second]).await.unwrap();
+
+        // Adding it again to the same feed keeps the saved one
+        let mut again = test_article(first_feed.id, "1");
+        again.title = "Again".to_owned();
+        repository.add_articles(first_feed.id, &[&again]).await.unwrap();
+
+        let first_articles = repository.get_feed_articles(first_feed.id).await.unwrap();
+        assert_eq!(1, first_articles.len());
+        assert_eq!(first.id, first_articles[0].id);
+        assert_eq!("1", first_articles[0].title);
+        let second_articles = repository.get_feed_articles(second_feed.id).await.unwrap();
+        assert_eq!(1, second_articles.len());
+        assert_eq!(second.id, second_articles[0].id);
+    }
+
+    #[tokio::test]
+    async fn duplicates_are_linked_to_the_original_article() {
+        let repository = FeedRepositoryImpl::new(Arc::new(test_database(None)));
+        let first_feed = test_feed("first", None);
+        let second_feed = test_feed("second", None);
+        let third_feed = test_feed("third", None);
+        for feed in [&first_feed, &second_feed, &third_feed] {
+            repository.add_feed(feed.clone()).await.unwrap();
+        }
+
+        let original = test_article(first_feed.id, "original");
+        repository.add_articles(first_feed.id, &[&original]).await.unwrap();
+        let link = original.link.as_str();
+        let other = "http://other.com";
+
+        // The original article is found from other feeds, by its link or canonical url
+        let found = repository.find_duplicate_article(second_feed.id, link, other).await;
+        assert_eq!(Some(original.id), found.unwrap().map(|a| a.id));
+        let found = repository.find_duplicate_article(second_feed.id, other, link).await;
+        assert_eq!(Some(original.id), found.unwrap().map(|a| a.id));
+        let found = repository.find_duplicate_article(first_feed.id, link, link).await;
+        assert!(found.unwrap().is_none());
+
+        // Copies are linked to the original, never to another copy
+        let mut copy = test_article(second_feed.id, "copy");
+        copy.link = original.link.clone();
+        copy.duplicate_of = Some(original.id);
+        repository.add_articles(second_feed.id, &[&copy]).await.unwrap();
+
+        let saved_copy = repository.get_feed_articles(second_feed.id).await.unwrap();
+        assert_eq!(Some(original.id), saved_copy[0].duplicate_of);
+        let found = repository.find_duplicate_article(third_feed.id, link, link).await;
+        assert_eq!(Some(original.id), found.unwrap().map(|a| a.id));
+        let found = repository.find_duplicate_article(first_feed.id, link, link).await;
+        assert!(found.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn duplicate_candidates_are_published_between_the_dates() {
+        let repository = FeedRepositoryImpl::new(Arc::new(test_database(None)));
+        let first_feed = test_feed("first", None);
+        let second_feed = test_feed("second", None);
+        repository.add_feed(first_feed.clone()).await.unwrap();
+        repository.add_feed(second_feed.clone()).await.unwrap();
+
+        let now = Utc::now();
+        let mut articles = vec![];
+        for (guid, days_ago) in [("old", 10), ("inside", 3), ("new", 0)] {
+            let mut article = test_article(first_feed.id, guid);
+            article.published_at = now - chrono::TimeDelta::days(days_ago);
+            articles.push(article);
+        }
+        let own = test_article(second_feed.id, "own");
+        repository.add_articles(first_feed.id, &articles.iter().collect::<Vec<_>>()).await.unwrap();
+        repository.add_articles(second_feed.id, &[&own]).await.unwrap();
+
+        let candidates = repository
+            .get_duplicate_candidates(
+                second_feed.id,
+                now - chrono::TimeDelta::days(5),
+                now - chrono::TimeDelta::days(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            vec!["inside"],
+            candidates.iter().map(|a| a.guid.as_str()).collect::<Vec<_>>()
+        );
+    }
 }
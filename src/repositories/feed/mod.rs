@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: AGPL-3.0-only
 mod feed_repository_impl;
 
-use crate::models::{article::Article, feed::Feed};
+use crate::models::{
+    article::Article,
+    article_filter::ArticleFilter,
+    feed::{ContentMode, Feed},
+};
 use axum::async_trait;
 
 use chrono::{DateTime, Utc};
@@ -31,11 +35,77 @@ pub trait FeedRepository: Sync + Send {
         article_id: Uuid,
     ) -> Result<Option<Article>>;
 
+    async fn get_articles(&self, filter: &ArticleFilter) -> Result<Vec<Article>>;
+
+    async fn get_article_by_numeric_id(&self, numeric_id: i64) -> Result<Option<Article>>;
+
+    async fn get_articles_by_numeric_ids(&self, numeric_ids: &[i64]) -> Result<Vec<Article>>;
+
+    /// Returns the articles with a numeric id greater than `since_id`, the oldest first
+    async fn get_articles_since_numeric_id(&self, since_id: i64, limit: usize)
+        -> Result<Vec<Article>>;
+
+    /// Returns the articles with a numeric id lower than `max_id`, the most recent first
+    async fn get_articles_before_numeric_id(&self, max_id: i64, limit: usize)
+        -> Result<Vec<Article>>;
+
+    async fn get_article_count(&self) -> Result<usize>;
+
+    async fn get_unread_article_numeric_ids(&self) -> Result<Vec<i64>>;
+
+    async fn get_starred_article_numeric_ids(&self) -> Result<Vec<i64>>;
+
+    /// Returns the original article with the given canonical url or link in a feed other than
+    /// `feed_id`, if any
+    async fn find_duplicate_article(
+        &self,
+        feed_id: Uuid,
+        canonical_url: &str,
+        link: &str,
+    ) -> Result<Option<Article>>;
+
+    /// Returns the original articles of the feeds other than `feed_id` published between the
+    /// given dates, to compare their titles with the new ones
+    async fn get_duplicate_candidates(
+        &self,
+        feed_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Article>>;
+
+    /// Updates the data of an article that changes when the publisher updates it
+    async fn update_article(&self, article: &Article) -> Result<()>;
+
     async fn update_last_updated(&self, feed_id: Uuid, date: DateTime<Utc>) -> Result<()>;
 
+    /// Marks the article (and its copies in other feeds) as read, clearing its update badge too
     async fn mark_article_as_read(&self, feed_id: Uuid, article_id: Uuid) -> Result<()>;
 
+    /// Marks the article (and its copies in other feeds) as unread
+    async fn mark_article_as_unread(&self, feed_id: Uuid, article_id: Uuid) -> Result<()>;
+
+    /// Marks the articles of a feed (or all of them if `feed_id` is None) found before the given
+    /// date as read, with their copies in other feeds
+    async fn mark_articles_as_read(&self, feed_id: Option<Uuid>, before: DateTime<Utc>)
+        -> Result<()>;
+
+    async fn set_article_starred(&self, feed_id: Uuid, article_id: Uuid, starred: bool)
+        -> Result<()>;
+
+    /// Sets if the content of the article was extracted from its web page instead of the feed
+    async fn set_article_html_parsed(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        html_parsed: bool,
+    ) -> Result<()>;
+
     async fn delete_feed(&self, feed_id: Uuid) -> Result<()>;
 
     async fn update_favicon_url(&self, feed_id: Uuid, favicon_url: &str) -> Result<()>;
+
+    async fn update_feed_category(&self, feed_id: Uuid, category_id: Option<Uuid>) -> Result<()>;
+
+    async fn update_feed_content_mode(&self, feed_id: Uuid, content_mode: ContentMode)
+        -> Result<()>;
 }
@@ -1,8 +1,14 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 //! This implementation of FeedContentRepository saves the content as html files in the filesystem
-use crate::{config::Config, models::article::Article, repositories::RepositoryError};
+use crate::{
+    config::Config,
+    models::{article::Article, article_revision::ArticleRevision},
+    repositories::RepositoryError,
+    transaction,
+};
 use axum::async_trait;
+use chrono::Utc;
 use sqlite::ConnectionThreadSafe;
 use std::{path::Path, sync::Arc};
 use tokio::{fs, io::AsyncWriteExt};
@@ -106,4 +112,115 @@ impl FeedContentRepository for FeedContentFsRepositoryImpl {
 
         Ok(())
     }
+
+    async fn delete_article_images(&self, feed_id: Uuid, image_names: &[String]) -> Result<()> {
+        for image_name in image_names {
+            let image_path = format!(
+                "{}/articles/{}/static/{}",
+                self.config.data_path, feed_id, image_name
+            );
+
+            match fs::remove_file(&image_path).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(RepositoryError::Unexpected(e.into())),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_article_revision(&self, article: &Article) -> Result<()> {
+        let Some(content_path) = &article.content else {
+            return Ok(());
+        };
+
+        let revision = ArticleRevision {
+            id: Uuid::new_v4(),
+            article_id: article.id,
+            feed_id: article.feed_id,
+            title: article.title.clone(),
+            content: format!(
+                "{}/articles/{}/revisions/{}/{}.html",
+                self.config.data_path,
+                article.feed_id,
+                article.id,
+                Uuid::new_v4()
+            ),
+            created_at: Utc::now(),
+        };
+
+        if let Some(revisions_directory) = Path::new(&revision.content).parent() {
+            fs::create_dir_all(revisions_directory)
+                .await
+                .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+        }
+
+        fs::copy(content_path, &revision.content)
+            .await
+            .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO article_revision (id, article_id, feed_id, title, content, created_at)
+                    VALUES (?, ?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, revision.id.to_string().as_str()))?;
+            stmt.bind((2, revision.article_id.to_string().as_str()))?;
+            stmt.bind((3, revision.feed_id.to_string().as_str()))?;
+            stmt.bind((4, revision.title.as_str()))?;
+            stmt.bind((5, revision.content.as_str()))?;
+            stmt.bind((6, revision.created_at.to_rfc3339().as_str()))?;
+
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_article_revisions(&self, article_id: Uuid) -> Result<Vec<ArticleRevision>> {
+        self.connection
+            .prepare(
+                "SELECT * FROM article_revision WHERE article_id = ? ORDER BY created_at DESC",
+            )?
+            .into_iter()
+            .bind((1, article_id.to_string().as_str()))?
+            .flat_map(|r| r.map(ArticleRevision::try_from))
+            .collect()
+    }
+
+    async fn get_article_revision(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<Option<(ArticleRevision, String)>> {
+        let revision = self
+            .connection
+            .prepare("SELECT * FROM article_revision WHERE id = ? AND article_id = ? AND feed_id = ?")?
+            .into_iter()
+            .bind((1, revision_id.to_string().as_str()))?
+            .bind((2, article_id.to_string().as_str()))?
+            .bind((3, feed_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(ArticleRevision::try_from)
+            })
+            .transpose()?;
+
+        if let Some(revision) = revision {
+            let content = fs::read_to_string(&revision.content)
+                .await
+                .map_err(|e| RepositoryError::Unexpected(e.into()))?;
+
+            Ok(Some((revision, content)))
+        } else {
+            Ok(None)
+        }
+    }
 }
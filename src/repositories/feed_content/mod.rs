@@ -8,7 +8,7 @@ use axum::async_trait;
 pub use feed_content_fs_impl::FeedContentFsRepositoryImpl;
 use uuid::Uuid;
 
-use crate::models::article::Article;
+use crate::models::{article::Article, article_revision::ArticleRevision};
 
 use super::RepositoryError;
 
@@ -21,4 +21,21 @@ pub trait FeedContentRepository: Sync + Send {
     async fn save_article_content(&self, articles: &[(&Article, &String)]) -> Result<()>;
 
     async fn delete_feed_content(&self, feed_id: Uuid) -> Result<()>;
+
+    /// Deletes cached images of a feed by their file name. Images that do not exist are ignored.
+    async fn delete_article_images(&self, feed_id: Uuid, image_names: &[String]) -> Result<()>;
+
+    /// Keeps a copy of the current content of the article as a revision. Does nothing if the
+    /// article has no content
+    async fn save_article_revision(&self, article: &Article) -> Result<()>;
+
+    /// Returns the revisions of an article, the most recent first
+    async fn get_article_revisions(&self, article_id: Uuid) -> Result<Vec<ArticleRevision>>;
+
+    async fn get_article_revision(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<Option<(ArticleRevision, String)>>;
 }
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{models::filter_rule::FilterRule, repositories::RepositoryError, transaction};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{FilterRuleRepository, Result};
+
+#[derive(Clone)]
+pub struct FilterRuleRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl FilterRuleRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl FilterRuleRepository for FilterRuleRepositoryImpl {
+    async fn get_filter_rule_list(&self) -> Result<Vec<FilterRule>> {
+        self.connection
+            .prepare("SELECT * FROM filter_rule ORDER BY feed_id, pattern")?
+            .into_iter()
+            .flat_map(|r| r.map(FilterRule::try_from))
+            .collect()
+    }
+
+    async fn get_feed_filter_rules(&self, feed_id: Uuid) -> Result<Vec<FilterRule>> {
+        self.connection
+            .prepare("SELECT * FROM filter_rule WHERE feed_id IS NULL OR feed_id = ?")?
+            .into_iter()
+            .bind((1, feed_id.to_string().as_str()))?
+            .flat_map(|r| r.map(FilterRule::try_from))
+            .collect()
+    }
+
+    async fn get_filter_rule(&self, rule_id: Uuid) -> Result<Option<FilterRule>> {
+        self.connection
+            .prepare("SELECT * FROM filter_rule WHERE id = ?")?
+            .into_iter()
+            .bind((1, rule_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(FilterRule::try_from)
+            })
+            .transpose()
+    }
+
+    async fn add_filter_rule(&self, rule: &FilterRule) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO filter_rule (id, feed_id, field, match_type, pattern, action)
+                    VALUES (?, ?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, rule.id.to_string().as_str()))?;
+            stmt.bind((2, rule.feed_id.map(|f| f.to_string()).as_deref()))?;
+            stmt.bind((3, rule.field.as_str()))?;
+            stmt.bind((4, rule.match_type.as_str()))?;
+            stmt.bind((5, rule.pattern.as_str()))?;
+            stmt.bind((6, rule.action.as_str()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_filter_rule(&self, rule_id: Uuid) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM filter_rule WHERE id = ?")?;
+            stmt.bind((1, rule_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
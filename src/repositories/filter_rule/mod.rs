@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the rules used to filter the articles when they are ingested
+mod filter_rule_repository_impl;
+
+use crate::models::filter_rule::FilterRule;
+use axum::async_trait;
+
+pub use filter_rule_repository_impl::FilterRuleRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait FilterRuleRepository: Sync + Send {
+    /// Returns all the rules, global and per feed
+    async fn get_filter_rule_list(&self) -> Result<Vec<FilterRule>>;
+
+    /// Returns the rules that apply to a feed: the global ones and the ones of the feed
+    async fn get_feed_filter_rules(&self, feed_id: Uuid) -> Result<Vec<FilterRule>>;
+
+    async fn get_filter_rule(&self, rule_id: Uuid) -> Result<Option<FilterRule>>;
+
+    async fn add_filter_rule(&self, rule: &FilterRule) -> Result<()>;
+
+    async fn delete_filter_rule(&self, rule_id: Uuid) -> Result<()>;
+}
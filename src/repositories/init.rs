@@ -28,14 +28,23 @@ pub fn init_database(config: &Config) -> ConnectionThreadSafe {
         sqlite::Connection::open_thread_safe(format!("{}/database.db", config.data_path))
             .expect("there was an error opening a connection to the database");
 
+    run_migrations(&connection, &config.static_data_path, None);
+
+    connection
+}
+
+/// Runs the migrations that were not applied yet, in order. If `until` is given, the ones after
+/// that file are left pending
+pub(super) fn run_migrations(
+    connection: &ConnectionThreadSafe,
+    static_data_path: &str,
+    until: Option<&str>,
+) {
     // First we create (if needed) the migrations table and query it to filter already applied
     // migrations
     execute_migration(
-        &connection,
-        format!(
-            "{}/migrations/{MIGRATIONS_TABLE_FILE}",
-            config.static_data_path
-        ),
+        connection,
+        format!("{static_data_path}/migrations/{MIGRATIONS_TABLE_FILE}"),
     );
 
     let migrations_done: Vec<OsString> = connection
@@ -48,17 +57,23 @@ pub fn init_database(config: &Config) -> ConnectionThreadSafe {
         })
         .collect();
 
-    let migration_files: Vec<DirEntry> =
-        read_dir(format!("{}/migrations", config.static_data_path))
+    let mut migration_files: Vec<DirEntry> =
+        read_dir(format!("{static_data_path}/migrations"))
             .expect("unable to read migrations directory")
             .collect::<Result<Vec<DirEntry>, _>>()
             .expect("unable to read migrations directory")
             .into_iter()
             .filter(|m| {
-                !migrations_done.contains(&m.file_name()) && m.file_name() != MIGRATIONS_TABLE_FILE
+                !migrations_done.contains(&m.file_name())
+                    && m.file_name() != MIGRATIONS_TABLE_FILE
+                    && until.is_none_or(|until| m.file_name().as_os_str() <= until)
             })
             .collect();
 
+    // read_dir does not guarantee any order. Migrations are prefixed with their date, so sorting
+    // them by name ensures that a migration altering a table runs after the one creating it
+    migration_files.sort_by_key(|m| m.file_name());
+
     for migration in migration_files {
         let migration_filename = migration.file_name();
         let migration_filename = migration_filename
@@ -66,7 +81,7 @@ pub fn init_database(config: &Config) -> ConnectionThreadSafe {
             .expect("unable to parse migration file name");
 
         let migration = migration.path();
-        execute_migration(&connection, migration);
+        execute_migration(connection, migration);
 
         let mut stmt = connection
             .prepare("INSERT INTO migrations (name) VALUES (?)")
@@ -76,6 +91,4 @@ pub fn init_database(config: &Config) -> ConnectionThreadSafe {
 
         stmt.next().expect("unable to process migration");
     }
-
-    connection
 }
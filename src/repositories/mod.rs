@@ -1,14 +1,52 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+pub mod category;
+pub mod digest;
+pub mod email_delivery;
+pub mod enclosure;
 pub mod error;
 pub mod feed;
 pub mod feed_content;
+pub mod filter_rule;
 mod init;
 pub mod persisted_config;
+pub mod rewrite_rule;
+pub mod saved_article;
+pub mod tag;
+#[cfg(test)]
+pub(crate) mod test_utils;
+pub mod webhook;
+pub mod websub_subscription;
 
 pub use error::RepositoryError;
 pub use init::init_database;
 
+use sqlite::ConnectionThreadSafe;
+
+/// Reserves the next numeric id of a table (see the `numeric_id_sequence` table). It must be
+/// called inside the transaction that inserts the row, ids are never reused
+pub(crate) fn next_numeric_id(
+    connection: &ConnectionThreadSafe,
+    table: &str,
+) -> Result<i64, RepositoryError> {
+    let mut stmt = connection
+        .prepare("UPDATE numeric_id_sequence SET last_id = last_id + 1 WHERE name = ?")?;
+    stmt.bind((1, table))?;
+    stmt.next()?;
+    drop(stmt);
+
+    connection
+        .prepare("SELECT last_id FROM numeric_id_sequence WHERE name = ?")?
+        .into_iter()
+        .bind((1, table))?
+        .next()
+        .ok_or(RepositoryError::Unexpected(anyhow::anyhow!(
+            "the numeric id sequence of {table} was not found"
+        )))?
+        .map(|row| row.read::<i64, _>("last_id"))
+        .map_err(|e| RepositoryError::Unexpected(e.into()))
+}
+
 #[macro_export]
 macro_rules! transaction {
     ($self: ident, $transaction: expr) => {{
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the rules used to rewrite the HTML of the articles of a feed
+mod rewrite_rule_repository_impl;
+
+use crate::models::rewrite_rule::RewriteRule;
+use axum::async_trait;
+
+pub use rewrite_rule_repository_impl::RewriteRuleRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait RewriteRuleRepository: Sync + Send {
+    /// Returns the rules of a feed in the order they must be applied
+    async fn get_feed_rewrite_rules(&self, feed_id: Uuid) -> Result<Vec<RewriteRule>>;
+
+    async fn get_rewrite_rule(&self, rule_id: Uuid) -> Result<Option<RewriteRule>>;
+
+    async fn add_rewrite_rule(&self, rule: &RewriteRule) -> Result<()>;
+
+    async fn delete_rewrite_rule(&self, rule_id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{models::rewrite_rule::RewriteRule, repositories::RepositoryError, transaction};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{Result, RewriteRuleRepository};
+
+#[derive(Clone)]
+pub struct RewriteRuleRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl RewriteRuleRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl RewriteRuleRepository for RewriteRuleRepositoryImpl {
+    async fn get_feed_rewrite_rules(&self, feed_id: Uuid) -> Result<Vec<RewriteRule>> {
+        self.connection
+            .prepare("SELECT * FROM rewrite_rule WHERE feed_id = ? ORDER BY rowid")?
+            .into_iter()
+            .bind((1, feed_id.to_string().as_str()))?
+            .flat_map(|r| r.map(RewriteRule::try_from))
+            .collect()
+    }
+
+    async fn get_rewrite_rule(&self, rule_id: Uuid) -> Result<Option<RewriteRule>> {
+        self.connection
+            .prepare("SELECT * FROM rewrite_rule WHERE id = ?")?
+            .into_iter()
+            .bind((1, rule_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(RewriteRule::try_from)
+            })
+            .transpose()
+    }
+
+    async fn add_rewrite_rule(&self, rule: &RewriteRule) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO rewrite_rule (id, feed_id, kind, pattern, replacement)
+                    VALUES (?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, rule.id.to_string().as_str()))?;
+            stmt.bind((2, rule.feed_id.to_string().as_str()))?;
+            stmt.bind((3, rule.kind.as_str()))?;
+            stmt.bind((4, rule.pattern.as_str()))?;
+            stmt.bind((5, rule.replacement.as_deref()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_rewrite_rule(&self, rule_id: Uuid) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM rewrite_rule WHERE id = ?")?;
+            stmt.bind((1, rule_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository keeps the record of the articles saved to read-it-later services
+mod saved_article_repository_impl;
+
+use crate::models::saved_article::SavedArticle;
+use axum::async_trait;
+
+pub use saved_article_repository_impl::SavedArticleRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait SavedArticleRepository: Sync + Send {
+    /// Returns the services the article was saved to, the most recent first
+    async fn get_saved_article_list(&self, article_id: Uuid) -> Result<Vec<SavedArticle>>;
+
+    async fn add_saved_article(&self, saved_article: &SavedArticle) -> Result<()>;
+}
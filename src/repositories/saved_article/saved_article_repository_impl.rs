@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{models::saved_article::SavedArticle, transaction};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{Result, SavedArticleRepository};
+
+pub struct SavedArticleRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl SavedArticleRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl SavedArticleRepository for SavedArticleRepositoryImpl {
+    async fn get_saved_article_list(&self, article_id: Uuid) -> Result<Vec<SavedArticle>> {
+        self.connection
+            .prepare("SELECT * FROM saved_article WHERE article_id = ? ORDER BY saved_at DESC")?
+            .into_iter()
+            .bind((1, article_id.to_string().as_str()))?
+            .flat_map(|r| r.map(SavedArticle::try_from))
+            .collect()
+    }
+
+    async fn add_saved_article(&self, saved_article: &SavedArticle) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO saved_article (id, article_id, feed_id, target, saved_at, url)
+                    VALUES (?, ?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, saved_article.id.to_string().as_str()))?;
+            stmt.bind((2, saved_article.article_id.to_string().as_str()))?;
+            stmt.bind((3, saved_article.feed_id.to_string().as_str()))?;
+            stmt.bind((4, saved_article.target.as_str()))?;
+            stmt.bind((5, saved_article.saved_at.to_rfc3339().as_str()))?;
+            stmt.bind((6, saved_article.url.as_deref()))?;
+
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
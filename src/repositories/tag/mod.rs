@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the tags of the articles
+mod tag_repository_impl;
+
+use crate::models::{article::Article, tag::ArticleTag};
+use axum::async_trait;
+
+pub use tag_repository_impl::TagRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait TagRepository: Sync + Send {
+    async fn get_article_tags(&self, article_id: Uuid) -> Result<Vec<ArticleTag>>;
+
+    /// Adds the tags to their articles, ignoring the ones the article already has
+    async fn add_article_tags(&self, tags: &[ArticleTag]) -> Result<()>;
+
+    async fn delete_article_tag(&self, article_id: Uuid, name: &str) -> Result<()>;
+
+    /// Returns the articles of all the feeds that have the tag
+    async fn get_tag_articles(&self, name: &str) -> Result<Vec<Article>>;
+}
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{
+    models::{article::Article, tag::ArticleTag},
+    transaction,
+};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{Result, TagRepository};
+
+#[derive(Clone)]
+pub struct TagRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn get_article_tags(&self, article_id: Uuid) -> Result<Vec<ArticleTag>> {
+        self.connection
+            .prepare("SELECT * FROM article_tag WHERE article_id = ? ORDER BY name")?
+            .into_iter()
+            .bind((1, article_id.to_string().as_str()))?
+            .flat_map(|r| r.map(ArticleTag::try_from))
+            .collect()
+    }
+
+    async fn add_article_tags(&self, tags: &[ArticleTag]) -> Result<()> {
+        transaction!(self, {
+            for tag in tags {
+                let mut stmt = self.connection.prepare(
+                    r#"
+                        INSERT INTO article_tag (article_id, feed_id, name, user_defined)
+                        VALUES (?, ?, ?, ?)
+                        ON CONFLICT(article_id, name) DO NOTHING;
+                    "#,
+                )?;
+                stmt.bind((1, tag.article_id.to_string().as_str()))?;
+                stmt.bind((2, tag.feed_id.to_string().as_str()))?;
+                stmt.bind((3, tag.name.as_str()))?;
+                stmt.bind((4, if tag.user_defined { 1 } else { 0 }))?;
+
+                // Execute the statement
+                stmt.next()?;
+                stmt.reset()?;
+                drop(stmt);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_article_tag(&self, article_id: Uuid, name: &str) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM article_tag WHERE article_id = ? AND name = ?")?;
+            stmt.bind((1, article_id.to_string().as_str()))?;
+            stmt.bind((2, name))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_tag_articles(&self, name: &str) -> Result<Vec<Article>> {
+        self.connection
+            .prepare(
+                "SELECT a.* FROM article a JOIN article_tag t ON t.article_id = a.id
+                WHERE t.name = ?",
+            )?
+            .into_iter()
+            .bind((1, name))?
+            .flat_map(|r| r.map(Article::try_from))
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Databases and fixtures for the repository tests
+use chrono::{DateTime, Utc};
+use sqlite::ConnectionThreadSafe;
+use uuid::Uuid;
+
+use super::init::run_migrations;
+use crate::models::{
+    article::Article,
+    feed::{ContentMode, Feed},
+};
+
+/// Opens an in-memory database with the migrations applied until the given file (all of them if
+/// it is None)
+pub fn test_database(until: Option<&str>) -> ConnectionThreadSafe {
+    let connection = sqlite::Connection::open_thread_safe(":memory:")
+        .expect("there was an error opening the test database");
+    run_migrations(&connection, env!("CARGO_MANIFEST_DIR"), until);
+
+    connection
+}
+
+/// Applies the remaining migrations to a database opened with [`test_database`]
+pub fn migrate_test_database(connection: &ConnectionThreadSafe) {
+    run_migrations(connection, env!("CARGO_MANIFEST_DIR"), None);
+}
+
+pub fn test_feed(title: &str, category_id: Option<Uuid>) -> Feed {
+    Feed {
+        id: Uuid::new_v4(),
+        numeric_id: 0,
+        title: title.to_owned(),
+        url: format!("http://example.com/{title}.xml"),
+        link: "http://example.com".to_owned(),
+        favicon_url: None,
+        last_updated: DateTime::default(),
+        unread_count: 0,
+        category_id,
+        content_mode: ContentMode::default(),
+    }
+}
+
+/// Unread article published now, with the guid as link and title
+pub fn test_article(feed_id: Uuid, guid: &str) -> Article {
+    let now = Utc::now();
+
+    Article {
+        id: Uuid::new_v4(),
+        numeric_id: 0,
+        feed_id,
+        title: guid.to_owned(),
+        author: None,
+        guid: guid.to_owned(),
+        link: format!("http://example.com/{guid}"),
+        summary: None,
+        thumbnail_url: None,
+        content: None,
+        read: false,
+        starred: false,
+        html_parsed: false,
+        last_updated: now,
+        published_at: now,
+        found_at: now,
+        date_guessed: false,
+        content_hash: None,
+        has_update: false,
+        updated_at: None,
+        duplicate_of: None,
+        canonical_url: None,
+    }
+}
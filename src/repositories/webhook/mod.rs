@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the outgoing webhooks and the queue of their calls
+mod webhook_repository_impl;
+
+use crate::models::webhook::{Webhook, WebhookDelivery};
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+
+pub use webhook_repository_impl::WebhookRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait WebhookRepository: Sync + Send {
+    async fn get_webhook_list(&self) -> Result<Vec<Webhook>>;
+
+    async fn get_webhook(&self, webhook_id: Uuid) -> Result<Option<Webhook>>;
+
+    async fn add_webhook(&self, webhook: &Webhook) -> Result<()>;
+
+    /// Deletes the webhook and its deliveries
+    async fn delete_webhook(&self, webhook_id: Uuid) -> Result<()>;
+
+    async fn add_deliveries(&self, deliveries: &[WebhookDelivery]) -> Result<()>;
+
+    /// Returns the pending deliveries whose next attempt is due, the oldest first
+    async fn get_due_deliveries(&self, now: DateTime<Utc>) -> Result<Vec<WebhookDelivery>>;
+
+    /// Saves the status and the result of the last attempt of a delivery
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> Result<()>;
+
+    /// Returns the most recent deliveries, the newest first
+    async fn get_delivery_list(&self, limit: u32) -> Result<Vec<WebhookDelivery>>;
+
+    /// Deletes the finished (delivered or failed) deliveries created before the date
+    async fn delete_deliveries_before(&self, date: DateTime<Utc>) -> Result<()>;
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{
+    models::webhook::{DeliveryStatus, Webhook, WebhookDelivery},
+    repositories::RepositoryError,
+    transaction,
+};
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{Result, WebhookRepository};
+
+#[derive(Clone)]
+pub struct WebhookRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl WebhookRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    async fn get_webhook_list(&self) -> Result<Vec<Webhook>> {
+        self.connection
+            .prepare("SELECT * FROM webhook ORDER BY url")?
+            .into_iter()
+            .flat_map(|r| r.map(Webhook::try_from))
+            .collect()
+    }
+
+    async fn get_webhook(&self, webhook_id: Uuid) -> Result<Option<Webhook>> {
+        self.connection
+            .prepare("SELECT * FROM webhook WHERE id = ?")?
+            .into_iter()
+            .bind((1, webhook_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(Webhook::try_from)
+            })
+            .transpose()
+    }
+
+    async fn add_webhook(&self, webhook: &Webhook) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO webhook (id, url, feed_id, category_id, secret)
+                    VALUES (?, ?, ?, ?, ?);
+                "#,
+            )?;
+            stmt.bind((1, webhook.id.to_string().as_str()))?;
+            stmt.bind((2, webhook.url.as_str()))?;
+            stmt.bind((3, webhook.feed_id.map(|f| f.to_string()).as_deref()))?;
+            stmt.bind((4, webhook.category_id.map(|c| c.to_string()).as_deref()))?;
+            stmt.bind((5, webhook.secret.as_deref()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_webhook(&self, webhook_id: Uuid) -> Result<()> {
+        transaction!(self, {
+            // First delete the deliveries of the webhook
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM webhook_delivery WHERE webhook_id = ?")?;
+            stmt.bind((1, webhook_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            // Then delete the webhook itself
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM webhook WHERE id = ?")?;
+            stmt.bind((1, webhook_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn add_deliveries(&self, deliveries: &[WebhookDelivery]) -> Result<()> {
+        transaction!(self, {
+            for delivery in deliveries {
+                let mut stmt = self.connection.prepare(
+                    r#"
+                        INSERT INTO webhook_delivery
+                            (id, webhook_id, payload, status, attempts, created_at, next_attempt_at)
+                        VALUES (?, ?, ?, ?, ?, ?, ?);
+                    "#,
+                )?;
+                stmt.bind((1, delivery.id.to_string().as_str()))?;
+                stmt.bind((2, delivery.webhook_id.to_string().as_str()))?;
+                stmt.bind((3, delivery.payload.as_str()))?;
+                stmt.bind((4, delivery.status.as_str()))?;
+                stmt.bind((5, i64::from(delivery.attempts)))?;
+                stmt.bind((6, delivery.created_at.to_rfc3339().as_str()))?;
+                stmt.bind((7, delivery.next_attempt_at.to_rfc3339().as_str()))?;
+
+                // Execute the statement
+                stmt.next()?;
+                stmt.reset()?;
+                drop(stmt);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn get_due_deliveries(&self, now: DateTime<Utc>) -> Result<Vec<WebhookDelivery>> {
+        self.connection
+            .prepare(
+                r#"
+                    SELECT * FROM webhook_delivery
+                    WHERE status = ? AND next_attempt_at <= ?
+                    ORDER BY created_at
+                "#,
+            )?
+            .into_iter()
+            .bind((1, DeliveryStatus::Pending.as_str()))?
+            .bind((2, now.to_rfc3339().as_str()))?
+            .flat_map(|r| r.map(WebhookDelivery::try_from))
+            .collect()
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    UPDATE webhook_delivery
+                    SET status = ?, attempts = ?, next_attempt_at = ?, last_attempt_at = ?,
+                        response_status = ?, error = ?
+                    WHERE id = ?
+                "#,
+            )?;
+            stmt.bind((1, delivery.status.as_str()))?;
+            stmt.bind((2, i64::from(delivery.attempts)))?;
+            stmt.bind((3, delivery.next_attempt_at.to_rfc3339().as_str()))?;
+            stmt.bind((4, delivery.last_attempt_at.map(|d| d.to_rfc3339()).as_deref()))?;
+            stmt.bind((5, delivery.response_status.map(i64::from)))?;
+            stmt.bind((6, delivery.error.as_deref()))?;
+            stmt.bind((7, delivery.id.to_string().as_str()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_delivery_list(&self, limit: u32) -> Result<Vec<WebhookDelivery>> {
+        self.connection
+            .prepare("SELECT * FROM webhook_delivery ORDER BY created_at DESC LIMIT ?")?
+            .into_iter()
+            .bind((1, i64::from(limit)))?
+            .flat_map(|r| r.map(WebhookDelivery::try_from))
+            .collect()
+    }
+
+    async fn delete_deliveries_before(&self, date: DateTime<Utc>) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM webhook_delivery WHERE status != ? AND created_at < ?")?;
+            stmt.bind((1, DeliveryStatus::Pending.as_str()))?;
+            stmt.bind((2, date.to_rfc3339().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! This repository manages the WebSub subscriptions of the feeds
+mod websub_subscription_repository_impl;
+
+use crate::models::websub_subscription::WebSubSubscription;
+use axum::async_trait;
+
+pub use websub_subscription_repository_impl::WebSubSubscriptionRepositoryImpl;
+use uuid::Uuid;
+
+use super::RepositoryError;
+
+pub type Result<T> = std::result::Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait WebSubSubscriptionRepository: Sync + Send {
+    async fn get_subscription_list(&self) -> Result<Vec<WebSubSubscription>>;
+
+    async fn get_subscription(&self, feed_id: Uuid) -> Result<Option<WebSubSubscription>>;
+
+    /// Adds the subscription or replaces the one of its feed
+    async fn save_subscription(&self, subscription: &WebSubSubscription) -> Result<()>;
+
+    async fn delete_subscription(&self, feed_id: Uuid) -> Result<()>;
+}
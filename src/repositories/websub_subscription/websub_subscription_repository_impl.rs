@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use crate::{
+    models::websub_subscription::WebSubSubscription, repositories::RepositoryError, transaction,
+};
+use axum::async_trait;
+use sqlite::ConnectionThreadSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{Result, WebSubSubscriptionRepository};
+
+#[derive(Clone)]
+pub struct WebSubSubscriptionRepositoryImpl {
+    connection: Arc<ConnectionThreadSafe>,
+}
+
+impl WebSubSubscriptionRepositoryImpl {
+    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl WebSubSubscriptionRepository for WebSubSubscriptionRepositoryImpl {
+    async fn get_subscription_list(&self) -> Result<Vec<WebSubSubscription>> {
+        self.connection
+            .prepare("SELECT * FROM websub_subscription")?
+            .into_iter()
+            .flat_map(|r| r.map(WebSubSubscription::try_from))
+            .collect()
+    }
+
+    async fn get_subscription(&self, feed_id: Uuid) -> Result<Option<WebSubSubscription>> {
+        self.connection
+            .prepare("SELECT * FROM websub_subscription WHERE feed_id = ?")?
+            .into_iter()
+            .bind((1, feed_id.to_string().as_str()))?
+            .nth(0)
+            .map(|r| {
+                r.map_err(|e| RepositoryError::Unexpected(e.into()))
+                    .and_then(WebSubSubscription::try_from)
+            })
+            .transpose()
+    }
+
+    async fn save_subscription(&self, subscription: &WebSubSubscription) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self.connection.prepare(
+                r#"
+                    INSERT INTO websub_subscription
+                        (feed_id, hub_url, topic_url, secret, status, lease_expires_at, requested_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(feed_id) DO UPDATE SET
+                        hub_url = excluded.hub_url,
+                        topic_url = excluded.topic_url,
+                        secret = excluded.secret,
+                        status = excluded.status,
+                        lease_expires_at = excluded.lease_expires_at,
+                        requested_at = excluded.requested_at;
+                "#,
+            )?;
+            stmt.bind((1, subscription.feed_id.to_string().as_str()))?;
+            stmt.bind((2, subscription.hub_url.as_str()))?;
+            stmt.bind((3, subscription.topic_url.as_str()))?;
+            stmt.bind((4, subscription.secret.as_str()))?;
+            stmt.bind((5, subscription.status.as_str()))?;
+            stmt.bind((
+                6,
+                subscription.lease_expires_at.map(|d| d.to_rfc3339()).as_deref(),
+            ))?;
+            stmt.bind((7, subscription.requested_at.map(|d| d.to_rfc3339()).as_deref()))?;
+
+            // Execute the statement
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+
+    async fn delete_subscription(&self, feed_id: Uuid) -> Result<()> {
+        transaction!(self, {
+            let mut stmt = self
+                .connection
+                .prepare("DELETE FROM websub_subscription WHERE feed_id = ?")?;
+            stmt.bind((1, feed_id.to_string().as_str()))?;
+            stmt.next()?;
+            stmt.reset()?;
+            drop(stmt);
+
+            Ok(())
+        })
+    }
+}
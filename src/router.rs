@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 use axum::{
+    middleware::map_response,
     routing::{delete, get, post},
     Router,
 };
@@ -9,17 +10,37 @@ use tower_http::services::ServeDir;
 use crate::{
     config::Config,
     controllers::{
+        api,
+        category::{
+            add_category, delete_category, get_category_article_list, get_category_list,
+            rename_category,
+        },
         config::{
-            get_config, set_dark_theme, set_dont_invert_images, set_hide_article_header,
-            set_toolbar_position_left, set_zoom,
+            get_config, set_article_sort, set_dark_theme, set_digest, set_dont_invert_images,
+            set_hide_article_header, set_save_endpoint, set_show_thumbnails, set_smtp,
+            set_toolbar_position_left, set_wallabag, set_zoom,
         },
+        digest::{create_digest, delete_digest, get_digest_file, get_digests},
+        email::{get_outbox, send_article, send_digest},
+        export::{get_atom_export, get_epub_export},
         feed::{
             add_new_feed, add_new_feed_form, delete_feed, get_article, get_article_list,
-            get_feed_list,
+            get_article_revision, get_feed_list, get_feed_settings, reload_article, reprocess_feed,
+            set_article_starred, update_feed_settings,
         },
+        fever::fever_api,
+        filter_rule::{add_filter_rule, delete_filter_rule, get_filter_rules},
+        greader,
         not_found::not_found,
+        rewrite_rule::{add_rewrite_rule, delete_rewrite_rule},
+        save_to::{get_save_to, save_article},
+        tag::{add_article_tag, delete_article_tag, get_tag_article_list},
+        webhook::{add_webhook, delete_webhook, get_webhooks},
+        websub::{receive_content, verify_intent},
+    },
+    middlewares::{
+        error_handling_middleware::ErrorHandlingLayer, json_error_middleware::json_error_response,
     },
-    middlewares::error_handling_middleware::ErrorHandlingLayer,
     state::AppState,
 };
 
@@ -54,7 +75,72 @@ pub fn build<S: AppState>(state: S, config: &Config) -> Router {
         )
         .route("/feed/:feed_id", get(get_article_list::<S>))
         .route("/feed/:feed_id", delete(delete_feed::<S>))
+        .route(
+            "/feed/:feed_id/settings",
+            get(get_feed_settings::<S>).post(update_feed_settings::<S>),
+        )
+        .route("/feed/:feed_id/reprocess", post(reprocess_feed::<S>))
+        .route(
+            "/feed/:feed_id/rewrite_rules/add",
+            post(add_rewrite_rule::<S>),
+        )
+        .route(
+            "/feed/:feed_id/rewrite_rules/:rule_id",
+            delete(delete_rewrite_rule::<S>),
+        )
         .route("/feed/:feed_id/article/:article_id", get(get_article::<S>))
+        .route(
+            "/feed/:feed_id/article/:article_id/revisions/:revision_id",
+            get(get_article_revision::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/reload",
+            post(reload_article::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/starred",
+            post(set_article_starred::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/send",
+            post(send_article::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/save/:target",
+            post(save_article::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/tags/add",
+            post(add_article_tag::<S>),
+        )
+        .route(
+            "/feed/:feed_id/article/:article_id/tags/:name",
+            delete(delete_article_tag::<S>),
+        )
+        .route("/tag/:name", get(get_tag_article_list::<S>))
+        .route("/category", get(get_category_list::<S>))
+        .route("/category/add", post(add_category::<S>))
+        .route(
+            "/category/:category_id",
+            get(get_category_article_list::<S>)
+                .post(rename_category::<S>)
+                .delete(delete_category::<S>),
+        )
+        .route("/filters", get(get_filter_rules::<S>))
+        .route("/filters/add", post(add_filter_rule::<S>))
+        .route("/filters/:rule_id", delete(delete_filter_rule::<S>))
+        .route("/digests", get(get_digests::<S>))
+        .route("/digests/create", post(create_digest::<S>))
+        .route(
+            "/digests/:digest_id",
+            get(get_digest_file::<S>).delete(delete_digest::<S>),
+        )
+        .route("/digests/:digest_id/send", post(send_digest::<S>))
+        .route("/outbox", get(get_outbox::<S>))
+        .route("/save_to", get(get_save_to::<S>))
+        .route("/webhooks", get(get_webhooks::<S>))
+        .route("/webhooks/add", post(add_webhook::<S>))
+        .route("/webhooks/:webhook_id", delete(delete_webhook::<S>))
         .route("/config", get(get_config::<S>))
         .route("/config/dark_theme", post(set_dark_theme::<S>))
         .route("/config/zoom", post(set_zoom::<S>))
@@ -70,8 +156,2037 @@ pub fn build<S: AppState>(state: S, config: &Config) -> Router {
             "/config/dont_invert_images",
             post(set_dont_invert_images::<S>),
         )
+        .route("/config/show_thumbnails", post(set_show_thumbnails::<S>))
+        .route("/config/article_sort", post(set_article_sort::<S>))
+        .route("/config/digest", post(set_digest::<S>))
+        .route("/config/smtp", post(set_smtp::<S>))
+        .route("/config/wallabag", post(set_wallabag::<S>))
+        .route("/config/save_endpoint", post(set_save_endpoint::<S>))
+        .route("/export/atom", get(get_atom_export::<S>))
+        .route("/export/epub", get(get_epub_export::<S>))
         .route("/", get(get_feed_list::<S>))
         .layer(ErrorHandlingLayer::new(state.clone()))
+        // Nested after the error handling layer, the API returns its own JSON errors
+        .nest("/api/v1", build_api::<S>())
+        .merge(build_fever::<S>())
+        .merge(build_greader::<S>())
+        // The hubs only read the status of the responses, so they are not rendered as error pages
+        .route(
+            "/websub/:feed_id",
+            get(verify_intent::<S>).post(receive_content::<S>),
+        )
         .fallback(not_found::<S>)
         .with_state(state)
 }
+
+fn build_fever<S: AppState>() -> Router<S> {
+    // The clients are configured with the url of the endpoint, with or without the trailing slash
+    Router::new()
+        .route("/fever", get(fever_api::<S>).post(fever_api::<S>))
+        .route("/fever/", get(fever_api::<S>).post(fever_api::<S>))
+        .layer(map_response(json_error_response))
+}
+
+fn build_greader<S: AppState>() -> Router<S> {
+    let reader_api = Router::new()
+        .route("/token", get(greader::get_token::<S>))
+        .route("/user-info", get(greader::get_user_info::<S>))
+        .route(
+            "/subscription/list",
+            get(greader::get_subscription_list::<S>),
+        )
+        .route(
+            "/subscription/edit",
+            post(greader::edit_subscription::<S>),
+        )
+        .route(
+            "/subscription/quickadd",
+            post(greader::quickadd_subscription::<S>),
+        )
+        .route("/tag/list", get(greader::get_tag_list::<S>))
+        .route("/unread-count", get(greader::get_unread_count::<S>))
+        .route(
+            "/stream/contents",
+            get(greader::get_stream_contents::<S>),
+        )
+        .route(
+            "/stream/contents/*stream",
+            get(greader::get_stream_contents::<S>),
+        )
+        .route(
+            "/stream/items/ids",
+            get(greader::get_stream_item_ids::<S>),
+        )
+        .route(
+            "/stream/items/contents",
+            get(greader::get_stream_items_contents::<S>)
+                .post(greader::get_stream_items_contents::<S>),
+        )
+        .route("/edit-tag", post(greader::edit_tag::<S>))
+        .route("/mark-all-as-read", post(greader::mark_all_as_read::<S>));
+
+    Router::new()
+        .route(
+            "/accounts/ClientLogin",
+            get(greader::client_login::<S>).post(greader::client_login::<S>),
+        )
+        .nest("/reader/api/0", reader_api)
+        .layer(map_response(json_error_response))
+}
+
+fn build_api<S: AppState>() -> Router<S> {
+    Router::new()
+        .route(
+            "/feeds",
+            get(api::get_feed_list::<S>).post(api::add_feed::<S>),
+        )
+        .route(
+            "/feeds/:feed_id",
+            get(api::get_feed::<S>).delete(api::delete_feed::<S>),
+        )
+        .route("/feeds/:feed_id/articles", get(api::get_feed_articles::<S>))
+        .route(
+            "/feeds/:feed_id/articles/:article_id",
+            get(api::get_article::<S>).patch(api::update_article::<S>),
+        )
+        .route(
+            "/config",
+            get(api::get_config::<S>).patch(api::update_config::<S>),
+        )
+        .fallback(api::not_found)
+        .layer(map_response(json_error_response))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+
+    use axum::{
+        body::{to_bytes, Body},
+        extract::Path,
+        http::{header, HeaderMap, Method, Request, StatusCode},
+        middleware::map_request,
+        routing::{get, post},
+        Form, Router,
+    };
+    use md5::{Digest, Md5};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        config::Config,
+        models::webhook::DeliveryStatus,
+        repositories::init_database,
+        services::{
+            category::CategoryService,
+            digest::DigestService,
+            email::EmailService,
+            feed::FeedService,
+            greader::item_id,
+            webhook::{sign_payload, WebhookService, DELIVERY_HEADER, SIGNATURE_HEADER},
+            websub::WebSubService,
+        },
+        state::State,
+    };
+
+    const RSS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0">
+            <channel>
+                <title>Test Feed</title>
+                <link>http://example.com</link>
+                <description>A test feed</description>
+                <item>
+                    <title>First article</title>
+                    <link>http://example.com/first</link>
+                    <guid>first</guid>
+                    <pubDate>Tue, 02 Jan 2024 10:00:00 GMT</pubDate>
+                    <description>&lt;p&gt;Content of the first article&lt;/p&gt;</description>
+                </item>
+                <item>
+                    <title>Second article</title>
+                    <link>http://example.com/second</link>
+                    <guid>second</guid>
+                    <pubDate>Mon, 01 Jan 2024 10:00:00 GMT</pubDate>
+                    <description>&lt;p&gt;Content of the second article&lt;/p&gt;</description>
+                </item>
+            </channel>
+        </rss>"#;
+
+    /// App with an empty data directory, which is removed when it is dropped
+    struct TestApp {
+        router: Router,
+        state: State,
+        data_path: PathBuf,
+    }
+
+    impl Drop for TestApp {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.data_path);
+        }
+    }
+
+    async fn build_app() -> TestApp {
+        let data_path = std::env::temp_dir().join(format!("kindle-rss-reader-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&data_path).unwrap();
+
+        let config = Arc::new(
+            envy::from_iter::<_, Config>([
+                ("DATA_PATH".to_owned(), data_path.to_str().unwrap().to_owned()),
+                (
+                    "STATIC_DATA_PATH".to_owned(),
+                    env!("CARGO_MANIFEST_DIR").to_owned(),
+                ),
+                ("API_USERNAME".to_owned(), "reader".to_owned()),
+                ("API_PASSWORD".to_owned(), "secret".to_owned()),
+                ("EXPORT_TOKEN".to_owned(), "private".to_owned()),
+                ("PUBLIC_URL".to_owned(), "http://reader.example.com/".to_owned()),
+                ("MAX_ENCLOSURE_SIZE_TO_CACHE".to_owned(), "1000000".to_owned()),
+            ])
+            .unwrap(),
+        );
+
+        let connection = init_database(&config);
+        let state = State::new(connection, config.clone()).await;
+
+        TestApp {
+            router: super::build(state.clone(), &config),
+            state,
+            data_path,
+        }
+    }
+
+    /// Serves the test feed in a random port and returns its url
+    async fn serve_feed() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let app = Router::new().route(
+            "/feed.xml",
+            get(|| async { ([(header::CONTENT_TYPE, "application/rss+xml")], RSS) }),
+        );
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        format!("http://{address}/feed.xml")
+    }
+
+    /// Local SMTP server that accepts every email and records the messages
+    async fn serve_smtp() -> (u16, Arc<Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(vec![]));
+
+        let messages = received.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (reader, mut writer) = stream.into_split();
+                let mut lines = BufReader::new(reader).lines();
+                writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+
+                let mut message: Option<String> = None;
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if let Some(content) = message.as_mut() {
+                        if line == "." {
+                            messages.lock().unwrap().push(message.take().unwrap());
+                            writer.write_all(b"250 OK\r\n").await.unwrap();
+                        } else {
+                            content.push_str(&line);
+                            content.push('\n');
+                        }
+                        continue;
+                    }
+
+                    let command = line.split(' ').next().unwrap_or_default().to_uppercase();
+                    let reply: &[u8] = match command.as_str() {
+                        "EHLO" | "HELO" => b"250 localhost\r\n",
+                        "DATA" => {
+                            message = Some(String::new());
+                            b"354 End data with <CR><LF>.<CR><LF>\r\n"
+                        }
+                        "QUIT" => b"221 Bye\r\n",
+                        _ => b"250 OK\r\n",
+                    };
+                    writer.write_all(reply).await.unwrap();
+                }
+            }
+        });
+
+        (port, received)
+    }
+
+    async fn request(
+        app: &TestApp,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body.map_or(Body::empty(), |body| Body::from(body.to_string())))
+            .unwrap();
+
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+
+        (status, body)
+    }
+
+    /// Sends a Fever request with the given query and form body
+    async fn fever_request(app: &TestApp, query: &str, body: &str) -> Value {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/fever/?api&{query}"))
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(body.to_owned()))
+            .unwrap();
+
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    /// Sends a Google Reader request with the given form body, authenticated with the token if
+    /// it is given, and returns the status and the body
+    async fn greader_request(
+        app: &TestApp,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        body: &str,
+    ) -> (StatusCode, String) {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("GoogleLogin auth={token}"));
+        }
+        let request = request.body(Body::from(body.to_owned())).unwrap();
+
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    /// Sends an authenticated GET request to the Google Reader API and returns the JSON response
+    async fn greader_get(app: &TestApp, uri: &str, token: &str) -> Value {
+        let uri = format!("/reader/api/0/{uri}");
+        let (status, body) = greader_request(app, Method::GET, &uri, Some(token), "").await;
+        assert_eq!(StatusCode::OK, status, "{uri}: {body}");
+
+        serde_json::from_str(&body).unwrap()
+    }
+
+    /// Sends an authenticated POST request to the Google Reader API and returns the response,
+    /// parsed as JSON unless it is the `OK` of the actions
+    async fn greader_post(app: &TestApp, uri: &str, token: &str, body: &str) -> Value {
+        let uri = format!("/reader/api/0/{uri}");
+        let (status, body) = greader_request(app, Method::POST, &uri, Some(token), body).await;
+        assert_eq!(StatusCode::OK, status, "{uri}: {body}");
+
+        if body == "OK" {
+            Value::Null
+        } else {
+            serde_json::from_str(&body).unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_api_feeds_and_articles() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+
+        let (status, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        assert_eq!(StatusCode::CREATED, status);
+        assert_eq!("Test Feed", feed["title"]);
+        let feed_id = feed["id"].as_str().unwrap().to_owned();
+
+        let (status, feeds) = request(&app, Method::GET, "/api/v1/feeds", None).await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(1, feeds.as_array().unwrap().len());
+        assert_eq!(feeds[0], feed);
+
+        let articles_uri = format!("/api/v1/feeds/{feed_id}/articles");
+        let (status, articles) = request(&app, Method::GET, &articles_uri, None).await;
+        assert_eq!(StatusCode::OK, status);
+        let titles: Vec<&str> = articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["First article", "Second article"], titles);
+        let article_id = articles[0]["id"].as_str().unwrap().to_owned();
+
+        let article_uri = format!("/api/v1/feeds/{feed_id}/articles/{article_id}");
+        let (status, article) = request(&app, Method::GET, &article_uri, None).await;
+        assert_eq!(StatusCode::OK, status);
+        assert!(article["html"]
+            .as_str()
+            .unwrap()
+            .contains("Content of the first article"));
+        assert_eq!(false, article["read"]);
+        // The internal fields are not exposed
+        for field in ["content", "content_hash", "canonical_url", "html_parsed", "found_at"] {
+            assert!(article.get(field).is_none(), "{field}");
+            assert!(articles[0].get(field).is_none(), "{field}");
+        }
+
+        let (status, _) = request(
+            &app,
+            Method::PATCH,
+            &article_uri,
+            Some(json!({ "read": true, "starred": true })),
+        )
+        .await;
+        assert_eq!(StatusCode::NO_CONTENT, status);
+
+        let (_, read) =
+            request(&app, Method::GET, &format!("{articles_uri}?read=true"), None).await;
+        assert_eq!(1, read.as_array().unwrap().len());
+        assert_eq!(article_id, read[0]["id"]);
+        assert_eq!(true, read[0]["starred"]);
+
+        let (_, unread) =
+            request(&app, Method::GET, &format!("{articles_uri}?read=false&limit=5"), None).await;
+        assert_eq!(1, unread.as_array().unwrap().len());
+        assert_eq!("Second article", unread[0]["title"]);
+
+        let (status, _) =
+            request(&app, Method::PATCH, &article_uri, Some(json!({ "read": false }))).await;
+        assert_eq!(StatusCode::NO_CONTENT, status);
+        let (_, unread) =
+            request(&app, Method::GET, &format!("{articles_uri}?read=false"), None).await;
+        assert_eq!(2, unread.as_array().unwrap().len());
+
+        let feed_uri = format!("/api/v1/feeds/{feed_id}");
+        let (status, _) = request(&app, Method::DELETE, &feed_uri, None).await;
+        assert_eq!(StatusCode::NO_CONTENT, status);
+
+        let (status, error) = request(&app, Method::GET, &feed_uri, None).await;
+        assert_eq!(StatusCode::NOT_FOUND, status);
+        assert!(error["error"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_api_config() {
+        let app = build_app().await;
+
+        let (status, config) = request(&app, Method::GET, "/api/v1/config", None).await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(false, config["dark_theme"]);
+        assert_eq!("newest", config["article_sort"]);
+
+        let (status, config) = request(
+            &app,
+            Method::PATCH,
+            "/api/v1/config",
+            Some(json!({ "dark_theme": true, "article_sort": "oldest" })),
+        )
+        .await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(true, config["dark_theme"]);
+        assert_eq!("oldest", config["article_sort"]);
+        assert_eq!(1.0, config["zoom"]);
+
+        let (status, error) = request(
+            &app,
+            Method::PATCH,
+            "/api/v1/config",
+            Some(json!({ "zoom": -1.0 })),
+        )
+        .await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert!(error["error"].is_string());
+
+        // Nothing is saved when one of the settings is invalid
+        let (status, _) = request(
+            &app,
+            Method::PATCH,
+            "/api/v1/config",
+            Some(json!({ "dark_theme": false, "digest": { "hour": 24 } })),
+        )
+        .await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        let (_, config) = request(&app, Method::GET, "/api/v1/config", None).await;
+        assert_eq!(true, config["dark_theme"]);
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_json() {
+        let app = build_app().await;
+        let unknown_feed = format!("/api/v1/feeds/{}", Uuid::new_v4());
+
+        let cases = [
+            (Method::GET, unknown_feed.clone(), None, StatusCode::NOT_FOUND),
+            (
+                Method::GET,
+                format!("{unknown_feed}/articles"),
+                None,
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Method::PATCH,
+                format!("{unknown_feed}/articles/{}", Uuid::new_v4()),
+                Some(json!({ "read": true })),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Method::GET,
+                "/api/v1/feeds/not-an-id".to_owned(),
+                None,
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Method::POST,
+                "/api/v1/feeds".to_owned(),
+                Some(json!({ "url": "not a url" })),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Method::POST,
+                "/api/v1/feeds".to_owned(),
+                Some(json!({ "link": "http://example.com" })),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                Method::PATCH,
+                "/api/v1/config".to_owned(),
+                Some(json!({ "unknown": true })),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                Method::GET,
+                "/api/v1/unknown".to_owned(),
+                None,
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (method, uri, body, expected_status) in cases {
+            let (status, error) = request(&app, method, &uri, body).await;
+            assert_eq!(expected_status, status, "{uri}");
+            assert!(error["error"].is_string(), "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_categories() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+
+        let send = |method: Method, uri: &str, body: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body.to_owned()))
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+        let page = |uri: String| async move {
+            let response = send(Method::GET, &uri, "").await.unwrap();
+            assert_eq!(StatusCode::OK, response.status());
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            String::from_utf8(body.to_vec()).unwrap()
+        };
+
+        let response = send(Method::POST, "/category/add", "name=News").await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        // The names are unique
+        let response = send(Method::POST, "/category/add", "name=+News+").await.unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+        let categories = app.state.category_service.get_category_list().await.unwrap();
+        assert_eq!(1, categories.len());
+        let category_id = categories[0].id;
+
+        // The feeds can only be added to existing categories, from any entry point
+        let unknown = Uuid::new_v4();
+        let response = send(
+            Method::POST,
+            "/feed/add",
+            &format!("url={feed_url}&category_id={unknown}"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+        let (status, body) = request(
+            &app,
+            Method::POST,
+            "/api/v1/feeds",
+            Some(json!({ "url": feed_url, "category_id": unknown })),
+        )
+        .await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert!(body["error"].as_str().unwrap().contains(&unknown.to_string()));
+        assert!(app.state.feed_service.get_feed_list().await.unwrap().is_empty());
+
+        let response = send(
+            Method::POST,
+            "/feed/add",
+            &format!("url={feed_url}&category_id={category_id}"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let feed_id = app.state.feed_service.get_feed_list().await.unwrap()[0].id;
+        let response = send(
+            Method::POST,
+            &format!("/feed/{feed_id}/settings"),
+            &format!("category_id={unknown}&content_mode=feed"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+        let (_, articles) =
+            request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+
+        // The feed is listed inside its category, both with the count of unread articles
+        let feed_list = page("/".to_owned()).await;
+        let category_position = feed_list.find("<h3 onclick").unwrap();
+        assert!(feed_list[category_position..].contains("News"));
+        assert!(feed_list[category_position..].contains("Test Feed"));
+        assert_eq!(2, feed_list.matches(r#"<span class="unread-count">2</span>"#).count());
+
+        let article_id = articles[0]["id"].as_str().unwrap();
+        request(
+            &app,
+            Method::PATCH,
+            &format!("/api/v1/feeds/{feed_id}/articles/{article_id}"),
+            Some(json!({ "read": true })),
+        )
+        .await;
+        let feed_list = page("/".to_owned()).await;
+        assert_eq!(2, feed_list.matches(r#"<span class="unread-count">1</span>"#).count());
+        let river = page(format!("/category/{category_id}")).await;
+        assert!(river.contains("First article") && river.contains("Second article"));
+
+        // The names are escaped in every page that shows them
+        let name = "name=%3Cb%3EWorld%3C%2Fb%3E";
+        let response = send(Method::POST, &format!("/category/{category_id}"), name)
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let pages = [
+            "/category".to_owned(),
+            "/".to_owned(),
+            "/feed/add".to_owned(),
+            "/digests".to_owned(),
+            format!("/feed/{feed_id}/settings"),
+        ];
+        for uri in pages {
+            let body = page(uri.clone()).await;
+            assert!(!body.contains("<b>World"), "{uri}");
+            assert!(body.contains("&lt;b&gt;World"), "{uri}");
+        }
+
+        let response = send(Method::DELETE, &format!("/category/{category_id}"), "")
+            .await
+            .unwrap();
+        assert!(response.status().is_success() || response.status().is_redirection());
+        assert!(app.state.category_service.get_category_list().await.unwrap().is_empty());
+        let (_, feed) = request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}"), None).await;
+        assert_eq!(Value::Null, feed["category_id"]);
+    }
+
+    #[tokio::test]
+    async fn test_reload_and_reprocess() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let app = build_app().await;
+
+        // Local site with a feed that can be made unreachable, the web page of the first article
+        // and the images of both
+        let feed_available = Arc::new(AtomicBool::new(true));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let rss = RSS
+            .replace(
+                "&lt;p&gt;Content of the first article&lt;/p&gt;",
+                &format!("&lt;p&gt;Feed content&lt;/p&gt;&lt;img src=\"{site}/feed.png\"&gt;"),
+            )
+            .replace("http://example.com/first", &format!("{site}/first"))
+            .replace(
+                "&lt;p&gt;Content of the second article&lt;/p&gt;",
+                &format!("&lt;p&gt;Second content&lt;/p&gt;&lt;img src=\"{site}/feed.png\"&gt;"),
+            );
+        let page = format!(
+            "<html><head><title>First article</title></head><body><article>\
+            <h1>First article</h1><p>The content of the web page of the first article, long \
+            enough to be taken as the main content of the page by the extraction.</p>\
+            <img src=\"{site}/page.png\"></article></body></html>"
+        );
+        let server = Router::new()
+            .route(
+                "/feed.xml",
+                get({
+                    let feed_available = feed_available.clone();
+                    move || async move {
+                        if feed_available.load(Ordering::SeqCst) {
+                            Ok(([(header::CONTENT_TYPE, "application/rss+xml")], rss))
+                        } else {
+                            Err(StatusCode::SERVICE_UNAVAILABLE)
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/first",
+                get(move || async move { ([(header::CONTENT_TYPE, "text/html")], page) }),
+            )
+            .route("/feed.png", get(|| async { "feed image" }))
+            .route("/page.png", get(|| async { "page image" }));
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let (_, feed) = request(
+            &app,
+            Method::POST,
+            "/api/v1/feeds",
+            Some(json!({ "url": format!("{site}/feed.xml") })),
+        )
+        .await;
+        let feed_id = feed["id"].as_str().unwrap().to_owned();
+        let (_, articles) =
+            request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+        let article_uri = |title: &str| {
+            let article = articles
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|article| article["title"] == title)
+                .unwrap();
+            format!("/feed/{feed_id}/article/{}", article["id"].as_str().unwrap())
+        };
+        let (first_uri, second_uri) = (article_uri("First article"), article_uri("Second article"));
+
+        let send = |method: Method, uri: String| {
+            let request = Request::builder().method(method).uri(uri).body(Body::empty()).unwrap();
+            app.router.clone().oneshot(request)
+        };
+        let articles_path = app.data_path.join("articles").join(&feed_id);
+        let article_file = |uri: &str| {
+            let article_id = uri.rsplit('/').next().unwrap();
+            std::fs::read_to_string(articles_path.join(format!("{article_id}.html"))).unwrap()
+        };
+        let images = || -> std::collections::HashSet<String> {
+            std::fs::read_dir(articles_path.join("static"))
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect()
+        };
+        let image_content = |name: &String| {
+            std::fs::read_to_string(articles_path.join("static").join(name)).unwrap()
+        };
+
+        // Reading the articles caches their content and images
+        send(Method::GET, first_uri.clone()).await.unwrap();
+        send(Method::GET, second_uri.clone()).await.unwrap();
+        assert!(article_file(&first_uri).contains("Feed content"));
+        let feed_images = images();
+        assert_eq!(2, feed_images.len());
+
+        // Reloading an article replaces its content and image with the ones of its web page
+        let response = send(Method::POST, format!("{first_uri}/reload")).await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        assert!(article_file(&first_uri).contains("content of the web page"));
+        let reloaded_images = images();
+        assert_eq!(2, reloaded_images.len());
+        let page_image = reloaded_images.difference(&feed_images).next().unwrap().clone();
+        assert_eq!("page image", image_content(&page_image));
+        let second_image = reloaded_images.intersection(&feed_images).next().unwrap().clone();
+        assert!(article_file(&second_uri).contains(&second_image));
+
+        // When the feed can not be fetched only the articles taken from it are skipped
+        feed_available.store(false, Ordering::SeqCst);
+        let response = send(Method::POST, format!("/feed/{feed_id}/reprocess")).await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        assert!(response.headers()[header::LOCATION].to_str().unwrap().ends_with("=1"));
+        let reprocessed_images = images();
+        assert_eq!(2, reprocessed_images.len());
+        assert!(!reprocessed_images.contains(&page_image));
+        assert!(reprocessed_images.contains(&second_image));
+        assert!(article_file(&first_uri).contains("content of the web page"));
+
+        feed_available.store(true, Ordering::SeqCst);
+        let response = send(Method::POST, format!("/feed/{feed_id}/reprocess")).await.unwrap();
+        assert!(response.headers()[header::LOCATION].to_str().unwrap().ends_with("=2"));
+        let final_images = images();
+        assert_eq!(2, final_images.len());
+        assert!(final_images.is_disjoint(&reprocessed_images));
+        assert!(article_file(&second_uri).contains("Second content"));
+        for image in &final_images {
+            assert!(
+                article_file(&first_uri).contains(image) || article_file(&second_uri).contains(image)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enclosures_are_cached_in_background() {
+        let app = build_app().await;
+
+        // The episode is not served until the article list is returned
+        let release = Arc::new(tokio::sync::Notify::new());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let rss = RSS.replace(
+            "<guid>first</guid>",
+            &format!(
+                r#"<guid>first</guid>
+                <enclosure url="{site}/episode.mp3" type="audio/mpeg" length="7"/>"#
+            ),
+        );
+        let server = Router::new()
+            .route(
+                "/feed.xml",
+                get(|| async { ([(header::CONTENT_TYPE, "application/rss+xml")], rss) }),
+            )
+            .route(
+                "/episode.mp3",
+                get({
+                    let release = release.clone();
+                    move || async move {
+                        release.notified().await;
+                        "episode"
+                    }
+                }),
+            );
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let timeout = std::time::Duration::from_secs(5);
+        let body = json!({ "url": format!("{site}/feed.xml") });
+        let (_, feed) =
+            tokio::time::timeout(timeout, request(&app, Method::POST, "/api/v1/feeds", Some(body)))
+                .await
+                .expect("adding the feed waited for the enclosure download");
+        let feed_id = feed["id"].as_str().unwrap();
+        let articles_uri = format!("/api/v1/feeds/{feed_id}/articles");
+        let (status, articles) =
+            tokio::time::timeout(timeout, request(&app, Method::GET, &articles_uri, None))
+                .await
+                .expect("the article list waited for the enclosure download");
+        assert_eq!(StatusCode::OK, status);
+
+        let article_id = articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|article| article["title"] == "First article")
+            .unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let enclosures = app.state.feed_service.get_article_enclosures(article_id).await.unwrap();
+        assert_eq!(1, enclosures.len());
+        assert_eq!(None, enclosures[0].cached_url);
+
+        release.notify_one();
+        let mut cached_url = None;
+        for _ in 0..50 {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            let enclosures =
+                app.state.feed_service.get_article_enclosures(article_id).await.unwrap();
+            cached_url = enclosures[0].cached_url.clone();
+            if cached_url.is_some() {
+                break;
+            }
+        }
+        let cached_url = cached_url.expect("the enclosure was not cached");
+        let file_name = cached_url.rsplit('/').next().unwrap();
+        let file = app.data_path.join("articles").join(feed_id).join("enclosures").join(file_name);
+        assert_eq!("episode", std::fs::read_to_string(file).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_thumbnails() {
+        let app = build_app().await;
+
+        let mut image = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(400, 300)
+            .write_to(&mut image, image::ImageFormat::Png)
+            .unwrap();
+        let image = image.into_inner();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let rss = RSS.replace(
+            "&lt;p&gt;Content of the first article&lt;/p&gt;",
+            &format!("&lt;p&gt;First&lt;/p&gt;&lt;img src=\"{site}/image.png\"&gt;"),
+        );
+        let feed = move || {
+            let rss = rss.clone();
+            async move { ([(header::CONTENT_TYPE, "application/rss+xml")], rss) }
+        };
+        let server = Router::new()
+            .route("/a.xml", get(feed.clone()))
+            .route("/b.xml", get(feed))
+            .route("/image.png", get(move || async move { image }));
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let add_feed = |path: &'static str| {
+            let site = site.clone();
+            let app = &app;
+            async move {
+                let body = json!({ "url": format!("{site}/{path}") });
+                let (_, feed) = request(app, Method::POST, "/api/v1/feeds", Some(body)).await;
+                let feed_id = feed["id"].as_str().unwrap().to_owned();
+                let articles_uri = format!("/api/v1/feeds/{feed_id}/articles");
+                let (_, articles) = request(app, Method::GET, &articles_uri, None).await;
+                (feed_id, articles)
+            }
+        };
+        let thumbnails = |feed_id: &str| -> Vec<String> {
+            std::fs::read_dir(app.data_path.join("articles").join(feed_id).join("static"))
+                .map(|entries| {
+                    entries
+                        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                        .filter(|name| name.ends_with(".jpg"))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        // Thumbnails are not created while the lists do not show them
+        let (feed_id, articles) = add_feed("a.xml").await;
+        assert!(thumbnails(&feed_id).is_empty());
+        assert!(articles.as_array().unwrap().iter().all(|a| a["thumbnail_url"].is_null()));
+
+        let request_body = Request::builder()
+            .method(Method::POST)
+            .uri("/config/show_thumbnails")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("show_thumbnails=true"))
+            .unwrap();
+        app.router.clone().oneshot(request_body).await.unwrap();
+
+        let (feed_id, articles) = add_feed("b.xml").await;
+        let thumbnail = thumbnails(&feed_id).pop().expect("the thumbnail was not created");
+        let first = articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|article| article["title"] == "First article")
+            .unwrap();
+        assert!(first["thumbnail_url"].as_str().unwrap().ends_with(&thumbnail));
+
+        // Replacing the content of the article keeps its thumbnail
+        let article_uri = format!("/feed/{feed_id}/article/{}", first["id"].as_str().unwrap());
+        for (method, uri) in [
+            (Method::GET, article_uri),
+            (Method::POST, format!("/feed/{feed_id}/reprocess")),
+        ] {
+            let request = Request::builder().method(method).uri(uri).body(Body::empty()).unwrap();
+            app.router.clone().oneshot(request).await.unwrap();
+        }
+        assert_eq!(vec![thumbnail], thumbnails(&feed_id));
+    }
+
+    #[tokio::test]
+    async fn test_tag_page_escapes_the_name() {
+        let app = build_app().await;
+
+        let request = Request::builder()
+            .uri("/tag/%3Cscript%3Ealert(1)%3C%2Fscript%3E%20%20News")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(!body.contains("<script>alert"));
+        assert!(body.contains("#&lt;script&gt;alert(1)&lt;&#x2f;script&gt; news"));
+    }
+
+    #[tokio::test]
+    async fn test_filter_rules_page_escapes_the_patterns_and_titles() {
+        let app = build_app().await;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let feed_url = format!("http://{}/feed.xml", listener.local_addr().unwrap());
+        let rss = RSS.replace("<title>First article", "<title>&lt;b&gt;First&lt;/b&gt; article");
+        let server = Router::new().route(
+            "/feed.xml",
+            get(move || async move { ([(header::CONTENT_TYPE, "application/rss+xml")], rss) }),
+        );
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap();
+        request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+
+        let add_rule = Request::builder()
+            .method(Method::POST)
+            .uri("/filters/add")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(
+                "field=title&match_type=substring&pattern=%3Ci%3Eads&action=mark_as_read",
+            ))
+            .unwrap();
+        app.router.clone().oneshot(add_rule).await.unwrap();
+
+        let test_rule = Request::builder()
+            .uri("/filters?field=title&match_type=substring&pattern=%3Cb%3EFirst")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.router.clone().oneshot(test_rule).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(!body.contains("<b>First"));
+        assert!(!body.contains("<i>ads"));
+        assert!(body.contains(r#"value="&lt;b&gt;First""#));
+        assert!(body.contains("<h3>&lt;b&gt;First&lt;&#x2f;b&gt; article</h3>"));
+        assert!(body.contains("<h3>&lt;i&gt;ads</h3>"));
+    }
+
+    #[tokio::test]
+    async fn test_updated_web_pages_are_downloaded_concurrently() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let app = build_app().await;
+
+        // Once the articles are updated, their web pages are only served when both are requested
+        // at the same time
+        let updated = Arc::new(AtomicBool::new(false));
+        let barrier = Arc::new(tokio::sync::Barrier::new(2));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let rss = RSS
+            .replace("http://example.com/first", &format!("{site}/first"))
+            .replace("http://example.com/second", &format!("{site}/second"));
+        let page = {
+            let updated = updated.clone();
+            move |Path(name): Path<String>| {
+                let updated = updated.clone();
+                let barrier = barrier.clone();
+                async move {
+                    let version = if updated.load(Ordering::SeqCst) {
+                        barrier.wait().await;
+                        2
+                    } else {
+                        1
+                    };
+                    let page = format!(
+                        "<html><head><title>{name}</title></head><body><article>\
+                        <h1>{name}</h1><p>Version {version} of the web page of the {name} \
+                        article, long enough to be taken as the main content of the page.</p>\
+                        </article></body></html>"
+                    );
+                    ([(header::CONTENT_TYPE, "text/html")], page)
+                }
+            }
+        };
+        let server = Router::new()
+            .route(
+                "/feed.xml",
+                get({
+                    let rss = rss.clone();
+                    move || async move { ([(header::CONTENT_TYPE, "application/rss+xml")], rss) }
+                }),
+            )
+            .route("/:name", get(page));
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let body = json!({ "url": format!("{site}/feed.xml") });
+        let (_, feed) = request(&app, Method::POST, "/api/v1/feeds", Some(body)).await;
+        let feed_id = feed["id"].as_str().unwrap().to_owned();
+        let articles_uri = format!("/api/v1/feeds/{feed_id}/articles");
+        let (_, articles) = request(&app, Method::GET, &articles_uri, None).await;
+        let article_uris: Vec<String> = articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|article| format!("/feed/{feed_id}/article/{}", article["id"].as_str().unwrap()))
+            .collect();
+        let send = |method: Method, uri: String| {
+            let request = Request::builder().method(method).uri(uri).body(Body::empty()).unwrap();
+            app.router.clone().oneshot(request)
+        };
+        // Reloading the articles takes their content from the web pages from now on
+        for uri in &article_uris {
+            send(Method::POST, format!("{uri}/reload")).await.unwrap();
+        }
+
+        // The updated feed is pushed, as it would only be fetched again after a while
+        updated.store(true, Ordering::SeqCst);
+        let rss = rss.replace("article</title>", "article (updated)</title>");
+        let feed_id = Uuid::parse_str(&feed_id).unwrap();
+        let push = app.state.feed_service.push_feed_content(
+            feed_id,
+            rss.as_bytes(),
+            Some("application/rss+xml"),
+        );
+        tokio::time::timeout(std::time::Duration::from_secs(5), push)
+            .await
+            .expect("the updated web pages were downloaded one after another")
+            .unwrap();
+        let (_, articles) = request(&app, Method::GET, &articles_uri, None).await;
+        assert!(articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|article| article["title"].as_str().unwrap().ends_with("(updated)")));
+
+        for uri in article_uris {
+            let response = send(Method::GET, uri).await.unwrap();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body = String::from_utf8(body.to_vec()).unwrap();
+            assert!(body.contains("Version 2 of the web page"));
+            assert!(body.contains("Previous versions"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feeds_are_refreshed_concurrently() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let app = build_app().await;
+
+        // Once the feeds are added, they are only served when both are requested at the same time
+        let added = Arc::new(AtomicBool::new(false));
+        let barrier = Arc::new(tokio::sync::Barrier::new(2));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let server = Router::new().route(
+            "/:name",
+            get({
+                let added = added.clone();
+                move || {
+                    let added = added.clone();
+                    let barrier = barrier.clone();
+                    async move {
+                        if added.load(Ordering::SeqCst) {
+                            barrier.wait().await;
+                        }
+                        ([(header::CONTENT_TYPE, "application/rss+xml")], RSS)
+                    }
+                }
+            }),
+        );
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        for name in ["first.xml", "second.xml"] {
+            let body = json!({ "url": format!("{site}/{name}") });
+            let (status, _) = request(&app, Method::POST, "/api/v1/feeds", Some(body)).await;
+            assert_eq!(StatusCode::CREATED, status);
+        }
+
+        added.store(true, Ordering::SeqCst);
+        let refresh = app.state.feed_service.refresh_feeds();
+        tokio::time::timeout(std::time::Duration::from_secs(5), refresh)
+            .await
+            .expect("the feeds were downloaded one after another")
+            .unwrap();
+
+        let (_, feeds) = request(&app, Method::GET, "/api/v1/feeds", None).await;
+        for feed in feeds.as_array().unwrap() {
+            assert_eq!(2, feed["unread_count"], "{}", feed["url"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fever() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+        let api_key = format!("api_key={:x}", Md5::digest("reader:secret"));
+
+        let response = fever_request(&app, "feeds", "api_key=wrong").await;
+        assert_eq!(json!({ "api_version": 3, "auth": 0 }), response);
+
+        request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+
+        let response = fever_request(&app, "feeds&groups", &api_key).await;
+        assert_eq!(1, response["auth"]);
+        assert_eq!("Test Feed", response["feeds"][0]["title"]);
+        assert_eq!(json!([]), response["groups"]);
+        let feed_id = response["feeds"][0]["id"].as_i64().unwrap();
+
+        // The syncs check the feeds in the background, the new articles show up after a while
+        for _ in 0..50 {
+            let response = fever_request(&app, "unread_item_ids", &api_key).await;
+            if response["unread_item_ids"] != "" {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        let response = fever_request(&app, "items&since_id=0", &api_key).await;
+        let items = response["items"].as_array().unwrap();
+        assert_eq!(2, items.len());
+        assert_eq!(2, response["total_items"]);
+        assert_eq!(feed_id, items[0]["feed_id"]);
+        assert!(items[0]["html"].as_str().unwrap().contains("Content of the"));
+        let first_id = items[0]["id"].as_i64().unwrap();
+        let second_id = items[1]["id"].as_i64().unwrap();
+        assert!(first_id < second_id);
+
+        let response =
+            fever_request(&app, &format!("items&since_id={second_id}"), &api_key).await;
+        assert_eq!(json!([]), response["items"]);
+
+        let response = fever_request(&app, &format!("items&max_id={second_id}"), &api_key).await;
+        assert_eq!(first_id, response["items"][0]["id"]);
+
+        let response = fever_request(&app, "unread_item_ids", &api_key).await;
+        assert_eq!(format!("{first_id},{second_id}"), response["unread_item_ids"]);
+
+        let body = format!("{api_key}&mark=item&as=read&id={first_id}");
+        let response = fever_request(&app, "", &body).await;
+        assert_eq!(second_id.to_string(), response["unread_item_ids"]);
+
+        let body = format!("{api_key}&mark=item&as=saved&id={second_id}");
+        let response = fever_request(&app, "", &body).await;
+        assert_eq!(second_id.to_string(), response["saved_item_ids"]);
+
+        let body = format!("{api_key}&mark=feed&as=read&id={feed_id}&before=0");
+        let response = fever_request(&app, "", &body).await;
+        assert_eq!(second_id.to_string(), response["unread_item_ids"]);
+
+        let before = chrono::Utc::now().timestamp() + 60;
+        let body = format!("{api_key}&mark=group&as=read&id=0&before={before}");
+        let response = fever_request(&app, "", &body).await;
+        assert_eq!("", response["unread_item_ids"]);
+    }
+
+    #[tokio::test]
+    async fn test_greader() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+        let login = "/accounts/ClientLogin";
+
+        let (status, _) =
+            greader_request(&app, Method::POST, login, None, "Email=reader&Passwd=wrong").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, status);
+
+        let (status, body) =
+            greader_request(&app, Method::POST, login, None, "Email=reader&Passwd=secret").await;
+        assert_eq!(StatusCode::OK, status);
+        let token = body
+            .lines()
+            .find_map(|line| line.strip_prefix("Auth="))
+            .unwrap()
+            .to_owned();
+
+        let uri = "/reader/api/0/subscription/list";
+        let (status, _) = greader_request(&app, Method::GET, uri, Some("wrong"), "").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, status);
+
+        let body = format!("quickadd={feed_url}");
+        let response = greader_post(&app, "subscription/quickadd", &token, &body).await;
+        let stream_id = response["streamId"].as_str().unwrap().to_owned();
+
+        let body = format!("ac=edit&s={stream_id}&a=user/-/label/Tech");
+        greader_post(&app, "subscription/edit", &token, &body).await;
+
+        let response = greader_get(&app, "subscription/list", &token).await;
+        let subscription = &response["subscriptions"][0];
+        assert_eq!("Test Feed", subscription["title"]);
+        assert_eq!(stream_id, subscription["id"]);
+        assert_eq!("user/-/label/Tech", subscription["categories"][0]["id"]);
+
+        let response = greader_get(&app, "tag/list", &token).await;
+        assert_eq!(
+            json!([
+                { "id": "user/-/state/com.google/starred" },
+                { "id": "user/-/label/Tech", "type": "folder" },
+            ]),
+            response["tags"]
+        );
+
+        // The syncs check the feeds in the background, the new articles show up after a while
+        let uri = "stream/items/ids?s=user/-/state/com.google/reading-list&n=1";
+        for _ in 0..50 {
+            let page = greader_get(&app, uri, &token).await;
+            if !page["itemRefs"].as_array().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        // Pages of one item, the most recent first
+        let page = greader_get(&app, uri, &token).await;
+        let first_id = page["itemRefs"][0]["id"].as_str().unwrap().to_owned();
+        let continuation = page["continuation"].as_str().unwrap();
+        let page = greader_get(&app, &format!("{uri}&c={continuation}"), &token).await;
+        let second_id = page["itemRefs"][0]["id"].as_str().unwrap().to_owned();
+        assert!(page["continuation"].is_null());
+
+        let response = greader_get(&app, "stream/contents/user/-/label/Tech", &token).await;
+        let items = response["items"].as_array().unwrap();
+        assert_eq!(2, items.len());
+        assert_eq!("First article", items[0]["title"]);
+        assert_eq!(
+            item_id(first_id.parse().unwrap()),
+            items[0]["id"].as_str().unwrap()
+        );
+        assert_eq!(stream_id, items[0]["origin"]["streamId"]);
+        let content = items[0]["summary"]["content"].as_str().unwrap();
+        assert!(content.contains("Content of the first article"));
+        assert_eq!(
+            json!(["user/-/state/com.google/reading-list", "user/-/label/Tech"]),
+            items[0]["categories"]
+        );
+
+        // Both forms of the item ids are accepted
+        let body = format!(
+            "i={}&a=user/-/state/com.google/read",
+            item_id(first_id.parse().unwrap())
+        );
+        greader_post(&app, "edit-tag", &token, &body).await;
+        let body = format!("i={second_id}&a=user/-/state/com.google/starred");
+        greader_post(&app, "edit-tag", &token, &body).await;
+
+        let uri = format!("stream/items/ids?s={stream_id}&xt=user/-/state/com.google/read");
+        let response = greader_get(&app, &uri, &token).await;
+        assert_eq!(second_id, response["itemRefs"][0]["id"]);
+        assert_eq!(1, response["itemRefs"].as_array().unwrap().len());
+
+        let uri = "stream/contents/user/-/state/com.google/starred";
+        let response = greader_get(&app, uri, &token).await;
+        assert_eq!("Second article", response["items"][0]["title"]);
+        assert_eq!(1, response["items"].as_array().unwrap().len());
+
+        let body = format!("i={first_id}&i={second_id}");
+        let response = greader_post(&app, "stream/items/contents", &token, &body).await;
+        assert_eq!(2, response["items"].as_array().unwrap().len());
+
+        let response = greader_get(&app, "unread-count", &token).await;
+        assert_eq!(1, response["max"]);
+
+        let ts = (chrono::Utc::now().timestamp() + 60) * 1_000_000;
+        let body = format!("s={stream_id}&ts={ts}");
+        greader_post(&app, "mark-all-as-read", &token, &body).await;
+        let response = greader_get(&app, "unread-count", &token).await;
+        assert_eq!(0, response["max"]);
+
+        // Labels belong to the feeds, they can not be added to items
+        let uri = "/reader/api/0/edit-tag";
+        let body = format!("i={first_id}&a=user/-/label/Tech");
+        let (status, body) = greader_request(&app, Method::POST, uri, Some(&token), &body).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert!(serde_json::from_str::<Value>(&body).unwrap()["error"].is_string());
+
+        let body = format!("ac=unsubscribe&s={stream_id}");
+        greader_post(&app, "subscription/edit", &token, &body).await;
+        let response = greader_get(&app, "subscription/list", &token).await;
+        assert_eq!(json!([]), response["subscriptions"]);
+    }
+
+    #[tokio::test]
+    async fn test_atom_export() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+        let export = |uri: &str| {
+            let request = Request::builder()
+                .uri(uri)
+                .header(header::HOST, "reader.local")
+                .body(Body::empty())
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let articles_uri = format!("/api/v1/feeds/{}/articles", feed["id"].as_str().unwrap());
+        let (_, articles) = request(&app, Method::GET, &articles_uri, None).await;
+        let article_uri = format!("{articles_uri}/{}", articles[1]["id"].as_str().unwrap());
+        request(&app, Method::PATCH, &article_uri, Some(json!({ "starred": true }))).await;
+
+        let response = export("/export/atom?starred=true").await.unwrap();
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let response = export("/export/atom?starred=true&token=private")
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "application/atom+xml; charset=utf-8",
+            response.headers()[header::CONTENT_TYPE]
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let atom = atom_syndication::Feed::read_from(&body[..]).unwrap();
+
+        assert_eq!("Starred articles", atom.title().as_str());
+        assert_eq!("http://reader.local/export/atom?starred=true", atom.id());
+        assert!(atom.links().iter().all(|link| !link.href().contains("private")));
+        assert_eq!(1, atom.entries().len());
+        let entry = &atom.entries()[0];
+        assert_eq!("Second article", entry.title().as_str());
+        assert_eq!("http://example.com/second", entry.links()[0].href());
+        assert_eq!("Test Feed", entry.source().unwrap().title().as_str());
+        let content = entry.content().unwrap().value().unwrap();
+        assert!(content.contains("Content of the second article"));
+
+        let response = export("/export/atom?token=private").await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let atom = atom_syndication::Feed::read_from(&body[..]).unwrap();
+        assert_eq!("Articles", atom.title().as_str());
+        assert_eq!(2, atom.entries().len());
+    }
+
+    #[tokio::test]
+    async fn test_epub_export() {
+        use std::io::Read;
+
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap();
+        request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+
+        let export = |query: &str| {
+            let request = Request::builder()
+                .uri(format!("/export/epub?feed_id={feed_id}&token=private{query}"))
+                .body(Body::empty())
+                .unwrap();
+            let response = app.router.clone().oneshot(request);
+            async {
+                let response = response.await.unwrap();
+                assert_eq!(StatusCode::OK, response.status());
+                assert_eq!("application/epub+zip", response.headers()[header::CONTENT_TYPE]);
+                assert_eq!(
+                    "attachment; filename=\"Articles_from_Test_Feed.epub\"",
+                    response.headers()[header::CONTENT_DISPOSITION]
+                );
+                let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                zip::ZipArchive::new(std::io::Cursor::new(body.to_vec())).unwrap()
+            }
+        };
+        let read = |archive: &mut zip::ZipArchive<_>, name: &str| {
+            let mut content = String::new();
+            archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+            content
+        };
+
+        // The book requires the export token, which the link of the article list includes
+        let uri = format!("/export/epub?feed_id={feed_id}");
+        let request = Request::builder().uri(&uri).body(Body::empty()).unwrap();
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+        let request = Request::builder().uri(format!("/feed/{feed_id}")).body(Body::empty());
+        let response = app.router.clone().oneshot(request.unwrap()).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let page = String::from_utf8(body.to_vec()).unwrap();
+        assert!(page.contains(&format!(r#"href="{uri}&token=private""#)));
+
+        // The chapters go from the oldest article to the most recent one
+        let mut epub = export("").await;
+        assert!(read(&mut epub, "OEBPS/chapter-1.xhtml").contains("Second article"));
+        let chapter = read(&mut epub, "OEBPS/chapter-2.xhtml");
+        assert!(chapter.contains("<h1>First article</h1>"));
+        assert!(chapter.contains("Content of the first article"));
+        let navigation = read(&mut epub, "OEBPS/nav.xhtml");
+        assert!(navigation.contains("Second article"));
+        assert!(navigation.contains("First article"));
+
+        let mut epub = export("&from=2024-01-02&to=2024-01-02").await;
+        assert!(read(&mut epub, "OEBPS/chapter-1.xhtml").contains("First article"));
+        assert!(epub.by_name("OEBPS/chapter-2.xhtml").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_digests() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+        let (_, other_feed) = request(
+            &app,
+            Method::POST,
+            "/api/v1/feeds",
+            Some(json!({ "url": format!("{feed_url}?other") })),
+        )
+        .await;
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap();
+
+        let send = |method: Method, uri: &str, body: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body.to_owned()))
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+
+        let response = send(Method::POST, "/config/digest", "hour=24").await.unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+        let response = send(
+            Method::POST,
+            "/config/digest",
+            &format!("enabled=on&hour=7&editions=1&mark_as_read=on&feed_ids={feed_id}"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let (_, config) = request(&app, Method::GET, "/api/v1/config", None).await;
+        assert_eq!(json!([feed_id]), config["digest"]["feed_ids"]);
+        assert_eq!(7, config["digest"]["hour"]);
+
+        let response = send(Method::POST, "/digests/create", "").await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let digests = app.state.digest_service.get_digest_list().await.unwrap();
+        assert_eq!(1, digests.len());
+        assert_eq!(2, digests[0].article_count);
+
+        // Only the selected feed is included, and its articles are marked as read
+        let (_, articles) =
+            request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+        assert!(articles.as_array().unwrap().iter().all(|a| a["read"] == true));
+        let other_uri = format!("/api/v1/feeds/{}/articles", other_feed["id"].as_str().unwrap());
+        let (_, articles) = request(&app, Method::GET, &other_uri, None).await;
+        assert!(articles.as_array().unwrap().iter().all(|a| a["read"] == false));
+
+        let response = send(Method::GET, "/digests", "").await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8(body.to_vec()).unwrap().contains(&digests[0].title));
+
+        let digest_uri = format!("/digests/{}", digests[0].id);
+        let response = send(Method::GET, &digest_uri, "").await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("application/epub+zip", response.headers()[header::CONTENT_TYPE]);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let mut epub = zip::ZipArchive::new(std::io::Cursor::new(body.to_vec())).unwrap();
+        assert!(epub.by_name("OEBPS/chapter-2.xhtml").is_ok());
+
+        // Without new articles no digest is created
+        send(Method::POST, "/digests/create", "").await.unwrap();
+        assert_eq!(1, app.state.digest_service.get_digest_list().await.unwrap().len());
+
+        let response = send(Method::DELETE, &digest_uri, "").await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        assert!(app.state.digest_service.get_digest_list().await.unwrap().is_empty());
+        let response = send(Method::GET, &digest_uri, "").await.unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_send_to_device() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+        let (smtp_port, received) = serve_smtp().await;
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap();
+        let (_, articles) =
+            request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+        let article_id = articles[0]["id"].as_str().unwrap();
+        let send_uri = format!("/feed/{feed_id}/article/{article_id}/send");
+
+        let send = |method: Method, uri: &str, body: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body.to_owned()))
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+        let configure = |port: u16| {
+            send(
+                Method::POST,
+                "/config/smtp",
+                &format!(
+                    "host=127.0.0.1&port={port}&security=none&username=&password=secret\
+                    &from=reader%40example.com&recipient=device%40kindle.com"
+                ),
+            )
+        };
+
+        // Nothing is queued until the server is configured
+        let response = send(Method::POST, &send_uri, "").await.unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+
+        let response = configure(smtp_port).await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let (_, config) = request(&app, Method::GET, "/api/v1/config", None).await;
+        assert_eq!("127.0.0.1", config["smtp"]["host"]);
+        assert_eq!("", config["smtp"]["password"]);
+
+        let response = send(Method::POST, &send_uri, "").await.unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let response = send(Method::GET, "/outbox", "").await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8(body.to_vec()).unwrap().contains("First article"));
+
+        assert_eq!(1, app.state.email_service.deliver_pending().await.unwrap());
+        let message = received.lock().unwrap().pop().unwrap();
+        assert!(message.contains("From: reader@example.com"));
+        assert!(message.contains("To: device@kindle.com"));
+        assert!(message.contains("Subject: First article"));
+        assert!(message.contains("Content-Type: application/epub+zip"));
+        assert!(message.contains("filename=\"First_article.epub\""));
+        let deliveries = app.state.email_service.get_delivery_log().await.unwrap();
+        assert_eq!(DeliveryStatus::Delivered, deliveries[0].status);
+        assert!(!std::path::Path::new(&deliveries[0].file_path).exists());
+
+        send(Method::POST, "/digests/create", "").await.unwrap();
+        let digests = app.state.digest_service.get_digest_list().await.unwrap();
+        let response = send(Method::POST, &format!("/digests/{}/send", digests[0].id), "")
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        assert_eq!(1, app.state.email_service.deliver_pending().await.unwrap());
+        let message = received.lock().unwrap().pop().unwrap();
+        assert!(message.contains(&format!("Subject: {}", digests[0].title)));
+
+        // The email stays in the outbox to be retried when the server can not be reached
+        let closed_port = {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        configure(closed_port).await.unwrap();
+        send(Method::POST, &send_uri, "").await.unwrap();
+        assert_eq!(0, app.state.email_service.deliver_pending().await.unwrap());
+        let deliveries = app.state.email_service.get_delivery_log().await.unwrap();
+        assert_eq!(DeliveryStatus::Pending, deliveries[0].status);
+        assert_eq!(1, deliveries[0].attempts);
+        assert!(deliveries[0].error.is_some());
+        assert!(std::path::Path::new(&deliveries[0].file_path).exists());
+
+        // The settings are escaped in the outbox page
+        let body = "host=127.0.0.1&port=1&security=none&username=%22%3E%3Cb%3Eme";
+        send(Method::POST, "/config/smtp", body).await.unwrap();
+        let response = send(Method::GET, "/outbox", "").await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let page = String::from_utf8(body.to_vec()).unwrap();
+        assert!(page.contains(r#"value="&quot;&gt;&lt;b&gt;me""#));
+        assert!(!page.contains("<b>me"));
+    }
+
+    #[tokio::test]
+    async fn test_save_to() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+
+        // Local Wallabag server and read-it-later endpoint that record the saved articles
+        let received = Arc::new(Mutex::new(vec![]));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = Router::new()
+            .route(
+                "/oauth/v2/token",
+                post(|Form(form): Form<HashMap<String, String>>| async move {
+                    assert_eq!("password", form["grant_type"]);
+                    assert_eq!("wallabag-secret", form["client_secret"]);
+                    json!({ "access_token": "token-123" }).to_string()
+                }),
+            )
+            .route(
+                "/api/entries.json",
+                post({
+                    let received = received.clone();
+                    move |headers: HeaderMap, Form(form): Form<HashMap<String, String>>| {
+                        let received = received.clone();
+                        async move {
+                            assert_eq!("Bearer token-123", headers[header::AUTHORIZATION]);
+                            received.lock().unwrap().push(form["url"].clone());
+                            json!({ "id": 42 }).to_string()
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/save",
+                post({
+                    let received = received.clone();
+                    move |headers: HeaderMap, body: String| {
+                        let received = received.clone();
+                        async move {
+                            assert_eq!("Bearer endpoint-token", headers[header::AUTHORIZATION]);
+                            let body: Value = serde_json::from_str(&body).unwrap();
+                            received.lock().unwrap().push(body["url"].to_string());
+                            StatusCode::CREATED
+                        }
+                    }
+                }),
+            );
+        tokio::spawn(async move { axum::serve(listener, server).await });
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap();
+        let (_, articles) =
+            request(&app, Method::GET, &format!("/api/v1/feeds/{feed_id}/articles"), None).await;
+        let article_id = articles[0]["id"].as_str().unwrap();
+        let article_uri = format!("/feed/{feed_id}/article/{article_id}");
+
+        let send = |method: Method, uri: &str, body: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body.to_owned()))
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+
+        // Nothing is saved until the services are configured
+        let response = send(Method::POST, &format!("{article_uri}/save/wallabag"), "")
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+
+        let response = send(
+            Method::POST,
+            "/config/wallabag",
+            &format!(
+                "url={server_url}&client_id=reader&client_secret=wallabag-secret\
+                &username=nico&password=hunter2"
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let response = send(
+            Method::POST,
+            "/config/save_endpoint",
+            &format!("url={server_url}/save&token=endpoint-token"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        let response = send(Method::POST, "/config/save_endpoint", "url=ftp://example.com")
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+
+        let (_, config) = request(&app, Method::GET, "/api/v1/config", None).await;
+        assert_eq!("reader", config["wallabag"]["client_id"]);
+        assert_eq!("", config["wallabag"]["client_secret"]);
+        assert_eq!("", config["wallabag"]["password"]);
+        assert_eq!("", config["save_endpoint"]["token"]);
+
+        // The secrets are kept when the settings are saved without them
+        let update = format!("url={server_url}&client_id=reader&username=nico");
+        send(Method::POST, "/config/wallabag", &update).await.unwrap();
+
+        for target in ["wallabag", "endpoint"] {
+            let response = send(Method::POST, &format!("{article_uri}/save/{target}"), "")
+                .await
+                .unwrap();
+            assert_eq!(StatusCode::SEE_OTHER, response.status());
+        }
+        assert_eq!(2, received.lock().unwrap().len());
+
+        let response = send(Method::GET, &article_uri, "").await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let link = format!(r#"href="{server_url}/view/42""#).replace('/', "&#x2f;");
+        assert!(body.contains(&link));
+        assert!(body.contains("read-it-later endpoint"));
+
+        let response = send(Method::GET, "/save_to", "").await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_webhooks() {
+        let app = build_app().await;
+        let feed_url = serve_feed().await;
+
+        // Local listener that records the calls to the webhook
+        let received = Arc::new(Mutex::new(vec![]));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let hook_url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let receiver = Router::new()
+            .route(
+                "/hook",
+                post(|headers: HeaderMap, body: String| async move {
+                    (headers, body)
+                }),
+            )
+            .layer(map_request({
+                let received = received.clone();
+                move |request: Request<Body>| {
+                    let received = received.clone();
+                    async move {
+                        let (parts, body) = request.into_parts();
+                        let body = to_bytes(body, usize::MAX).await.unwrap();
+                        received.lock().unwrap().push((
+                            parts.headers.clone(),
+                            String::from_utf8(body.to_vec()).unwrap(),
+                        ));
+                        Request::from_parts(parts, Body::from(body))
+                    }
+                }
+            }));
+        tokio::spawn(async move { axum::serve(listener, receiver).await });
+
+        let add_webhook = |body: String| {
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/webhooks/add")
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body))
+                .unwrap();
+            app.router.clone().oneshot(request)
+        };
+        let response = add_webhook(format!("url={hook_url}&feed_id=&category_id=&secret=s3cret"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::SEE_OTHER, response.status());
+        // Nothing listens in the port 1, so its deliveries fail
+        add_webhook("url=http://127.0.0.1:1/hook?to=%3Cb%3Eme&secret=".to_owned())
+            .await
+            .unwrap();
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        // The articles are added (and the webhooks queued) when the feed is checked
+        let articles_uri = format!("/api/v1/feeds/{}/articles", feed["id"].as_str().unwrap());
+        request(&app, Method::GET, &articles_uri, None).await;
+
+        let delivered = app.state.webhook_service.deliver_pending().await.unwrap();
+        assert_eq!(1, delivered);
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(1, received.len());
+        let (headers, body) = &received[0];
+        assert_eq!(
+            sign_payload("s3cret", body),
+            headers[SIGNATURE_HEADER].to_str().unwrap()
+        );
+        assert!(headers.contains_key(DELIVERY_HEADER));
+        let payload: Value = serde_json::from_str(body).unwrap();
+        assert_eq!("new_articles", payload["event"]);
+        assert_eq!(feed["id"], payload["feed"]["id"]);
+        assert_eq!(2, payload["articles"].as_array().unwrap().len());
+        assert_eq!("First article", payload["articles"][0]["title"]);
+
+        // The failed delivery waits to be retried
+        assert_eq!(0, app.state.webhook_service.deliver_pending().await.unwrap());
+        let deliveries = app.state.webhook_service.get_delivery_log().await.unwrap();
+        assert_eq!(2, deliveries.len());
+        let failed = deliveries
+            .iter()
+            .find(|delivery| delivery.response_status.is_none())
+            .unwrap();
+        assert_eq!(1, failed.attempts);
+        assert!(failed.error.is_some());
+
+        let request = Request::builder()
+            .uri("/webhooks")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let page = String::from_utf8(body.to_vec()).unwrap();
+        assert!(page.contains(&hook_url.replace('/', "&#x2f;")));
+        assert!(page.contains("delivered, 1 attempt"));
+        assert!(page.contains("retrying on"));
+        assert!(page.contains("&#x2f;hook?to=&lt;b&gt;me"));
+        assert!(!page.contains("<b>me"));
+
+        let webhooks = app.state.webhook_service.get_webhook_list().await.unwrap();
+        for webhook in webhooks {
+            let request = Request::builder()
+                .method(Method::DELETE)
+                .uri(format!("/webhooks/{}", webhook.id))
+                .body(Body::empty())
+                .unwrap();
+            app.router.clone().oneshot(request).await.unwrap();
+        }
+        assert!(app.state.webhook_service.get_webhook_list().await.unwrap().is_empty());
+        assert!(app.state.webhook_service.get_delivery_log().await.unwrap().is_empty());
+    }
+
+    /// RSS feed that advertises a WebSub hub, with the given extra items
+    fn websub_rss(hub_url: &str, feed_url: &str, items: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+                <channel>
+                    <title>Pushed Feed</title>
+                    <link>http://example.com</link>
+                    <description>A feed with a hub</description>
+                    <atom:link rel="hub" href="{hub_url}"/>
+                    <atom:link rel="self" href="{feed_url}"/>
+                    {items}
+                    <item>
+                        <title>First article</title>
+                        <link>http://example.com/first</link>
+                        <guid>first</guid>
+                        <pubDate>Tue, 02 Jan 2024 10:00:00 GMT</pubDate>
+                        <description>Content of the first article</description>
+                    </item>
+                </channel>
+            </rss>"#
+        )
+    }
+
+    fn websub_item(title: &str) -> String {
+        format!(
+            "<item><title>{title}</title><link>http://example.com/{title}</link>\
+            <guid>{title}</guid><pubDate>Wed, 03 Jan 2024 10:00:00 GMT</pubDate>\
+            <description>Pushed content</description></item>"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_websub() {
+        use hmac::{Hmac, Mac};
+
+        let app = build_app().await;
+
+        // Local listener that serves the feed and records the subscription requests to its hub
+        let requests = Arc::new(Mutex::new(vec![]));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let hub_url = format!("http://{address}/hub");
+        let feed_url = format!("http://{address}/feed.xml");
+        let rss = websub_rss(&hub_url, &feed_url, "");
+        let hub = Router::new()
+            .route(
+                "/feed.xml",
+                get(|| async move { ([(header::CONTENT_TYPE, "application/rss+xml")], rss) }),
+            )
+            .route(
+                "/hub",
+                post({
+                    let requests = requests.clone();
+                    move |Form(form): Form<HashMap<String, String>>| async move {
+                        requests.lock().unwrap().push(form);
+                        StatusCode::ACCEPTED
+                    }
+                }),
+            );
+        tokio::spawn(async move { axum::serve(listener, hub).await });
+
+        let (_, feed) =
+            request(&app, Method::POST, "/api/v1/feeds", Some(json!({ "url": feed_url }))).await;
+        let feed_id = feed["id"].as_str().unwrap().to_owned();
+        let articles_uri = format!("/api/v1/feeds/{feed_id}/articles");
+        request(&app, Method::GET, &articles_uri, None).await;
+
+        app.state.websub_service.request_subscriptions().await.unwrap();
+        let form = requests.lock().unwrap()[0].clone();
+        assert_eq!(
+            format!("http://reader.example.com/websub/{feed_id}"),
+            form["hub.callback"]
+        );
+        assert_eq!("subscribe", form["hub.mode"]);
+        assert_eq!(feed_url, form["hub.topic"]);
+        let secret = form["hub.secret"].clone();
+
+        // It is not requested again while it waits for the verification
+        app.state.websub_service.request_subscriptions().await.unwrap();
+        assert_eq!(1, requests.lock().unwrap().len());
+
+        let send = |method: Method, uri: String, signature: Option<String>, body: String| {
+            let mut request = Request::builder().method(method).uri(uri);
+            if let Some(signature) = signature {
+                request = request.header("X-Hub-Signature", signature);
+            }
+            app.router
+                .clone()
+                .oneshot(request.body(Body::from(body)).unwrap())
+        };
+
+        let verification = |topic: &str| {
+            format!(
+                "/websub/{feed_id}?hub.mode=subscribe&hub.topic={topic}\
+                &hub.challenge=c4allenge&hub.lease_seconds=3600"
+            )
+        };
+        // A verification that does not match the subscription gets a bare 404, without the
+        // challenge
+        let mismatches = [
+            verification("http://other.com"),
+            verification(&feed_url).replace("subscribe", "unsubscribe"),
+            format!("/websub/{feed_id}?hub.mode=denied&hub.topic=http://other.com"),
+            format!("/websub/{}?hub.mode=denied&hub.topic={feed_url}", Uuid::new_v4()),
+        ];
+        for uri in mismatches {
+            let response = send(Method::GET, uri.clone(), None, String::new()).await.unwrap();
+            assert_eq!(StatusCode::NOT_FOUND, response.status(), "{uri}");
+            assert!(response.headers().get(header::CONTENT_TYPE).is_none(), "{uri}");
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            assert!(body.is_empty(), "{uri}");
+        }
+
+        let response = send(Method::GET, verification(&feed_url), None, String::new())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&b"c4allenge"[..], &body[..]);
+
+        // Content with an invalid signature is acknowledged but ignored
+        let pushed = websub_rss(&hub_url, &feed_url, &websub_item("forged"));
+        let response = send(
+            Method::POST,
+            format!("/websub/{feed_id}"),
+            Some("sha1=0000".to_owned()),
+            pushed,
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::ACCEPTED, response.status());
+
+        let pushed = websub_rss(&hub_url, &feed_url, &websub_item("pushed"));
+        let mut mac = Hmac::<sha1::Sha1>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(pushed.as_bytes());
+        let signature = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        let response = send(
+            Method::POST,
+            format!("/websub/{feed_id}"),
+            Some(format!("sha1={signature}")),
+            pushed,
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::ACCEPTED, response.status());
+
+        let (_, articles) = request(&app, Method::GET, &articles_uri, None).await;
+        let titles = articles
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|article| article["title"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(titles.contains(&"pushed"));
+        assert!(!titles.contains(&"forged"));
+
+        let response = send(
+            Method::POST,
+            format!("/websub/{}", Uuid::new_v4()),
+            None,
+            String::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    }
+}
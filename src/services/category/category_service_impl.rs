@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use super::{error::CategoryServiceError, CategoryService, Result};
+use crate::{
+    models::{article::Article, category::Category},
+    repositories::category::CategoryRepository,
+};
+
+pub struct CategoryServiceImpl<CR>
+where
+    CR: CategoryRepository,
+{
+    category_repository: Arc<CR>,
+}
+
+impl<CR> CategoryServiceImpl<CR>
+where
+    CR: CategoryRepository,
+{
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    /// Validates the name and checks that no other category is already using it
+    async fn validate_name(&self, name: &str, category_id: Option<Uuid>) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CategoryServiceError::EmptyName);
+        }
+
+        match self.category_repository.get_category_by_name(name).await? {
+            Some(category) if Some(category.id) != category_id => {
+                Err(CategoryServiceError::AlreadyExists(name.to_owned()))
+            }
+            _ => Ok(name.to_owned()),
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> CategoryService for CategoryServiceImpl<CR>
+where
+    CR: CategoryRepository,
+{
+    async fn get_category_list(&self) -> Result<Vec<Category>> {
+        Ok(self.category_repository.get_category_list().await?)
+    }
+
+    async fn add_category(&self, name: &str) -> Result<Category> {
+        let name = self.validate_name(name, None).await?;
+
+        let category = Category {
+            id: Uuid::new_v4(),
+            numeric_id: 0,
+            name,
+            unread_count: 0,
+        };
+
+        self.category_repository.add_category(&category).await?;
+
+        Ok(category)
+    }
+
+    async fn rename_category(&self, category_id: Uuid, name: &str) -> Result<()> {
+        if self
+            .category_repository
+            .get_category(category_id)
+            .await?
+            .is_none()
+        {
+            return Err(CategoryServiceError::CategoryNotFound(category_id));
+        }
+
+        let name = self.validate_name(name, Some(category_id)).await?;
+
+        Ok(self
+            .category_repository
+            .rename_category(category_id, &name)
+            .await?)
+    }
+
+    async fn delete_category(&self, category_id: Uuid) -> Result<()> {
+        if self
+            .category_repository
+            .get_category(category_id)
+            .await?
+            .is_none()
+        {
+            return Err(CategoryServiceError::CategoryNotFound(category_id));
+        }
+
+        Ok(self.category_repository.delete_category(category_id).await?)
+    }
+
+    async fn get_category_articles(&self, category_id: Uuid) -> Result<(Category, Vec<Article>)> {
+        let category = self
+            .category_repository
+            .get_category(category_id)
+            .await?
+            .ok_or(CategoryServiceError::CategoryNotFound(category_id))?;
+
+        let mut articles = self
+            .category_repository
+            .get_category_articles(category_id)
+            .await?;
+
+        articles.sort_by_key(|a| std::cmp::Reverse(a.published_at));
+
+        Ok((category, articles))
+    }
+}
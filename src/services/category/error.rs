@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CategoryServiceError {
+    #[error("the category {0} was not found")]
+    CategoryNotFound(Uuid),
+
+    #[error("a category named \"{0}\" already exists")]
+    AlreadyExists(String),
+
+    #[error("the category name can not be empty")]
+    EmptyName,
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<CategoryServiceError> for ApiError {
+    fn from(error: CategoryServiceError) -> Self {
+        match error {
+            e @ CategoryServiceError::CategoryNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e @ (CategoryServiceError::AlreadyExists(_) | CategoryServiceError::EmptyName) => {
+                Self {
+                    original_error: e.into(),
+                    status_code: StatusCode::BAD_REQUEST,
+                }
+            }
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
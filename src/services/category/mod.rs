@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod category_service_impl;
+mod error;
+
+use axum::async_trait;
+
+pub use category_service_impl::CategoryServiceImpl;
+pub use error::CategoryServiceError;
+use uuid::Uuid;
+
+use crate::models::{article::Article, category::Category};
+
+pub(crate) type Result<T> = std::result::Result<T, CategoryServiceError>;
+
+#[async_trait]
+pub(crate) trait CategoryService: Sync + Send {
+    async fn get_category_list(&self) -> Result<Vec<Category>>;
+
+    async fn add_category(&self, name: &str) -> Result<Category>;
+
+    async fn rename_category(&self, category_id: Uuid, name: &str) -> Result<()>;
+
+    async fn delete_category(&self, category_id: Uuid) -> Result<()>;
+
+    /// Returns the category together with the articles of all its feeds, the most recent first
+    async fn get_category_articles(&self, category_id: Uuid) -> Result<(Category, Vec<Article>)>;
+}
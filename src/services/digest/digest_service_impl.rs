@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+use chrono::{DateTime, Local, Utc};
+use uuid::Uuid;
+
+use super::{error::DigestServiceError, DigestService, Result, MAX_DIGEST_ARTICLES};
+use crate::{
+    models::{article_filter::ArticleFilter, digest::Digest},
+    providers::{epub::EpubProvider, persisted_config::PersistedConfigProvider},
+    repositories::digest::DigestRepository,
+    services::feed::FeedService,
+};
+
+pub struct DigestServiceImpl<DR, FS, PCP, EP> {
+    digest_repository: Arc<DR>,
+    feed_service: Arc<FS>,
+    persisted_config_provider: Arc<PCP>,
+    epub_provider: Arc<EP>,
+    /// Last scheduled time a digest was attempted for, so it is not retried when there were no
+    /// articles to include
+    last_scheduled_attempt: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl<DR, FS, PCP, EP> DigestServiceImpl<DR, FS, PCP, EP> {
+    pub fn new(
+        digest_repository: Arc<DR>,
+        feed_service: Arc<FS>,
+        persisted_config_provider: Arc<PCP>,
+        epub_provider: Arc<EP>,
+    ) -> Self {
+        Self {
+            digest_repository,
+            feed_service,
+            persisted_config_provider,
+            epub_provider,
+            last_scheduled_attempt: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl<DR, FS, PCP, EP> DigestService for DigestServiceImpl<DR, FS, PCP, EP>
+where
+    DR: DigestRepository,
+    FS: FeedService,
+    PCP: PersistedConfigProvider,
+    EP: EpubProvider,
+{
+    async fn get_digest_list(&self) -> Result<Vec<Digest>> {
+        Ok(self.digest_repository.get_digest_list().await?)
+    }
+
+    async fn get_digest_file(&self, digest_id: Uuid) -> Result<(Digest, Vec<u8>)> {
+        let digest = self
+            .digest_repository
+            .get_digest(digest_id)
+            .await?
+            .ok_or(DigestServiceError::DigestNotFound(digest_id))?;
+        let epub = self.digest_repository.get_digest_file(&digest).await?;
+
+        Ok((digest, epub))
+    }
+
+    async fn create_digest(&self) -> Result<Option<Digest>> {
+        let config = self.persisted_config_provider.get_configuration().await?.digest;
+        let previous_digests = self.digest_repository.get_digest_list().await?;
+        let now = Utc::now();
+
+        let feeds = self
+            .feed_service
+            .get_feed_list()
+            .await?
+            .into_iter()
+            .filter(|feed| config.includes(feed))
+            .collect::<Vec<_>>();
+        if feeds.is_empty() {
+            return Ok(None);
+        }
+
+        // The articles are only fetched when the feeds are checked
+        for feed in &feeds {
+            if let Err(e) = self.feed_service.get_channel(feed.id).await {
+                tracing::warn!("unable to check the feed {} for the digest: {e}", feed.title);
+            }
+        }
+
+        // The articles found since the previous digest, even if they were published before it
+        let filter = ArticleFilter {
+            feed_ids: Some(feeds.iter().map(|feed| feed.id).collect()),
+            read: Some(false),
+            found_after: previous_digests.first().map(|digest| digest.created_at),
+            limit: Some(MAX_DIGEST_ARTICLES),
+            ..Default::default()
+        };
+        let mut articles = self.feed_service.get_articles(&filter).await?;
+        if articles.is_empty() {
+            return Ok(None);
+        }
+
+        // Like a newspaper, the articles are grouped by feed, the oldest first
+        articles.sort_by_key(|article| article.published_at);
+        let mut chapters = vec![];
+        for feed in &feeds {
+            let (feed_articles, rest): (Vec<_>, Vec<_>) = articles
+                .into_iter()
+                .partition(|article| article.feed_id == feed.id);
+            articles = rest;
+
+            for article in feed_articles {
+                let content = self
+                    .feed_service
+                    .get_cached_content(feed.id, article.id)
+                    .await?;
+                let html = article.content_or_summary_html(content);
+
+                chapters.push((article, feed.clone(), html));
+            }
+        }
+
+        let title = format!("Digest {}", now.with_timezone(&Local).format("%Y-%m-%d %H:%M"));
+        let epub = self.epub_provider.build_epub(&title, &chapters).await?;
+
+        let mut digest = Digest {
+            id: Uuid::new_v4(),
+            title,
+            created_at: now,
+            article_count: chapters.len(),
+            file_path: String::new(),
+        };
+        self.digest_repository.add_digest(&mut digest, &epub).await?;
+
+        if config.mark_as_read {
+            for (article, _, _) in &chapters {
+                self.feed_service
+                    .mark_article_as_read(article.feed_id, article.id)
+                    .await?;
+            }
+        }
+
+        // The new digest is the first one
+        for old_digest in previous_digests
+            .iter()
+            .skip(config.editions.saturating_sub(1))
+        {
+            self.digest_repository.delete_digest(old_digest).await?;
+        }
+
+        Ok(Some(digest))
+    }
+
+    async fn create_scheduled_digest(&self) -> Result<Option<Digest>> {
+        let config = self.persisted_config_provider.get_configuration().await?.digest;
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let scheduled = config.last_scheduled_time(Local::now());
+        let last_digest = self
+            .digest_repository
+            .get_digest_list()
+            .await?
+            .first()
+            .map(|digest| digest.created_at);
+        let last_attempt = *self
+            .last_scheduled_attempt
+            .lock()
+            .expect("the last digest attempt lock is poisoned");
+
+        if last_digest.max(last_attempt).is_some_and(|last| last >= scheduled) {
+            return Ok(None);
+        }
+
+        *self
+            .last_scheduled_attempt
+            .lock()
+            .expect("the last digest attempt lock is poisoned") = Some(scheduled);
+        self.create_digest().await
+    }
+
+    async fn delete_digest(&self, digest_id: Uuid) -> Result<()> {
+        let digest = self
+            .digest_repository
+            .get_digest(digest_id)
+            .await?
+            .ok_or(DigestServiceError::DigestNotFound(digest_id))?;
+
+        Ok(self.digest_repository.delete_digest(&digest).await?)
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    controllers::ApiError,
+    providers::{epub::EpubProviderError, persisted_config::PersistedConfigProviderError},
+    repositories::RepositoryError,
+    services::feed::FeedServiceError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DigestServiceError {
+    #[error("the digest {0} was not found")]
+    DigestNotFound(Uuid),
+
+    #[error("a feed error ocurred: {0}")]
+    Feed(#[from] FeedServiceError),
+
+    #[error("unable to read the settings: {0}")]
+    Config(#[from] PersistedConfigProviderError),
+
+    #[error("unable to build the EPUB: {0}")]
+    Epub(#[from] EpubProviderError),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<DigestServiceError> for ApiError {
+    fn from(error: DigestServiceError) -> Self {
+        match error {
+            e @ DigestServiceError::DigestNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            DigestServiceError::Feed(e) => e.into(),
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Daily digest: an EPUB book with the unread articles of the selected feeds, created at the
+//! hour set in the settings
+mod digest_service_impl;
+mod error;
+
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use uuid::Uuid;
+
+pub use digest_service_impl::DigestServiceImpl;
+pub use error::DigestServiceError;
+
+use crate::models::digest::Digest;
+
+pub(crate) type Result<T> = std::result::Result<T, DigestServiceError>;
+
+/// Maximum number of articles included in a digest
+pub const MAX_DIGEST_ARTICLES: usize = 200;
+
+/// How often the schedule of the digest is checked
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+#[async_trait]
+pub(crate) trait DigestService: Sync + Send {
+    /// Returns the digests, the most recent first
+    async fn get_digest_list(&self) -> Result<Vec<Digest>>;
+
+    /// Returns the digest with the content of its EPUB file
+    async fn get_digest_file(&self, digest_id: Uuid) -> Result<(Digest, Vec<u8>)>;
+
+    /// Checks the selected feeds for new articles and creates a digest with the unread ones
+    /// found since the previous digest. Returns None if there are no articles to include
+    async fn create_digest(&self) -> Result<Option<Digest>>;
+
+    /// Creates the digest if it is enabled and the one of its scheduled time was not created yet
+    async fn create_scheduled_digest(&self) -> Result<Option<Digest>>;
+
+    async fn delete_digest(&self, digest_id: Uuid) -> Result<()>;
+}
+
+/// Creates the digests at their scheduled time. It never returns, so it must be spawned
+pub(crate) async fn run_digest_schedule<DS: DigestService>(service: Arc<DS>) {
+    let mut interval = tokio::time::interval(SCHEDULE_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        match service.create_scheduled_digest().await {
+            Ok(Some(digest)) => tracing::info!("created the digest {}", digest.title),
+            Ok(None) => {}
+            Err(e) => tracing::error!("there was an error creating the digest: {e:?}"),
+        }
+    }
+}
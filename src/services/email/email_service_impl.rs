@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+use super::{EmailService, EmailServiceError, Result, OUTBOX_LOG_DAYS, OUTBOX_LOG_SIZE};
+use crate::{
+    models::{email_delivery::EmailDelivery, webhook::DeliveryStatus},
+    providers::{
+        email::EmailProvider, epub::EpubProvider, persisted_config::PersistedConfigProvider,
+    },
+    repositories::{digest::DigestRepository, email_delivery::EmailDeliveryRepository},
+    services::feed::{FeedService, FeedServiceError},
+};
+
+pub struct EmailServiceImpl<EDR, DR, FS, PCP, EP, EMP> {
+    email_delivery_repository: Arc<EDR>,
+    digest_repository: Arc<DR>,
+    feed_service: Arc<FS>,
+    persisted_config_provider: Arc<PCP>,
+    epub_provider: Arc<EP>,
+    email_provider: Arc<EMP>,
+}
+
+impl<EDR, DR, FS, PCP, EP, EMP> EmailServiceImpl<EDR, DR, FS, PCP, EP, EMP> {
+    pub fn new(
+        email_delivery_repository: Arc<EDR>,
+        digest_repository: Arc<DR>,
+        feed_service: Arc<FS>,
+        persisted_config_provider: Arc<PCP>,
+        epub_provider: Arc<EP>,
+        email_provider: Arc<EMP>,
+    ) -> Self {
+        Self {
+            email_delivery_repository,
+            digest_repository,
+            feed_service,
+            persisted_config_provider,
+            epub_provider,
+            email_provider,
+        }
+    }
+}
+
+/// Name of the attached file, made of the ASCII alphanumeric characters of the title
+fn file_name(title: &str) -> String {
+    let name = title
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    format!("{name}.epub")
+}
+
+#[async_trait]
+impl<EDR, DR, FS, PCP, EP, EMP> EmailService for EmailServiceImpl<EDR, DR, FS, PCP, EP, EMP>
+where
+    EDR: EmailDeliveryRepository,
+    DR: DigestRepository,
+    FS: FeedService,
+    PCP: PersistedConfigProvider,
+    EP: EpubProvider,
+    EMP: EmailProvider,
+{
+    async fn get_delivery_log(&self) -> Result<Vec<EmailDelivery>> {
+        Ok(self
+            .email_delivery_repository
+            .get_delivery_list(OUTBOX_LOG_SIZE)
+            .await?)
+    }
+
+    async fn send_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<EmailDelivery> {
+        let config = self.persisted_config_provider.get_configuration().await?;
+        if !config.smtp.is_configured() {
+            return Err(EmailServiceError::NotConfigured);
+        }
+
+        let feed = self
+            .feed_service
+            .get_feed(feed_id)
+            .await?
+            .ok_or(FeedServiceError::FeedNotFound(feed_id))?;
+        let (article, html) = self.feed_service.get_item_content(feed_id, article_id).await?;
+
+        let title = article.title.clone();
+        let epub = self
+            .epub_provider
+            .build_epub(&title, &[(article, feed, html)])
+            .await?;
+
+        let mut delivery = EmailDelivery::new(title, Utc::now());
+        self.email_delivery_repository
+            .add_delivery(&mut delivery, &epub)
+            .await?;
+
+        Ok(delivery)
+    }
+
+    async fn send_digest(&self, digest_id: Uuid) -> Result<EmailDelivery> {
+        let config = self.persisted_config_provider.get_configuration().await?;
+        if !config.smtp.is_configured() {
+            return Err(EmailServiceError::NotConfigured);
+        }
+
+        let digest = self
+            .digest_repository
+            .get_digest(digest_id)
+            .await?
+            .ok_or(EmailServiceError::DigestNotFound(digest_id))?;
+        let epub = self.digest_repository.get_digest_file(&digest).await?;
+
+        let mut delivery = EmailDelivery::new(digest.title, Utc::now());
+        self.email_delivery_repository
+            .add_delivery(&mut delivery, &epub)
+            .await?;
+
+        Ok(delivery)
+    }
+
+    async fn deliver_pending(&self) -> Result<usize> {
+        let deliveries = self
+            .email_delivery_repository
+            .get_due_deliveries(Utc::now())
+            .await?;
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        // The emails wait in the outbox until the server is configured
+        let smtp = self.persisted_config_provider.get_configuration().await?.smtp;
+        if !smtp.is_configured() {
+            return Ok(0);
+        }
+
+        let mut delivered = 0;
+        for mut delivery in deliveries {
+            let result = match self
+                .email_delivery_repository
+                .get_delivery_file(&delivery)
+                .await
+            {
+                Ok(epub) => self
+                    .email_provider
+                    .send_epub(&smtp, &delivery.title, &file_name(&delivery.title), epub)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+
+            delivery.record_attempt(Utc::now(), result.err());
+            match delivery.status {
+                DeliveryStatus::Delivered => delivered += 1,
+                DeliveryStatus::Failed => tracing::warn!(
+                    "giving up sending {} by email: {:?}",
+                    delivery.title,
+                    delivery.error
+                ),
+                DeliveryStatus::Pending => {}
+            }
+
+            self.email_delivery_repository
+                .update_delivery(&delivery)
+                .await?;
+        }
+
+        Ok(delivered)
+    }
+
+    async fn prune_delivery_log(&self) -> Result<()> {
+        Ok(self
+            .email_delivery_repository
+            .delete_deliveries_before(Utc::now() - TimeDelta::days(OUTBOX_LOG_DAYS))
+            .await?)
+    }
+}
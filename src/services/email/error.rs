@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    controllers::ApiError,
+    providers::{epub::EpubProviderError, persisted_config::PersistedConfigProviderError},
+    repositories::RepositoryError,
+    services::feed::FeedServiceError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmailServiceError {
+    #[error("the SMTP server is not configured")]
+    NotConfigured,
+
+    #[error("the digest {0} was not found")]
+    DigestNotFound(Uuid),
+
+    #[error("a feed error ocurred: {0}")]
+    Feed(#[from] FeedServiceError),
+
+    #[error("unable to read the settings: {0}")]
+    Config(#[from] PersistedConfigProviderError),
+
+    #[error("unable to build the EPUB: {0}")]
+    Epub(#[from] EpubProviderError),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<EmailServiceError> for ApiError {
+    fn from(error: EmailServiceError) -> Self {
+        match error {
+            e @ EmailServiceError::NotConfigured => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e @ EmailServiceError::DigestNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            EmailServiceError::Feed(e) => e.into(),
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Delivery of documents to the e-reader by email (like Send to Kindle). The articles and the
+//! digests are queued in the outbox as EPUB files and this service sends them in the background
+mod email_service_impl;
+mod error;
+
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use uuid::Uuid;
+
+pub use email_service_impl::EmailServiceImpl;
+pub use error::EmailServiceError;
+
+use crate::models::email_delivery::EmailDelivery;
+
+pub(crate) type Result<T> = std::result::Result<T, EmailServiceError>;
+
+/// How often the outbox is checked for emails to send
+const OUTBOX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Emails shown in the outbox page
+pub const OUTBOX_LOG_SIZE: u32 = 50;
+
+/// Days the finished emails are kept
+pub const OUTBOX_LOG_DAYS: i64 = 30;
+
+#[async_trait]
+pub(crate) trait EmailService: Sync + Send {
+    /// Returns the most recent emails, the newest first
+    async fn get_delivery_log(&self) -> Result<Vec<EmailDelivery>>;
+
+    /// Queues an email with the article as an EPUB book
+    async fn send_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<EmailDelivery>;
+
+    /// Queues an email with the EPUB book of the digest
+    async fn send_digest(&self, digest_id: Uuid) -> Result<EmailDelivery>;
+
+    /// Sends the queued emails whose attempt is due and returns how many of them succeeded
+    async fn deliver_pending(&self) -> Result<usize>;
+
+    /// Deletes the finished emails older than `OUTBOX_LOG_DAYS`
+    async fn prune_delivery_log(&self) -> Result<()>;
+}
+
+/// Sends the queued emails periodically. It never returns, so it must be spawned
+pub(crate) async fn run_outbox<ES: EmailService>(service: Arc<ES>) {
+    let mut interval = tokio::time::interval(OUTBOX_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = service.deliver_pending().await {
+            tracing::error!("there was an error sending the emails of the outbox: {e:?}");
+        }
+
+        if let Err(e) = service.prune_delivery_log().await {
+            tracing::error!("there was an error pruning the outbox: {e:?}");
+        }
+    }
+}
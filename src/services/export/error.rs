@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    controllers::ApiError, providers::epub::EpubProviderError, repositories::RepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportServiceError {
+    #[error("the feed {0} was not found")]
+    FeedNotFound(Uuid),
+
+    #[error("the category {0} was not found")]
+    CategoryNotFound(Uuid),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+
+    #[error("unable to build the EPUB: {0}")]
+    Epub(#[from] EpubProviderError),
+}
+
+impl From<ExportServiceError> for ApiError {
+    fn from(error: ExportServiceError) -> Self {
+        match error {
+            e @ (ExportServiceError::FeedNotFound(_) | ExportServiceError::CategoryNotFound(_)) => {
+                Self {
+                    original_error: e.into(),
+                    status_code: StatusCode::NOT_FOUND,
+                }
+            }
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use super::{
+    error::ExportServiceError, ExportQuery, ExportService, ExportedArticles, Result,
+    DEFAULT_EXPORTED_ARTICLES, MAX_EXPORTED_ARTICLES,
+};
+use crate::{
+    config::Config,
+    models::article_filter::ArticleFilter,
+    providers::epub::EpubProvider,
+    repositories::{
+        category::CategoryRepository, feed::FeedRepository, feed_content::FeedContentRepository,
+    },
+};
+
+pub struct ExportServiceImpl<FR, FCR, CR, EP>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+    EP: EpubProvider,
+{
+    feed_repository: Arc<FR>,
+    feed_content_repository: Arc<FCR>,
+    category_repository: Arc<CR>,
+    epub_provider: Arc<EP>,
+    config: Arc<Config>,
+}
+
+impl<FR, FCR, CR, EP> ExportServiceImpl<FR, FCR, CR, EP>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+    EP: EpubProvider,
+{
+    pub fn new(
+        feed_repository: Arc<FR>,
+        feed_content_repository: Arc<FCR>,
+        category_repository: Arc<CR>,
+        epub_provider: Arc<EP>,
+        config: Arc<Config>,
+    ) -> Self {
+        Self {
+            feed_repository,
+            feed_content_repository,
+            category_repository,
+            epub_provider,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl<FR, FCR, CR, EP> ExportService for ExportServiceImpl<FR, FCR, CR, EP>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+    EP: EpubProvider,
+{
+    fn is_authorized(&self, token: Option<&str>) -> bool {
+        self.config
+            .export_token
+            .as_deref()
+            .is_none_or(|export_token| Some(export_token) == token)
+    }
+
+    fn token(&self) -> Option<&str> {
+        self.config.export_token.as_deref()
+    }
+
+    async fn get_articles(&self, query: &ExportQuery) -> Result<ExportedArticles> {
+        let feeds = self.feed_repository.get_feed_list().await?;
+
+        let mut title = match (query.starred, query.read) {
+            (Some(true), _) => "Starred articles",
+            (_, Some(false)) => "Unread articles",
+            (_, Some(true)) => "Read articles",
+            _ => "Articles",
+        }
+        .to_owned();
+        if let Some(tag) = &query.tag {
+            title.push_str(&format!(" tagged \"{tag}\""));
+        }
+
+        let mut feed_ids = None;
+        if let Some(feed_id) = query.feed_id {
+            let feed = feeds
+                .iter()
+                .find(|feed| feed.id == feed_id)
+                .ok_or(ExportServiceError::FeedNotFound(feed_id))?;
+            title.push_str(&format!(" from {}", feed.title));
+            feed_ids = Some(vec![feed_id]);
+        } else if let Some(category_id) = query.category_id {
+            let category = self
+                .category_repository
+                .get_category(category_id)
+                .await?
+                .ok_or(ExportServiceError::CategoryNotFound(category_id))?;
+            title.push_str(&format!(" in {}", category.name));
+            feed_ids = Some(
+                feeds
+                    .iter()
+                    .filter(|feed| feed.category_id == Some(category_id))
+                    .map(|feed| feed.id)
+                    .collect(),
+            );
+        }
+
+        let filter = ArticleFilter {
+            feed_ids,
+            read: query.read,
+            starred: query.starred,
+            tag: query.tag.clone(),
+            published_after: query.published_after,
+            published_before: query.published_before,
+            limit: Some(
+                query
+                    .limit
+                    .unwrap_or(DEFAULT_EXPORTED_ARTICLES)
+                    .min(MAX_EXPORTED_ARTICLES),
+            ),
+            ..Default::default()
+        };
+
+        let mut articles = vec![];
+        for article in self.feed_repository.get_articles(&filter).await? {
+            let Some(feed) = feeds.iter().find(|feed| feed.id == article.feed_id) else {
+                continue;
+            };
+
+            let content = self
+                .feed_content_repository
+                .get_article_content(article.feed_id, article.id)
+                .await?;
+            let html = article.content_or_summary_html(content);
+
+            articles.push((article, feed.clone(), html));
+        }
+
+        Ok(ExportedArticles { title, articles })
+    }
+
+    async fn get_epub(&self, query: &ExportQuery) -> Result<(String, Vec<u8>)> {
+        let mut exported = self.get_articles(query).await?;
+
+        // The most recent articles are selected, but they are read from the oldest one
+        exported.articles.reverse();
+        let epub = self
+            .epub_provider
+            .build_epub(&exported.title, &exported.articles)
+            .await?;
+
+        Ok((exported.title, epub))
+    }
+}
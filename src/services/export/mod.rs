@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Exports curated lists of articles (for example, the starred ones) so they can be read
+//! elsewhere
+mod error;
+mod export_service_impl;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use error::ExportServiceError;
+pub use export_service_impl::ExportServiceImpl;
+
+use crate::models::{article::Article, feed::Feed};
+
+pub(crate) type Result<T> = std::result::Result<T, ExportServiceError>;
+
+/// Number of articles exported when the query does not specify it
+pub const DEFAULT_EXPORTED_ARTICLES: usize = 50;
+
+/// Maximum number of articles exported
+pub const MAX_EXPORTED_ARTICLES: usize = 500;
+
+/// Articles to export. All the filters are optional, the ones that are set must match
+#[derive(Debug, Default)]
+pub struct ExportQuery {
+    pub starred: Option<bool>,
+    pub read: Option<bool>,
+    pub tag: Option<String>,
+    pub feed_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
+    pub limit: Option<usize>,
+}
+
+/// Exported articles, the most recent first, with a title that describes the query
+pub struct ExportedArticles {
+    pub title: String,
+    /// The articles with their feed and their HTML content (or their summary if it was not
+    /// downloaded)
+    pub articles: Vec<(Article, Feed, String)>,
+}
+
+#[async_trait]
+pub(crate) trait ExportService: Sync + Send {
+    /// Checks the token sent with an export request. If no token is configured, the exported
+    /// articles are public
+    fn is_authorized(&self, token: Option<&str>) -> bool;
+
+    /// Token that must be sent with the export requests, to build the links of the app
+    fn token(&self) -> Option<&str>;
+
+    async fn get_articles(&self, query: &ExportQuery) -> Result<ExportedArticles>;
+
+    /// Builds an EPUB book with the articles, the oldest first. Returns its title and content
+    async fn get_epub(&self, query: &ExportQuery) -> Result<(String, Vec<u8>)>;
+}
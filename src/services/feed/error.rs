@@ -3,7 +3,11 @@
 use reqwest::StatusCode;
 use uuid::Uuid;
 
-use crate::{controllers::ApiError, repositories::RepositoryError};
+use crate::{
+    controllers::ApiError,
+    repositories::RepositoryError,
+    services::feed_rules::FeedRulesServiceError,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum FeedServiceError {
@@ -19,6 +23,12 @@ pub enum FeedServiceError {
     #[error("the feed {0} was not found")]
     FeedNotFound(Uuid),
 
+    #[error("the category {0} was not found")]
+    CategoryNotFound(Uuid),
+
+    #[error("unable to get the rules of the feed: {0}")]
+    Rules(#[from] FeedRulesServiceError),
+
     #[error("a repository error ocurred: {0}")]
     Repository(#[from] RepositoryError),
 
@@ -42,6 +52,11 @@ impl From<FeedServiceError> for ApiError {
                 status_code: StatusCode::NOT_FOUND,
             },
 
+            e @ FeedServiceError::CategoryNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
             e => Self {
                 original_error: e.into(),
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
@@ -5,101 +5,210 @@ use super::FeedService;
 use super::Result;
 use crate::config::Config;
 use crate::models::article::Article;
+use crate::models::article_filter::ArticleFilter;
+use crate::models::enclosure::Enclosure;
+use crate::models::feed::ContentMode;
+use crate::models::filter_rule::FilterAction;
 use crate::models::parsed_feed::ParsedFeed;
 use crate::models::parsed_feed::ParsedItem;
+use crate::models::tag::ArticleTag;
+use crate::models::webhook::{WebhookDelivery, WebhookPayload};
+use crate::models::websub_subscription::WebSubSubscription;
+use crate::providers::charset::{decode_document, DocumentKind};
+use crate::providers::enclosure::EnclosureProvider;
 use crate::providers::favicon::FaviconProvider;
 use crate::providers::feed_parser::FeedParser;
 use crate::providers::html_processor::HtmlProcessor;
 use crate::providers::image_processor::ImageProcessor;
 use crate::providers::image_processor::ImageProcessorFsImpl;
+use crate::providers::image_processor::THUMBNAIL_EXTENSION;
+use crate::providers::persisted_config::PersistedConfigProvider;
+use crate::repositories::category::CategoryRepository;
+use crate::repositories::enclosure::EnclosureRepository;
 use crate::repositories::feed_content::FeedContentRepository;
+use crate::repositories::tag::TagRepository;
+use crate::repositories::webhook::WebhookRepository;
+use crate::repositories::websub_subscription::WebSubSubscriptionRepository;
+use crate::services::feed_rules::{FeedRules, FeedRulesService};
 use crate::{models::feed::Feed, repositories::feed::FeedRepository};
 use axum::async_trait;
-use axum::body::Bytes;
 use chrono::DateTime;
 use chrono::TimeDelta;
 use chrono::Utc;
-use reqwest::Url;
-use std::collections::HashSet;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Response, Url};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::task::JoinSet;
+use tokio::{sync::Mutex, task::JoinSet};
 use uuid::Uuid;
 
 type ArticleContent = String;
 
-pub struct FeedServiceImpl<FR, FCR, HP, FRP, FAP, FVP>
+/// An article created from a feed item, with its content (if downloaded), enclosures and tags
+type ProcessedItem = (Article, Option<ArticleContent>, Vec<Enclosure>, Vec<ArticleTag>);
+
+/// Maximum length of the teaser shown in the article lists
+const TEASER_MAX_LENGTH: usize = 200;
+
+/// Maximum days between the publication of two articles with similar titles to consider them
+/// the same one
+const DUPLICATES_MAX_DAYS_APART: i64 = 2;
+
+pub struct FeedServiceImpl<FR, CR, FCR, FRS, ENR, TR, WHR, WSR, HP, FRP, FAP, FVP, ENP, PCP>
 where
     FR: FeedRepository,
+    CR: CategoryRepository,
     FCR: FeedContentRepository,
+    FRS: FeedRulesService,
+    ENR: EnclosureRepository,
+    TR: TagRepository,
+    WHR: WebhookRepository,
+    WSR: WebSubSubscriptionRepository,
     HP: HtmlProcessor + 'static,
     FRP: FeedParser + 'static,
     FAP: FeedParser + 'static,
     FVP: FaviconProvider + 'static,
+    ENP: EnclosureProvider + 'static,
+    PCP: PersistedConfigProvider + 'static,
 {
     feed_repository: Arc<FR>,
+    category_repository: Arc<CR>,
     feed_content_repository: Arc<FCR>,
+    feed_rules_service: Arc<FRS>,
+    enclosure_repository: Arc<ENR>,
+    tag_repository: Arc<TR>,
+    webhook_repository: Arc<WHR>,
+    websub_subscription_repository: Arc<WSR>,
     html_processor: Arc<HP>,
     atom_parser: Arc<FRP>,
     rss_parser: Arc<FAP>,
     favicon_provider: Arc<FVP>,
+    enclosure_provider: Arc<ENP>,
+    persisted_config_provider: Arc<PCP>,
     config: Arc<Config>,
     articles_router_path: &'static str,
+    /// Held while the feeds are refreshed, so the syncs of the apps do not start another refresh
+    refreshing: Mutex<()>,
 }
 
-impl<FR, FCR, HP, FRP, FAP, FVP> FeedServiceImpl<FR, FCR, HP, FRP, FAP, FVP>
+impl<FR, CR, FCR, FRS, ENR, TR, WHR, WSR, HP, FRP, FAP, FVP, ENP, PCP> FeedServiceImpl<FR, CR, FCR, FRS, ENR, TR, WHR, WSR, HP, FRP, FAP, FVP, ENP, PCP>
 where
-    FR: FeedRepository,
-    FCR: FeedContentRepository,
+    FR: FeedRepository + 'static,
+    CR: CategoryRepository + 'static,
+    FCR: FeedContentRepository + 'static,
+    FRS: FeedRulesService + 'static,
+    ENR: EnclosureRepository + 'static,
+    TR: TagRepository + 'static,
+    WHR: WebhookRepository + 'static,
+    WSR: WebSubSubscriptionRepository + 'static,
     HP: HtmlProcessor + 'static,
     FRP: FeedParser + 'static,
     FAP: FeedParser + 'static,
     FVP: FaviconProvider + 'static,
+    ENP: EnclosureProvider + 'static,
+    PCP: PersistedConfigProvider + 'static,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         feed_repository: Arc<FR>,
+        category_repository: Arc<CR>,
         feed_content_repository: Arc<FCR>,
+        feed_rules_service: Arc<FRS>,
+        enclosure_repository: Arc<ENR>,
+        tag_repository: Arc<TR>,
+        webhook_repository: Arc<WHR>,
+        websub_subscription_repository: Arc<WSR>,
         html_processor: Arc<HP>,
         atom_parser: Arc<FRP>,
         rss_parser: Arc<FAP>,
         favicon_provider: Arc<FVP>,
+        enclosure_provider: Arc<ENP>,
+        persisted_config_provider: Arc<PCP>,
         config: Arc<Config>,
         articles_router_path: &'static str,
     ) -> Self {
         Self {
             feed_repository,
+            category_repository,
             feed_content_repository,
+            feed_rules_service,
+            enclosure_repository,
+            tag_repository,
+            webhook_repository,
+            websub_subscription_repository,
             html_processor,
             atom_parser,
             rss_parser,
             favicon_provider,
+            enclosure_provider,
+            persisted_config_provider,
             config,
             articles_router_path,
+            refreshing: Mutex::new(()),
         }
     }
 
-    async fn download_feed_content(feed_url: &str) -> Result<Bytes> {
-        reqwest::get(feed_url)
-            .await
-            .map_err(FeedServiceError::GettingFeed)?
+    /// Fails if the feed is being assigned to a category that does not exist
+    async fn check_category(&self, category_id: Option<Uuid>) -> Result<()> {
+        if let Some(category_id) = category_id
+            && self.category_repository.get_category(category_id).await?.is_none()
+        {
+            return Err(FeedServiceError::CategoryNotFound(category_id));
+        }
+
+        Ok(())
+    }
+
+    /// Whether the feed should be checked for new articles: it was not checked recently and its
+    /// updates are not pushed by a WebSub hub
+    async fn is_check_due(&self, feed: &Feed, now: DateTime<Utc>) -> Result<bool> {
+        let pushed = self
+            .websub_subscription_repository
+            .get_subscription(feed.id)
+            .await?
+            .is_some_and(|subscription| subscription.is_active(now));
+
+        Ok(!pushed
+            && now - feed.last_updated
+                > TimeDelta::minutes(self.config.minutes_to_check_for_updates.into()))
+    }
+
+    /// Downloads a document and transcodes it to UTF-8
+    async fn download_document(response: Response, kind: DocumentKind) -> Result<String> {
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let bytes = response
             .bytes()
             .await
-            .map_err(|e| FeedServiceError::Unexpected(e.into()))
+            .map_err(|e| FeedServiceError::Unexpected(e.into()))?;
+
+        Ok(decode_document(&bytes, content_type.as_deref(), kind))
     }
 
-    async fn download_html_article(article_url: &str) -> Result<String> {
-        reqwest::get(article_url)
+    async fn download_feed_content(feed_url: &str) -> Result<String> {
+        let response = reqwest::get(feed_url)
             .await
-            .map_err(FeedServiceError::GettingArticle)?
-            .text()
+            .map_err(FeedServiceError::GettingFeed)?;
+
+        Self::download_document(response, DocumentKind::Xml).await
+    }
+
+    async fn download_html_article(article_url: &str) -> Result<String> {
+        let response = reqwest::get(article_url)
             .await
-            .map_err(|e| FeedServiceError::Unexpected(e.into()))
+            .map_err(FeedServiceError::GettingArticle)?;
+
+        Self::download_document(response, DocumentKind::Html).await
     }
 
-    fn parse_feed(&self, content: &[u8]) -> Result<ParsedFeed> {
-        if let Ok(parsed_feed) = self.rss_parser.parse_feed(content) {
+    fn parse_feed(&self, content: &str) -> Result<ParsedFeed> {
+        if let Ok(parsed_feed) = self.rss_parser.parse_feed(content.as_bytes()) {
             Ok(parsed_feed)
-        } else if let Ok(parsed_feed) = self.atom_parser.parse_feed(content) {
+        } else if let Ok(parsed_feed) = self.atom_parser.parse_feed(content.as_bytes()) {
             Ok(parsed_feed)
         } else {
             Err(FeedServiceError::UnsupportedFormat)
@@ -111,21 +220,21 @@ where
         html_processor: Arc<HP>,
         image_processor: Arc<impl ImageProcessor>,
         feed_link: Arc<String>,
+        rules: Arc<FeedRules>,
     ) -> Result<String> {
         let content = html_processor
             .process_html_article(content)
             .map_err(|e| FeedServiceError::Unexpected(anyhow::anyhow!(e)))?
             .to_owned();
 
-        // Fix img src in contents
-        let content = html_processor
-            .fix_img_src(&content, &feed_link, &*image_processor)
-            .await
-            .map_err(|e| FeedServiceError::Unexpected(e.into()))?;
-
-        html_processor
-            .sanitize(&content)
-            .map_err(|e| FeedServiceError::Unexpected(e.into()))
+        Self::process_rss_content(
+            &content,
+            html_processor,
+            image_processor,
+            feed_link,
+            rules,
+        )
+        .await
     }
 
     async fn process_rss_content(
@@ -133,10 +242,18 @@ where
         html_processor: Arc<HP>,
         image_processor: Arc<impl ImageProcessor>,
         feed_link: Arc<String>,
+        rules: Arc<FeedRules>,
     ) -> Result<String> {
+        // The rewrite rules are applied before fixing the images, so rules that change the images
+        // (e.g. http:// to https:// or unwrapping <noscript> fallbacks) take effect when they are
+        // downloaded
+        let content = html_processor
+            .apply_rewrite_rules(content, &rules.rewrites)
+            .map_err(|e| FeedServiceError::Unexpected(e.into()))?;
+
         // Fix img src in contents
         let content = html_processor
-            .fix_img_src(content, &feed_link, &*image_processor)
+            .fix_img_src(&content, &feed_link, &*image_processor)
             .await
             .map_err(|e| FeedServiceError::Unexpected(e.into()))?;
 
@@ -147,16 +264,39 @@ where
 
     /// This function processes a feed item and adds it to the feed's article list.
     ///
-    /// If `download_content` is `true`, then the article is downloaded and processed, otherwise,
-    /// it is only added to the list
+    /// If `use_feed_content` is `true`, the content included in the feed is used. Otherwise, if
+    /// `download_content` is `true`, then the article is downloaded and processed, if not, it is
+    /// only added to the list. If the download fails, the content included in the feed (if any) is
+    /// used as fallback.
+    ///
+    /// The filter rules are applied before downloading anything, so the content is matched against
+    /// the one included in the feed (if any). If a "skip" rule matches, `None` is returned.
+    ///
+    /// If the item does not have a valid date, `fallback_date` is used.
+    #[allow(clippy::too_many_arguments)]
     async fn process_parsed_item(
         download_content: bool,
+        use_feed_content: bool,
+        create_thumbnail: bool,
         image_processor: Arc<impl ImageProcessor>,
         html_processor: Arc<HP>,
         feed_id: Uuid,
         feed_link: Arc<String>,
+        rules: Arc<FeedRules>,
         item: ParsedItem,
-    ) -> Result<(Article, Option<ArticleContent>)> {
+        fallback_date: DateTime<Utc>,
+    ) -> Result<Option<ProcessedItem>> {
+        let matched_actions =
+            rules.filter_actions(&item.title, item.author.as_deref(), item.content_or_summary());
+
+        if matched_actions.contains(&FilterAction::Skip) {
+            tracing::debug!(
+                r#"skipping article "{}" of feed {feed_id} because of a filter rule"#,
+                item.title
+            );
+            return Ok(None);
+        }
+
         let article_id = Uuid::new_v4();
 
         let article_link = item.link.clone().ok_or_else(|| {
@@ -166,16 +306,58 @@ where
             ))
         })?;
 
-        // If the items have content, we cache it in the fs
-        let (html_parsed, content) = if let Some(content) = item.content {
-            (false, Some(content))
-        }
-        // Otherwise we follow the link and download the html
-        else if download_content {
-            let article = Self::download_html_article(&article_link).await?;
-            (true, Some(article))
-        } else {
-            (true, None)
+        // If the item does not have a guid, we identify it by its link
+        let guid = item.guid.clone().unwrap_or_else(|| article_link.clone());
+
+        let summary = Self::get_item_teaser(&html_processor, &item);
+        let content_hash = item.content_hash();
+
+        // The thumbnail is the Media RSS one or, if there is none, the first image of the content
+        // included in the feed. It is only created if the article lists show it
+        let thumbnail = item
+            .thumbnail
+            .clone()
+            .or_else(|| {
+                item.content_or_summary()
+                    .and_then(|content| html_processor.get_lead_image_url(content, &article_link))
+            })
+            .filter(|_| create_thumbnail);
+
+        let thumbnail_url = match thumbnail {
+            Some(thumbnail) => match image_processor.process_thumbnail_url(&thumbnail).await {
+                Ok(thumbnail_url) => Some(thumbnail_url),
+                Err(e) => {
+                    tracing::warn!("unable to create the thumbnail {thumbnail}: {e:?}");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // The web page of the article may declare its canonical url, used to find its copies
+        let mut canonical_link = None;
+
+        // If we use the content of the item (or its summary if the feed does not include the full
+        // content), we cache it in the fs
+        let (html_parsed, content) = match item.content.or(item.summary) {
+            Some(content) if use_feed_content => (false, Some(content)),
+            // Otherwise we follow the link and download the html
+            feed_content if download_content => {
+                match (Self::download_html_article(&article_link).await, feed_content) {
+                    (Ok(article), _) => {
+                        canonical_link = html_processor.get_canonical_url(&article, &article_link);
+                        (true, Some(article))
+                    }
+                    (Err(e), Some(feed_content)) => {
+                        tracing::warn!(
+                            "unable to download article {article_link}, using the feed content: {e:?}"
+                        );
+                        (false, Some(feed_content))
+                    }
+                    (Err(e), None) => return Err(e),
+                }
+            }
+            _ => (true, None),
         };
 
         let content = if let Some(content) = content {
@@ -186,41 +368,737 @@ where
                         html_processor,
                         image_processor,
                         feed_link,
+                        rules,
                     )
                     .await?,
                 )
             } else {
                 Some(
-                    Self::process_rss_content(&content, html_processor, image_processor, feed_link)
-                        .await?,
+                    Self::process_rss_content(
+                        &content,
+                        html_processor,
+                        image_processor,
+                        feed_link,
+                        rules,
+                    )
+                    .await?,
                 )
             }
         } else {
             None
         };
 
-        let date = item.pub_date.unwrap_or_else(Utc::now);
+        let published_at = item.pub_date.unwrap_or(fallback_date);
+
+        let canonical_url =
+            Article::canonicalize_link(canonical_link.as_deref().unwrap_or(&article_link));
 
-        Ok((
+        let enclosures = item
+            .enclosures
+            .into_iter()
+            .map(|enclosure| Enclosure {
+                id: Uuid::new_v4(),
+                article_id,
+                feed_id,
+                url: enclosure.url,
+                mime_type: enclosure.mime_type,
+                length: enclosure.length,
+                duration: enclosure.duration,
+                cached_url: None,
+            })
+            .collect();
+
+        let tags = item
+            .categories
+            .iter()
+            .filter_map(|category| ArticleTag::normalize_name(category))
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .map(|name| ArticleTag {
+                article_id,
+                feed_id,
+                name,
+                user_defined: false,
+            })
+            .collect();
+
+        Ok(Some((
             Article {
                 id: article_id,
+                numeric_id: 0,
                 feed_id,
                 title: item.title,
                 link: article_link,
-                guid: item.guid.ok_or_else(|| {
-                    FeedServiceError::Unexpected(anyhow::anyhow!(
-                        "an article for the feed {} does not have a guid",
-                        feed_id
-                    ))
-                })?,
+                summary,
+                thumbnail_url,
+                guid,
                 author: item.author,
                 html_parsed,
                 content: None,
-                read: false,
-                last_updated: date,
+                read: matched_actions.contains(&FilterAction::MarkAsRead),
+                starred: matched_actions.contains(&FilterAction::Star),
+                last_updated: item.updated.unwrap_or(published_at),
+                published_at,
+                found_at: fallback_date,
+                date_guessed: item.pub_date.is_none(),
+                content_hash: Some(content_hash),
+                has_update: false,
+                updated_at: None,
+                duplicate_of: None,
+                canonical_url,
             },
             content,
-        ))
+            enclosures,
+            tags,
+        )))
+    }
+
+    /// Plain text teaser of a feed item, taken from its summary or, if there is none, its content
+    fn get_item_teaser(html_processor: &HP, item: &ParsedItem) -> Option<String> {
+        item.summary
+            .as_deref()
+            .or(item.content.as_deref())
+            .and_then(|summary| html_processor.get_teaser(summary, TEASER_MAX_LENGTH))
+    }
+
+    /// Checks if the publisher updated any of the saved articles, comparing them with their items
+    /// in the feed. Updated articles are modified in place. Errors are only logged, an article
+    /// that could not be updated is checked again the next time the feed is fetched
+    async fn update_saved_articles(
+        &self,
+        feed: &Feed,
+        saved_articles: &mut [Article],
+        items: Vec<(usize, ParsedItem)>,
+    ) {
+        let mut updated_items = vec![];
+        for (index, item) in items {
+            let article = &mut saved_articles[index];
+            let content_hash = item.content_hash();
+
+            // Feeds that include the date of the last update (e.g. Atom <updated>) tell us
+            // directly, for the rest we compare the title and content. The publication date is
+            // never used, some feeds bump it on every fetch
+            let updated = article
+                .content_hash
+                .as_ref()
+                .is_some_and(|hash| *hash != content_hash)
+                || item.updated.is_some_and(|date| date > article.last_updated);
+
+            if updated {
+                tracing::info!("article {} of feed {} was updated", article.id, feed.title);
+                updated_items.push((index, item, content_hash));
+            // Articles saved before we started hashing them are only hashed
+            } else if article.content_hash.is_none() {
+                article.content_hash = Some(content_hash);
+
+                if let Err(e) = self.feed_repository.update_article(article).await {
+                    tracing::error!("unable to save the hash of article {}: {e:?}", article.id)
+                }
+            }
+        }
+
+        if updated_items.is_empty() {
+            return;
+        }
+
+        let rules = match self.feed_rules_service.get_feed_rules(feed.id).await {
+            Ok(rules) => Arc::new(rules),
+            Err(e) => {
+                tracing::error!("unable to get the rules of feed {}: {e:?}", feed.id);
+                return;
+            }
+        };
+        let feed_link = Arc::new(feed.link.clone());
+        let router_path = self.get_article_router_path(feed.id);
+        let file_path = self.get_article_file_path(feed.id);
+        let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
+
+        // The new content is processed concurrently, like the one of the new articles, as the web
+        // pages may take a while to download
+        let mut join_set: JoinSet<(usize, ParsedItem, String, Result<Option<ArticleContent>>)> =
+            JoinSet::new();
+        for (index, item, content_hash) in updated_items {
+            let article = &saved_articles[index];
+            // Articles that were never downloaded get the new content when they are read
+            let cached = article.content.is_some();
+            let web_page = (cached && article.html_parsed).then(|| article.link.clone());
+            let feed_content = (cached && !article.html_parsed)
+                .then(|| item.content_or_summary().map(ToOwned::to_owned))
+                .flatten();
+
+            let html_processor = self.html_processor.clone();
+            let image_processor = image_processor.clone();
+            let feed_link = feed_link.clone();
+            let rules = rules.clone();
+
+            join_set.spawn(async move {
+                let content = if let Some(link) = web_page {
+                    match Self::download_html_article(&link).await {
+                        Ok(content) => Self::process_html_content(
+                            &content,
+                            html_processor,
+                            image_processor,
+                            feed_link,
+                            rules,
+                        )
+                        .await
+                        .map(Some),
+                        Err(e) => Err(e),
+                    }
+                } else if let Some(content) = feed_content {
+                    Self::process_rss_content(
+                        &content,
+                        html_processor,
+                        image_processor,
+                        feed_link,
+                        rules,
+                    )
+                    .await
+                    .map(Some)
+                } else {
+                    Ok(None)
+                };
+
+                (index, item, content_hash, content)
+            });
+        }
+
+        while let Some(Ok((index, item, content_hash, content))) = join_set.join_next().await {
+            let article = &mut saved_articles[index];
+            let result = match content {
+                Ok(content) => {
+                    self.update_saved_article(article, item, content_hash, content)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                tracing::error!("unable to update article {}: {e:?}", article.id)
+            }
+        }
+    }
+
+    /// Saves the new content of an article that was updated by the publisher, keeping the
+    /// previous version as a revision, and flags it as updated
+    async fn update_saved_article(
+        &self,
+        article: &mut Article,
+        item: ParsedItem,
+        content_hash: String,
+        content: Option<ArticleContent>,
+    ) -> Result<()> {
+        if let Some(content) = content {
+            self.feed_content_repository
+                .save_article_revision(article)
+                .await?;
+
+            // The images of the old content are kept because the revision still uses them
+            self.feed_content_repository
+                .save_article_content(&[(article, &content)])
+                .await?;
+        }
+
+        article.summary = Self::get_item_teaser(&self.html_processor, &item);
+        article.title = item.title;
+        if item.author.is_some() {
+            article.author = item.author;
+        }
+        if let Some(date) = item.updated {
+            article.last_updated = date;
+        }
+        article.content_hash = Some(content_hash);
+        article.has_update = true;
+        article.updated_at = Some(Utc::now());
+        if self.config.mark_updated_articles_as_unread {
+            article.read = false;
+        }
+
+        Ok(self.feed_repository.update_article(article).await?)
+    }
+
+    /// Links the new articles that are copies of an article of another feed to it. Copies are
+    /// found by their canonical url or, if it does not match, by their title among the articles
+    /// published around the same time. A copy of an article that was already read is added as
+    /// read
+    async fn link_duplicate_articles(
+        &self,
+        feed_id: Uuid,
+        articles: &mut [(Article, Option<ArticleContent>)],
+    ) {
+        let max_days_apart = TimeDelta::days(DUPLICATES_MAX_DAYS_APART);
+        let dates = articles.iter().map(|(a, _)| a.published_at);
+        let (Some(from), Some(to)) = (dates.clone().min(), dates.max()) else {
+            return;
+        };
+
+        let candidates = match self
+            .feed_repository
+            .get_duplicate_candidates(feed_id, from - max_days_apart, to + max_days_apart)
+            .await
+        {
+            Ok(candidates) => candidates,
+            Err(e) => {
+                tracing::error!("unable to get the articles to look for copies: {e:?}");
+                vec![]
+            }
+        };
+
+        for (article, _) in articles.iter_mut() {
+            let canonical_url = article.canonical_url.as_deref().unwrap_or(&article.link);
+            let original = match self
+                .feed_repository
+                .find_duplicate_article(feed_id, canonical_url, &article.link)
+                .await
+            {
+                Ok(original) => original,
+                Err(e) => {
+                    tracing::error!("unable to look for copies of article {}: {e:?}", article.id);
+                    None
+                }
+            };
+
+            let original = original.as_ref().or_else(|| {
+                candidates.iter().find(|candidate| {
+                    (candidate.published_at - article.published_at).abs() <= max_days_apart
+                        && Article::is_similar_title(&candidate.title, &article.title)
+                })
+            });
+
+            if let Some(original) = original {
+                tracing::debug!(
+                    "article {} is a copy of article {} of feed {}",
+                    article.id,
+                    original.id,
+                    original.feed_id
+                );
+                article.duplicate_of = Some(original.id);
+                article.read |= original.read;
+            }
+        }
+    }
+
+    /// Saves the enclosures of the new articles. The ones that should be available offline are
+    /// downloaded in the background, so a big podcast episode does not delay the article list.
+    /// Errors are only logged, an enclosure that could not be downloaded keeps its original url
+    async fn save_enclosures(&self, enclosures: Vec<Enclosure>) {
+        if enclosures.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.enclosure_repository.add_enclosures(&enclosures).await {
+            tracing::error!("there was an error saving the articles enclosures: {e:?}");
+            return;
+        }
+
+        let enclosure_provider = self.enclosure_provider.clone();
+        let enclosure_repository = self.enclosure_repository.clone();
+        tokio::spawn(async move {
+            for enclosure in enclosures {
+                match enclosure_provider.cache_enclosure(&enclosure).await {
+                    Ok(Some(cached_url)) => {
+                        if let Err(e) =
+                            enclosure_repository.set_cached_url(enclosure.id, &cached_url).await
+                        {
+                            tracing::error!("unable to save cached enclosure {}: {e:?}", enclosure.id)
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!("unable to download enclosure {}: {e:?}", enclosure.url)
+                    }
+                }
+            }
+        });
+    }
+
+    /// Adds the new articles of the downloaded (or pushed) content of a feed and updates the saved
+    /// ones. Returns all the articles of the feed
+    async fn ingest_feed_content(&self, feed: &mut Feed, content: &str) -> Result<Vec<Article>> {
+        let feed_id = feed.id;
+        let parsed_feed = self.parse_feed(content)?;
+        self.update_websub_hub(
+            feed,
+            parsed_feed.hub_url.as_deref(),
+            parsed_feed.self_url.as_deref(),
+        )
+        .await;
+
+        // First we check with the channel and the database if any of the articles is new
+        let mut saved_articles = self.feed_repository.get_feed_articles(feed_id).await?;
+
+        let articles_guid: HashMap<String, usize> = saved_articles
+            .iter()
+            .enumerate()
+            .map(|(index, article)| (article.guid.clone(), index))
+            .collect();
+
+        // New items keep their position in the feed
+        let mut new_items: Vec<(usize, ParsedItem)> = vec![];
+        let mut saved_items: Vec<(usize, ParsedItem)> = vec![];
+        for (position, item) in parsed_feed.items.into_iter().enumerate() {
+            // If the guid is not found, we identify the article by its link
+            let Some(guid) = item.guid.as_ref().or(item.link.as_ref()) else {
+                tracing::warn!(
+                    r#"found an article for feed {} with id "{}" with no guid or link"#,
+                    feed.title,
+                    feed_id
+                );
+                continue;
+            };
+
+            match articles_guid.get(guid.as_str()) {
+                Some(&index) => saved_items.push((index, item)),
+                None => new_items.push((position, item)),
+            }
+        }
+
+        self.update_saved_articles(feed, &mut saved_articles, saved_items)
+            .await;
+
+        if new_items.is_empty() {
+            self.feed_repository
+                .update_last_updated(feed_id, Utc::now())
+                .await?;
+            return Ok(saved_articles);
+        }
+
+        let mut join_set: JoinSet<Result<Option<ProcessedItem>>> = JoinSet::new();
+        // The patterns of the rules are prepared once for all the new articles
+        let rules = Arc::new(self.feed_rules_service.get_feed_rules(feed_id).await?);
+        let feed_link = Arc::new(feed.link.clone());
+        let router_path = self.get_article_router_path(feed_id);
+        let file_path = self.get_article_file_path(feed_id);
+        let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
+
+        // Items without a valid date get the current one minus their position in the feed,
+        // so they keep the order of the feed (the most recent first) between them
+        let fetched_at = Utc::now();
+        let found_at = |position: usize| fetched_at - TimeDelta::seconds(position as i64);
+
+        // Feeds are not always sorted by date, we sort the items so the most recent ones
+        // are the ones downloaded when there is a limit
+        new_items.sort_by_key(|(position, item)| {
+            std::cmp::Reverse(item.pub_date.unwrap_or(found_at(*position)))
+        });
+
+        let create_thumbnails = match self.persisted_config_provider.get_configuration().await {
+            Ok(config) => config.show_thumbnails,
+            Err(e) => {
+                tracing::warn!("unable to read the configuration, skipping thumbnails: {e:?}");
+                false
+            }
+        };
+
+        // Create the articles from the parsed items
+        let mut processed_html_articles = 0;
+        for (position, item) in new_items {
+            let feed_link = feed_link.clone();
+            let img_processor = image_processor.clone();
+            let html_processor = self.html_processor.clone();
+
+            // If it is an HTML article, we add one to the counter
+            // If we use the feed content then we process it anyway because we already
+            // donwloaded it... otherwise we only process it if we processed less than config.max_articles_qty_to_download
+            let use_feed_content =
+                self.use_feed_content(feed.content_mode, item.content_or_summary());
+            if !use_feed_content {
+                processed_html_articles += 1;
+            }
+
+            let download_content =
+                if let Some(qty) = self.config.max_articles_qty_to_download {
+                    processed_html_articles <= qty
+                } else {
+                    true
+                };
+
+            //  Start a task to process the article
+            join_set.spawn(Self::process_parsed_item(
+                download_content,
+                use_feed_content,
+                create_thumbnails,
+                img_processor,
+                html_processor,
+                feed_id,
+                feed_link,
+                rules.clone(),
+                item,
+                found_at(position),
+            ));
+        }
+
+        let mut processed_articles = vec![];
+        let mut processed_enclosures = vec![];
+        let mut processed_tags = vec![];
+        while let Some(Ok(article)) = join_set.join_next().await {
+            match article {
+                Ok(Some((article, content, enclosures, tags))) => {
+                    processed_articles.push((article, content));
+                    processed_enclosures.extend(enclosures);
+                    processed_tags.extend(tags);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!("there was an error processing an article: {e:?}")
+                }
+            }
+        }
+
+        if self.config.detect_duplicate_articles {
+            self.link_duplicate_articles(feed_id, &mut processed_articles)
+                .await;
+        }
+
+        let articles: Vec<&Article> = processed_articles.iter().map(|(a, _)| a).collect();
+        // Add the articles
+        self.feed_repository
+            .add_articles(feed_id, &articles)
+            .await?;
+
+        let articles_contents: Vec<(&Article, &ArticleContent)> = processed_articles
+            .iter()
+            .filter_map(|(a, c)| c.as_ref().map(|c| (a, c)))
+            .collect();
+
+        if let Err(e) = self
+            .feed_content_repository
+            .save_article_content(&articles_contents)
+            .await
+        {
+            tracing::error!("there was an error saving articles content: {e:?}")
+        }
+
+        self.save_enclosures(processed_enclosures).await;
+
+        if let Err(e) = self.tag_repository.add_article_tags(&processed_tags).await {
+            tracing::error!("there was an error saving the articles tags: {e:?}")
+        }
+
+        // The new articles are read again to get the numeric ids assigned when they were
+        // saved
+        let articles = self.feed_repository.get_feed_articles(feed_id).await?;
+
+        let new_ids: HashSet<Uuid> = processed_articles.iter().map(|(a, _)| a.id).collect();
+        let new_articles: Vec<&Article> =
+            articles.iter().filter(|a| new_ids.contains(&a.id)).collect();
+        self.enqueue_webhook_deliveries(feed, &new_articles).await;
+
+        // Check if the feed has a favicon, if not we try to download it
+        if feed.favicon_url.is_none() {
+            match self
+                .favicon_provider
+                .download_favicon(&feed.link, feed_id.to_string().as_str())
+                .await
+            {
+                Ok(Some(favicon_url)) => {
+                    self.feed_repository
+                        .update_favicon_url(feed_id, &favicon_url)
+                        .await?;
+
+                    feed.favicon_url = Some(favicon_url);
+                }
+                Ok(None) => {
+                    tracing::info!("no favicon found for feed {}", feed.title);
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "there was an error downloading the favicon for feed {}: {e:?}",
+                        feed.title
+                    );
+                }
+            }
+        }
+
+        Ok(articles)
+    }
+
+    /// Keeps the WebSub subscription of the feed in sync with the hub it advertises. The
+    /// subscription is requested to the hub by the WebSub service. Only used if the app has a
+    /// public url the hubs can call
+    async fn update_websub_hub(&self, feed: &Feed, hub_url: Option<&str>, self_url: Option<&str>) {
+        if self.config.public_url.is_none() {
+            return;
+        }
+
+        let subscription = match self
+            .websub_subscription_repository
+            .get_subscription(feed.id)
+            .await
+        {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                tracing::error!("there was an error getting the WebSub subscription: {e:?}");
+                return;
+            }
+        };
+
+        let result = match (hub_url, subscription) {
+            (None, None) => Ok(()),
+            // The feed does not advertise the hub anymore, so it is polled again
+            (None, Some(_)) => {
+                self.websub_subscription_repository
+                    .delete_subscription(feed.id)
+                    .await
+            }
+            (Some(hub_url), subscription) => {
+                let topic_url = self_url.unwrap_or(&feed.url);
+                if subscription.is_some_and(|s| s.hub_url == hub_url && s.topic_url == topic_url) {
+                    return;
+                }
+
+                tracing::info!("found WebSub hub {hub_url} for feed {}", feed.title);
+                let subscription =
+                    WebSubSubscription::new(feed.id, hub_url.to_owned(), topic_url.to_owned());
+                self.websub_subscription_repository
+                    .save_subscription(&subscription)
+                    .await
+            }
+        };
+
+        if let Err(e) = result {
+            tracing::error!("there was an error saving the WebSub subscription: {e:?}")
+        }
+    }
+
+    /// Queues the calls to the webhooks that apply to the feed with its new articles. They are
+    /// sent in the background by the webhook service. Errors are only logged, so the articles are
+    /// not lost if the webhooks fail
+    async fn enqueue_webhook_deliveries(&self, feed: &Feed, new_articles: &[&Article]) {
+        if new_articles.is_empty() {
+            return;
+        }
+
+        let webhooks = match self.webhook_repository.get_webhook_list().await {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::error!("there was an error getting the webhooks: {e:?}");
+                return;
+            }
+        };
+
+        let payload = match serde_json::to_string(&WebhookPayload::new_articles(feed, new_articles))
+        {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::error!("there was an error serializing the webhook payload: {e:?}");
+                return;
+            }
+        };
+
+        let now = Utc::now();
+        let deliveries: Vec<WebhookDelivery> = webhooks
+            .into_iter()
+            .filter(|webhook| webhook.applies_to(feed))
+            .map(|webhook| WebhookDelivery::new(webhook.id, payload.clone(), now))
+            .collect();
+
+        if deliveries.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.webhook_repository.add_deliveries(&deliveries).await {
+            tracing::error!("there was an error queueing the webhook deliveries: {e:?}")
+        }
+    }
+
+    /// Checks if the content included in the feed should be used for an article, according to
+    /// the content mode of the feed
+    fn use_feed_content(&self, content_mode: ContentMode, content: Option<&str>) -> bool {
+        match (content_mode, content) {
+            (_, None) | (ContentMode::Full, _) => false,
+            (ContentMode::Feed, Some(_)) => true,
+            (ContentMode::Auto, Some(content)) => !self
+                .html_processor
+                .is_content_truncated(content, self.config.min_feed_content_length),
+        }
+    }
+
+    /// Downloads the web page of an article and processes it
+    async fn download_article_content(&self, feed: &Feed, article: &Article) -> Result<String> {
+        let content = Self::download_html_article(&article.link).await?;
+
+        let router_path = self.get_article_router_path(feed.id);
+        let file_path = self.get_article_file_path(feed.id);
+        let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
+
+        let rules = self.feed_rules_service.get_feed_rules(feed.id).await?;
+
+        Self::process_html_content(
+            &content,
+            self.html_processor.clone(),
+            image_processor,
+            feed.link.clone().into(),
+            rules.into(),
+        )
+        .await
+    }
+
+    /// Saves the new content of an article and deletes the cached images that were only
+    /// referenced by the old one
+    async fn replace_article_content(&self, article: &Article, content: &String) -> Result<()> {
+        let old_content = match self
+            .feed_content_repository
+            .get_article_content(article.feed_id, article.id)
+            .await
+        {
+            Ok(old_content) => old_content,
+            Err(e) => {
+                tracing::warn!("unable to read the old content of article {}: {e:?}", article.id);
+                None
+            }
+        };
+
+        self.feed_content_repository
+            .save_article_content(&[(article, content)])
+            .await?;
+
+        if let Some(old_content) = old_content {
+            // The thumbnail is not part of the content, but it is still shown in the lists
+            let new_images: HashSet<String> = self
+                .get_article_image_names(article.feed_id, content)
+                .into_iter()
+                .chain(article.thumbnail_url.as_deref().and_then(|thumbnail_url| {
+                    self.get_article_image_names(article.feed_id, thumbnail_url).pop()
+                }))
+                .collect();
+
+            let unused_images: Vec<String> = self
+                .get_article_image_names(article.feed_id, &old_content)
+                .into_iter()
+                .filter(|image| !new_images.contains(image))
+                .collect();
+
+            if let Err(e) = self
+                .feed_content_repository
+                .delete_article_images(article.feed_id, &unused_images)
+                .await
+            {
+                tracing::error!("unable to delete the old images of article {}: {e:?}", article.id)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the file names of the cached images referenced by the content of an article
+    fn get_article_image_names(&self, feed_id: Uuid, content: &str) -> Vec<String> {
+        let prefix = format!("{}/static/", self.get_article_router_path(feed_id));
+
+        content
+            .match_indices(&prefix)
+            .filter_map(|(index, _)| {
+                // The images are named with an uuid, and the thumbnails have the jpg extension
+                let name = content.get(index + prefix.len()..)?;
+                let uuid = name.get(..36).filter(|uuid| Uuid::parse_str(uuid).is_ok())?;
+
+                if name[36..].starts_with(THUMBNAIL_EXTENSION) {
+                    Some(format!("{uuid}{THUMBNAIL_EXTENSION}"))
+                } else {
+                    Some(uuid.to_owned())
+                }
+            })
+            .collect()
     }
 
     // TODO: File paths are not responsibility of this service, they should be managed by
@@ -237,14 +1115,22 @@ where
 }
 
 #[async_trait]
-impl<FR, FCR, HP, FRP, FAP, FVP> FeedService for FeedServiceImpl<FR, FCR, HP, FRP, FAP, FVP>
+impl<FR, CR, FCR, FRS, ENR, TR, WHR, WSR, HP, FRP, FAP, FVP, ENP, PCP> FeedService for FeedServiceImpl<FR, CR, FCR, FRS, ENR, TR, WHR, WSR, HP, FRP, FAP, FVP, ENP, PCP>
 where
     FR: FeedRepository + 'static,
+    CR: CategoryRepository + 'static,
     FCR: FeedContentRepository + 'static,
+    FRS: FeedRulesService + 'static,
+    ENR: EnclosureRepository + 'static,
+    TR: TagRepository + 'static,
+    WHR: WebhookRepository + 'static,
+    WSR: WebSubSubscriptionRepository + 'static,
     HP: HtmlProcessor,
     FRP: FeedParser + 'static,
     FAP: FeedParser + 'static,
     FVP: FaviconProvider + 'static,
+    ENP: EnclosureProvider + 'static,
+    PCP: PersistedConfigProvider + 'static,
 {
     async fn get_feed_list(&self) -> Result<Vec<Feed>> {
         Ok(self.feed_repository.get_feed_list().await?)
@@ -254,7 +1140,9 @@ where
         Ok(self.feed_repository.get_feed(feed_id).await?)
     }
 
-    async fn add_feed(&self, feed_url: Url) -> Result<()> {
+    async fn add_feed(&self, feed_url: Url, category_id: Option<Uuid>) -> Result<Feed> {
+        self.check_category(category_id).await?;
+
         let content = Self::download_feed_content(feed_url.as_str()).await?;
 
         let parsed_feed = self.parse_feed(&content)?;
@@ -283,180 +1171,133 @@ where
 
         let feed = Feed {
             id: feed_id,
+            numeric_id: 0,
             title: parsed_feed.title,
             link,
             url: feed_url.into(),
             favicon_url,
             last_updated: DateTime::default(),
             unread_count: 0,
+            category_id,
+            content_mode: ContentMode::default(),
         };
 
         self.feed_repository.add_feed(feed).await?;
 
-        Ok(())
+        // The feed is read again to get the fields set when it is saved (e.g. the numeric id)
+        self.feed_repository
+            .get_feed(feed_id)
+            .await?
+            .ok_or(FeedServiceError::FeedNotFound(feed_id))
     }
 
     async fn get_channel(&self, feed_id: Uuid) -> Result<(Feed, Vec<Article>)> {
         let feed = self.feed_repository.get_feed(feed_id).await?;
         if let Some(mut feed) = feed {
-            let mut articles = if Utc::now() - feed.last_updated
-                > TimeDelta::minutes(self.config.minutes_to_check_for_updates.into())
-            {
+            let mut articles = if self.is_check_due(&feed, Utc::now()).await? {
                 tracing::info!("checking for updates for feed {}", feed.title);
                 let content = Self::download_feed_content(feed.url.as_str()).await?;
-                let parsed_feed = self.parse_feed(&content)?;
-
-                // First we check with the channel and the database if any of the articles is new
-                let saved_articles = self.feed_repository.get_feed_articles(feed_id).await?;
-
-                let articles_guid: HashSet<&str> = saved_articles
-                    .iter()
-                    .map(|article| &*article.guid)
-                    .collect();
-
-                let new_items: Vec<ParsedItem> = parsed_feed
-                    .items
-                    .into_iter()
-                    .filter(|item| {
-                        if let Some(guid) = &item.guid {
-                            !articles_guid.contains(guid.as_str())
-                        // We filter by link because if guid is not found, we identify the article
-                        // by its link
-                        } else if let Some(link) = &item.link {
-                            !articles_guid.contains(link.as_str())
-                        } else {
-                            tracing::warn!(
-                                r#"found an article for feed {} with id "{}" with no guid or link"#,
-                                feed.title,
-                                feed_id
-                            );
-                            false
-                        }
-                    })
-                    .collect();
-
-                if new_items.is_empty() {
-                    self.feed_repository
-                        .update_last_updated(feed_id, Utc::now())
-                        .await?;
-                    return Ok((feed, saved_articles));
-                }
+                self.ingest_feed_content(&mut feed, &content).await?
+            } else {
+                self.feed_repository.get_feed_articles(feed_id).await?
+            };
 
-                let mut join_set: JoinSet<Result<(Article, Option<ArticleContent>)>> =
-                    JoinSet::new();
-                let feed_link = Arc::new(feed.link.clone());
-                let router_path = self.get_article_router_path(feed_id);
-                let file_path = self.get_article_file_path(feed_id);
-                let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
-
-                // TODO: We are assuming the channel items are sorted by pub date desc
-
-                // Create the articles from the parsed items
-                let mut processed_html_articles = 0;
-                for item in new_items {
-                    let feed_link = feed_link.clone();
-                    let img_processor = image_processor.clone();
-                    let html_processor = self.html_processor.clone();
-
-                    // If it is an HTML article, we add one to the counter
-                    // If content exist then we process it anyway because we already donwloaded
-                    // it... otherwise we only process it if we processed less than config.max_articles_qty_to_download
-                    if item.content.is_none() {
-                        processed_html_articles += 1;
-                    }
+            // Sort the articles by publication date, with the most recent first
+            articles.sort_by_key(|a| std::cmp::Reverse(a.published_at));
 
-                    let download_content =
-                        if let Some(qty) = self.config.max_articles_qty_to_download {
-                            processed_html_articles <= qty
-                        } else {
-                            true
-                        };
-
-                    //  Start a task to process the article
-                    join_set.spawn(Self::process_parsed_item(
-                        download_content,
-                        img_processor,
-                        html_processor,
-                        feed_id,
-                        feed_link,
-                        item,
-                    ));
-                }
+            Ok((feed, articles))
+        } else {
+            Err(FeedServiceError::FeedNotFound(feed_id))
+        }
+    }
 
-                let mut processed_articles = vec![];
-                while let Some(Ok(article)) = join_set.join_next().await {
-                    match article {
-                        Ok((article, content)) => processed_articles.push((article, content)),
-                        Err(e) => {
-                            tracing::error!("there was an error processing an article: {e:?}")
-                        }
-                    }
-                }
+    async fn refresh_feeds(&self) -> Result<()> {
+        let Ok(_refreshing) = self.refreshing.try_lock() else {
+            tracing::debug!("the feeds are already being refreshed");
+            return Ok(());
+        };
 
-                let articles: Vec<&Article> = processed_articles.iter().map(|(a, _)| a).collect();
-                // Add the articles
-                self.feed_repository
-                    .add_articles(feed_id, &articles)
-                    .await?;
+        let now = Utc::now();
+        let mut due_feeds = vec![];
+        for feed in self.feed_repository.get_feed_list().await? {
+            if self.is_check_due(&feed, now).await? {
+                due_feeds.push(feed);
+            }
+        }
 
-                let articles_contents: Vec<(&Article, &ArticleContent)> = processed_articles
-                    .iter()
-                    .filter_map(|(a, c)| c.as_ref().map(|c| (a, c)))
-                    .collect();
+        // The feeds are downloaded concurrently and their articles are added as they arrive
+        let mut join_set: JoinSet<(Feed, Result<String>)> = JoinSet::new();
+        for feed in due_feeds {
+            join_set.spawn(async move {
+                tracing::info!("checking for updates for feed {}", feed.title);
+                let content = Self::download_feed_content(feed.url.as_str()).await;
+                (feed, content)
+            });
+        }
 
-                if let Err(e) = self
-                    .feed_content_repository
-                    .save_article_content(&articles_contents)
-                    .await
-                {
-                    tracing::error!("there was an error saving articles content: {e:?}")
+        while let Some(result) = join_set.join_next().await {
+            let (mut feed, content) = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::error!("unable to check a feed for new articles: {e:?}");
+                    continue;
                 }
+            };
 
-                let mut articles: Vec<Article> =
-                    processed_articles.into_iter().map(|(a, _)| a).collect();
+            let ingested = match content {
+                Ok(content) => self.ingest_feed_content(&mut feed, &content).await.map(|_| ()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = ingested {
+                tracing::error!("unable to check feed {} for new articles: {e:?}", feed.title);
+            }
+        }
 
-                articles.extend(saved_articles);
+        Ok(())
+    }
 
-                // Sort the articles by last updated, with the most recent first
-                articles.sort_by(|a1, a2| a2.last_updated.cmp(&a1.last_updated));
+    async fn push_feed_content(
+        &self,
+        feed_id: Uuid,
+        content: &[u8],
+        content_type: Option<&str>,
+    ) -> Result<()> {
+        let mut feed = self
+            .feed_repository
+            .get_feed(feed_id)
+            .await?
+            .ok_or(FeedServiceError::FeedNotFound(feed_id))?;
 
-                // Check if the feed has a favicon, if not we try to download it
-                if feed.favicon_url.is_none() {
-                    match self
-                        .favicon_provider
-                        .download_favicon(&feed.link, feed_id.to_string().as_str())
-                        .await
-                    {
-                        Ok(Some(favicon_url)) => {
-                            self.feed_repository
-                                .update_favicon_url(feed_id, &favicon_url)
-                                .await?;
+        tracing::info!("received pushed content for feed {}", feed.title);
+        let content = decode_document(content, content_type, DocumentKind::Xml);
+        self.ingest_feed_content(&mut feed, &content).await?;
 
-                            feed.favicon_url = Some(favicon_url);
-                        }
-                        Ok(None) => {
-                            tracing::info!("no favicon found for feed {}", feed.title);
-                        }
-                        Err(e) => {
-                            tracing::error!(
-                                "there was an error downloading the favicon for feed {}: {e:?}",
-                                feed.title
-                            );
-                        }
-                    }
-                }
+        Ok(())
+    }
 
-                articles
-            } else {
-                self.feed_repository.get_feed_articles(feed_id).await?
-            };
+    async fn get_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<Option<Article>> {
+        Ok(self
+            .feed_repository
+            .get_article_description(feed_id, article_id)
+            .await?)
+    }
+
+    async fn get_articles(&self, filter: &ArticleFilter) -> Result<Vec<Article>> {
+        Ok(self.feed_repository.get_articles(filter).await?)
+    }
 
-            articles.sort_by(|a1, a2| a2.last_updated.cmp(&a1.last_updated));
+    async fn get_articles_by_numeric_ids(&self, numeric_ids: &[i64]) -> Result<Vec<Article>> {
+        Ok(self
+            .feed_repository
+            .get_articles_by_numeric_ids(numeric_ids)
+            .await?)
+    }
 
-            Ok((feed, articles))
-        } else {
-            Err(FeedServiceError::FeedNotFound(feed_id))
-        }
+    async fn get_cached_content(&self, feed_id: Uuid, article_id: Uuid) -> Result<Option<String>> {
+        Ok(self
+            .feed_content_repository
+            .get_article_content(feed_id, article_id)
+            .await?)
     }
 
     async fn get_item_content(&self, feed_id: Uuid, article_id: Uuid) -> Result<(Article, String)> {
@@ -470,37 +1311,51 @@ where
             .get_article_description(feed_id, article_id)
             .await?;
 
-        if let Some(article_data) = article_data {
-            // If the content exist we serve it
-            if let Some(content) = content {
-                Ok((article_data, content))
-            }
-            // Otherwise, we download it on demand
-            else {
-                let feed = self.feed_repository.get_feed(feed_id).await?.ok_or(
-                    FeedServiceError::Unexpected(anyhow::anyhow!(
-                        "feed with id {feed_id} not found"
-                    )),
-                )?;
-                let content = Self::download_html_article(&article_data.link).await?;
-
-                let router_path = self.get_article_router_path(feed_id);
-                let file_path = self.get_article_file_path(feed_id);
-                let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
-
-                let processed_article = Self::process_html_content(
-                    &content,
-                    self.html_processor.clone(),
-                    image_processor,
-                    feed.link.into(),
-                )
-                .await?;
+        if let Some(mut article_data) = article_data {
+            let feed = self.feed_repository.get_feed(feed_id).await?.ok_or(
+                FeedServiceError::Unexpected(anyhow::anyhow!("feed with id {feed_id} not found")),
+            )?;
 
-                self.feed_content_repository
-                    .save_article_content(&[(&article_data, &processed_article)])
-                    .await?;
+            match content {
+                // If the content exist and it was extracted from the web page, or the content mode
+                // of the feed allows to use the one included in the feed, we serve it
+                Some(content)
+                    if article_data.html_parsed
+                        || self.use_feed_content(feed.content_mode, Some(&content)) =>
+                {
+                    Ok((article_data, content))
+                }
+                // If the content was taken from the feed but the content mode of the feed does not
+                // allow it anymore, we download the web page, serving the cached content if it fails
+                Some(content) => match self.download_article_content(&feed, &article_data).await {
+                    Ok(processed_article) => {
+                        self.replace_article_content(&article_data, &processed_article)
+                            .await?;
+                        self.feed_repository
+                            .set_article_html_parsed(feed_id, article_id, true)
+                            .await?;
+                        article_data.html_parsed = true;
+
+                        Ok((article_data, processed_article))
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "unable to download article {article_id}, serving the feed content: {e:?}"
+                        );
+                        Ok((article_data, content))
+                    }
+                },
+                // Otherwise, we download it on demand
+                None => {
+                    let processed_article =
+                        self.download_article_content(&feed, &article_data).await?;
 
-                Ok((article_data, processed_article))
+                    self.feed_content_repository
+                        .save_article_content(&[(&article_data, &processed_article)])
+                        .await?;
+
+                    Ok((article_data, processed_article))
+                }
             }
         } else {
             Err(FeedServiceError::ArticleContentNotFound(
@@ -509,6 +1364,152 @@ where
         }
     }
 
+    async fn get_article_enclosures(&self, article_id: Uuid) -> Result<Vec<Enclosure>> {
+        Ok(self
+            .enclosure_repository
+            .get_article_enclosures(article_id)
+            .await?)
+    }
+
+    async fn reload_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<()> {
+        let feed = self
+            .feed_repository
+            .get_feed(feed_id)
+            .await?
+            .ok_or(FeedServiceError::FeedNotFound(feed_id))?;
+
+        let article = self
+            .feed_repository
+            .get_article_description(feed_id, article_id)
+            .await?
+            .ok_or(FeedServiceError::ArticleContentNotFound(
+                article_id, feed_id,
+            ))?;
+
+        let content = self.download_article_content(&feed, &article).await?;
+        self.replace_article_content(&article, &content).await?;
+
+        // The content now comes from the web page even if it was taken from the feed before
+        if !article.html_parsed {
+            self.feed_repository
+                .set_article_html_parsed(feed_id, article_id, true)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn reprocess_feed(&self, feed_id: Uuid) -> Result<usize> {
+        let feed = self
+            .feed_repository
+            .get_feed(feed_id)
+            .await?
+            .ok_or(FeedServiceError::FeedNotFound(feed_id))?;
+
+        // Articles that were never downloaded are skipped, they are processed when they are read
+        let articles: Vec<Article> = self
+            .feed_repository
+            .get_feed_articles(feed_id)
+            .await?
+            .into_iter()
+            .filter(|article| article.content.is_some())
+            .collect();
+
+        // The content that came from the feed can not be downloaded again from the article link,
+        // so we take it from the feed. If it can not be fetched, only those articles are skipped
+        let mut feed_contents: HashMap<String, String> = HashMap::new();
+        if articles.iter().any(|article| !article.html_parsed) {
+            match Self::download_feed_content(&feed.url)
+                .await
+                .and_then(|content| self.parse_feed(&content))
+            {
+                Ok(parsed_feed) => {
+                    feed_contents = parsed_feed
+                        .items
+                        .into_iter()
+                        .filter_map(|item| {
+                            Some((item.guid.or(item.link)?, item.content.or(item.summary)?))
+                        })
+                        .collect();
+                }
+                Err(e) => tracing::warn!("unable to get the content of feed {feed_id}: {e:?}"),
+            }
+        }
+
+        let rules = Arc::new(self.feed_rules_service.get_feed_rules(feed_id).await?);
+        let feed_link = Arc::new(feed.link.clone());
+        let router_path = self.get_article_router_path(feed_id);
+        let file_path = self.get_article_file_path(feed_id);
+        let image_processor = Arc::new(ImageProcessorFsImpl::new(router_path, file_path));
+
+        let mut join_set: JoinSet<Result<(Article, ArticleContent)>> = JoinSet::new();
+        for article in articles {
+            let feed_content = if article.html_parsed {
+                None
+            } else if let Some(content) = feed_contents.remove(&article.guid) {
+                Some(content)
+            } else {
+                tracing::info!(
+                    "skipping article {} of feed {feed_id} because its content is not in the feed",
+                    article.id
+                );
+                continue;
+            };
+
+            let html_processor = self.html_processor.clone();
+            let image_processor = image_processor.clone();
+            let feed_link = feed_link.clone();
+            let rules = rules.clone();
+
+            join_set.spawn(async move {
+                let content = match feed_content {
+                    Some(content) => {
+                        Self::process_rss_content(
+                            &content,
+                            html_processor,
+                            image_processor,
+                            feed_link,
+                            rules,
+                        )
+                        .await?
+                    }
+                    None => {
+                        let content = Self::download_html_article(&article.link).await?;
+                        Self::process_html_content(
+                            &content,
+                            html_processor,
+                            image_processor,
+                            feed_link,
+                            rules,
+                        )
+                        .await?
+                    }
+                };
+
+                Ok((article, content))
+            });
+        }
+
+        let mut reprocessed_articles = 0;
+        while let Some(Ok(result)) = join_set.join_next().await {
+            match result {
+                Ok((article, content)) => {
+                    match self.replace_article_content(&article, &content).await {
+                        Ok(()) => reprocessed_articles += 1,
+                        Err(e) => {
+                            tracing::error!("unable to save article {}: {e:?}", article.id)
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("there was an error reprocessing an article: {e:?}")
+                }
+            }
+        }
+
+        Ok(reprocessed_articles)
+    }
+
     async fn mark_article_as_read(&self, feed_id: Uuid, article_id: Uuid) -> Result<()> {
         Ok(self
             .feed_repository
@@ -516,6 +1517,36 @@ where
             .await?)
     }
 
+    async fn mark_article_as_unread(&self, feed_id: Uuid, article_id: Uuid) -> Result<()> {
+        Ok(self
+            .feed_repository
+            .mark_article_as_unread(feed_id, article_id)
+            .await?)
+    }
+
+    async fn mark_articles_as_read(
+        &self,
+        feed_id: Option<Uuid>,
+        before: DateTime<Utc>,
+    ) -> Result<()> {
+        Ok(self
+            .feed_repository
+            .mark_articles_as_read(feed_id, before)
+            .await?)
+    }
+
+    async fn set_article_starred(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        starred: bool,
+    ) -> Result<()> {
+        Ok(self
+            .feed_repository
+            .set_article_starred(feed_id, article_id, starred)
+            .await?)
+    }
+
     async fn delete_feed(&self, feed_id: Uuid) -> Result<()> {
         // First verify that the feed exists
         let feed = self.feed_repository.get_feed(feed_id).await?;
@@ -531,4 +1562,33 @@ where
         // Delete the feed and its articles from the database
         Ok(self.feed_repository.delete_feed(feed_id).await?)
     }
+
+    async fn update_feed_content_mode(
+        &self,
+        feed_id: Uuid,
+        content_mode: ContentMode,
+    ) -> Result<()> {
+        let feed = self.feed_repository.get_feed(feed_id).await?;
+        if feed.is_none() {
+            return Err(FeedServiceError::FeedNotFound(feed_id));
+        }
+
+        Ok(self
+            .feed_repository
+            .update_feed_content_mode(feed_id, content_mode)
+            .await?)
+    }
+
+    async fn update_feed_category(&self, feed_id: Uuid, category_id: Option<Uuid>) -> Result<()> {
+        let feed = self.feed_repository.get_feed(feed_id).await?;
+        if feed.is_none() {
+            return Err(FeedServiceError::FeedNotFound(feed_id));
+        }
+        self.check_category(category_id).await?;
+
+        Ok(self
+            .feed_repository
+            .update_feed_category(feed_id, category_id)
+            .await?)
+    }
 }
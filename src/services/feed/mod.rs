@@ -4,13 +4,19 @@ mod error;
 mod feed_service_impl;
 
 use axum::async_trait;
+use chrono::{DateTime, Utc};
 
-use error::FeedServiceError;
+pub use error::FeedServiceError;
 pub use feed_service_impl::FeedServiceImpl;
 use reqwest::Url;
 use uuid::Uuid;
 
-use crate::models::{article::Article, feed::Feed};
+use crate::models::{
+    article::Article,
+    article_filter::ArticleFilter,
+    enclosure::Enclosure,
+    feed::{ContentMode, Feed},
+};
 
 pub(crate) type Result<T> = std::result::Result<T, FeedServiceError>;
 
@@ -20,13 +26,65 @@ pub(crate) trait FeedService: Sync + Send {
 
     async fn get_feed(&self, feed_id: Uuid) -> Result<Option<Feed>>;
 
-    async fn add_feed(&self, feed_url: Url) -> Result<()>;
+    /// Adds the feed of the given url and returns it. Fails if the category does not exist
+    async fn add_feed(&self, feed_url: Url, category_id: Option<Uuid>) -> Result<Feed>;
 
+    /// Returns the feed with its articles, checking it for new ones if it was not checked
+    /// recently. Feeds whose updates are pushed by a WebSub hub are not checked
     async fn get_channel(&self, feed_id: Uuid) -> Result<(Feed, Vec<Article>)>;
 
+    /// Checks all the feeds for new articles like [`FeedService::get_channel`] does, downloading
+    /// them concurrently. Used by the apps, that never open the feeds. Errors are only logged, so a
+    /// broken feed does not stop the rest. Returns right away if a refresh is already running
+    async fn refresh_feeds(&self) -> Result<()>;
+
+    /// Adds the new articles of the content pushed by the WebSub hub of a feed
+    async fn push_feed_content(
+        &self,
+        feed_id: Uuid,
+        content: &[u8],
+        content_type: Option<&str>,
+    ) -> Result<()>;
+
+    async fn get_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<Option<Article>>;
+
+    /// Returns the saved articles that match the filter, without checking the feeds for new ones
+    async fn get_articles(&self, filter: &ArticleFilter) -> Result<Vec<Article>>;
+
+    async fn get_articles_by_numeric_ids(&self, numeric_ids: &[i64]) -> Result<Vec<Article>>;
+
+    /// Returns the saved content of an article, without downloading it if it is not saved
+    async fn get_cached_content(&self, feed_id: Uuid, article_id: Uuid) -> Result<Option<String>>;
+
     async fn get_item_content(&self, feed_id: Uuid, article_id: Uuid) -> Result<(Article, String)>;
 
+    /// Returns the files attached to an article (podcast episodes, PDFs, etc)
+    async fn get_article_enclosures(&self, article_id: Uuid) -> Result<Vec<Enclosure>>;
+
+    /// Downloads the web page of an article again and replaces the cached content and its images
+    async fn reload_article(&self, feed_id: Uuid, article_id: Uuid) -> Result<()>;
+
+    /// Processes again the cached content of all the articles of a feed, for example, after
+    /// changing its rewrite rules. Articles whose content came from the feed are reprocessed only
+    /// if they are still in it. Returns the number of reprocessed articles.
+    async fn reprocess_feed(&self, feed_id: Uuid) -> Result<usize>;
+
     async fn mark_article_as_read(&self, feed_id: Uuid, article_id: Uuid) -> Result<()>;
 
+    async fn mark_article_as_unread(&self, feed_id: Uuid, article_id: Uuid) -> Result<()>;
+
+    /// Marks the articles of a feed (or all of them if `feed_id` is None) found before the given
+    /// date as read
+    async fn mark_articles_as_read(&self, feed_id: Option<Uuid>, before: DateTime<Utc>)
+        -> Result<()>;
+
+    async fn set_article_starred(&self, feed_id: Uuid, article_id: Uuid, starred: bool)
+        -> Result<()>;
+
     async fn delete_feed(&self, feed_id: Uuid) -> Result<()>;
+
+    async fn update_feed_category(&self, feed_id: Uuid, category_id: Option<Uuid>) -> Result<()>;
+
+    async fn update_feed_content_mode(&self, feed_id: Uuid, content_mode: ContentMode)
+        -> Result<()>;
 }
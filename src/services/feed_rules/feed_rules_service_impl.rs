@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use super::{FeedRules, FeedRulesService, Result};
+use crate::{
+    models::filter_rule::FilterMatcher,
+    repositories::{filter_rule::FilterRuleRepository, rewrite_rule::RewriteRuleRepository},
+};
+
+pub struct FeedRulesServiceImpl<FLR, RWR>
+where
+    FLR: FilterRuleRepository,
+    RWR: RewriteRuleRepository,
+{
+    filter_rule_repository: Arc<FLR>,
+    rewrite_rule_repository: Arc<RWR>,
+}
+
+impl<FLR, RWR> FeedRulesServiceImpl<FLR, RWR>
+where
+    FLR: FilterRuleRepository,
+    RWR: RewriteRuleRepository,
+{
+    pub fn new(filter_rule_repository: Arc<FLR>, rewrite_rule_repository: Arc<RWR>) -> Self {
+        Self {
+            filter_rule_repository,
+            rewrite_rule_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<FLR, RWR> FeedRulesService for FeedRulesServiceImpl<FLR, RWR>
+where
+    FLR: FilterRuleRepository,
+    RWR: RewriteRuleRepository,
+{
+    async fn get_feed_rules(&self, feed_id: Uuid) -> Result<FeedRules> {
+        let filters = self
+            .filter_rule_repository
+            .get_feed_filter_rules(feed_id)
+            .await?
+            .iter()
+            .map(FilterMatcher::from)
+            .collect();
+
+        let rewrites = self
+            .rewrite_rule_repository
+            .get_feed_rewrite_rules(feed_id)
+            .await?;
+
+        Ok(FeedRules { filters, rewrites })
+    }
+}
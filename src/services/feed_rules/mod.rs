@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Rules applied by the feed service to the articles of a feed: the filter rules decide what
+//! happens to the new articles and the rewrite rules change their content. They are managed by
+//! the filter and rewrite rule services
+mod error;
+mod feed_rules_service_impl;
+
+use axum::async_trait;
+
+pub use error::FeedRulesServiceError;
+pub use feed_rules_service_impl::FeedRulesServiceImpl;
+use uuid::Uuid;
+
+use crate::models::{
+    filter_rule::{FilterAction, FilterMatcher},
+    rewrite_rule::RewriteRule,
+};
+
+pub type Result<T> = std::result::Result<T, FeedRulesServiceError>;
+
+/// Rules of a feed, with the patterns of the filters already prepared so they can be matched
+/// against all the new articles
+pub struct FeedRules {
+    pub filters: Vec<FilterMatcher>,
+    /// In the order they are applied
+    pub rewrites: Vec<RewriteRule>,
+}
+
+impl FeedRules {
+    /// Returns the actions of the filters that match the article data
+    pub fn filter_actions(
+        &self,
+        title: &str,
+        author: Option<&str>,
+        content: Option<&str>,
+    ) -> Vec<FilterAction> {
+        self.filters
+            .iter()
+            .filter(|filter| filter.matches(title, author, content))
+            .map(|filter| filter.action)
+            .collect()
+    }
+}
+
+/// Public, unlike the other services, as it is a bound of the feed service
+#[async_trait]
+pub trait FeedRulesService: Sync + Send {
+    /// Returns the rules of a feed. The filters include the ones that apply to all the feeds
+    async fn get_feed_rules(&self, feed_id: Uuid) -> Result<FeedRules>;
+}
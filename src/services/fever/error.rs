@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FeverServiceError {
+    #[error("the feed {0} was not found")]
+    FeedNotFound(i64),
+
+    #[error("the group {0} was not found")]
+    GroupNotFound(i64),
+
+    #[error("feeds and groups can only be marked as read")]
+    InvalidMark,
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<FeverServiceError> for ApiError {
+    fn from(error: FeverServiceError) -> Self {
+        match error {
+            e @ (FeverServiceError::FeedNotFound(_) | FeverServiceError::GroupNotFound(_)) => {
+                Self {
+                    original_error: e.into(),
+                    status_code: StatusCode::NOT_FOUND,
+                }
+            }
+
+            e @ FeverServiceError::InvalidMark => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
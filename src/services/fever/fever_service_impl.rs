@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use md5::{Digest, Md5};
+
+use super::{
+    error::FeverServiceError, FeverService, ItemsQuery, MarkAs, MarkTarget, Result, MAX_ITEMS,
+};
+use crate::{
+    config::Config,
+    models::{article::Article, category::Category, feed::Feed},
+    repositories::{
+        category::CategoryRepository, feed::FeedRepository, feed_content::FeedContentRepository,
+    },
+};
+
+pub struct FeverServiceImpl<FR, FCR, CR>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+{
+    feed_repository: Arc<FR>,
+    feed_content_repository: Arc<FCR>,
+    category_repository: Arc<CR>,
+    config: Arc<Config>,
+    /// MD5 hash of `username:password`, None if the credentials are not configured
+    api_key: Option<String>,
+}
+
+impl<FR, FCR, CR> FeverServiceImpl<FR, FCR, CR>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+{
+    pub fn new(
+        feed_repository: Arc<FR>,
+        feed_content_repository: Arc<FCR>,
+        category_repository: Arc<CR>,
+        config: Arc<Config>,
+    ) -> Self {
+        let api_key = config
+            .api_username
+            .as_ref()
+            .zip(config.api_password.as_ref())
+            .map(|(username, password)| {
+                format!("{:x}", Md5::digest(format!("{username}:{password}")))
+            });
+
+        Self {
+            feed_repository,
+            feed_content_repository,
+            category_repository,
+            config,
+            api_key,
+        }
+    }
+
+    /// Returns the HTML content of an article, or its summary if it was not downloaded
+    async fn get_item_html(&self, article: &Article) -> Result<String> {
+        let content = self
+            .feed_content_repository
+            .get_article_content(article.feed_id, article.id)
+            .await?;
+
+        Ok(article.content_or_summary_html(content))
+    }
+
+    async fn get_feed_by_numeric_id(&self, numeric_id: i64) -> Result<Feed> {
+        self.feed_repository
+            .get_feed_list()
+            .await?
+            .into_iter()
+            .find(|f| f.numeric_id == numeric_id)
+            .ok_or(FeverServiceError::FeedNotFound(numeric_id))
+    }
+}
+
+#[async_trait]
+impl<FR, FCR, CR> FeverService for FeverServiceImpl<FR, FCR, CR>
+where
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+    CR: CategoryRepository,
+{
+    fn is_authenticated(&self, api_key: &str) -> bool {
+        self.api_key
+            .as_deref()
+            .is_some_and(|key| key.eq_ignore_ascii_case(api_key))
+    }
+
+    async fn get_feeds(&self) -> Result<Vec<Feed>> {
+        Ok(self.feed_repository.get_feed_list().await?)
+    }
+
+    async fn get_groups(&self) -> Result<Vec<Category>> {
+        Ok(self.category_repository.get_category_list().await?)
+    }
+
+    async fn get_favicons(&self) -> Result<Vec<(i64, String)>> {
+        let mut favicons = vec![];
+        for feed in self.feed_repository.get_feed_list().await? {
+            let Some(favicon_url) = feed.favicon_url else {
+                continue;
+            };
+
+            // The favicons are served from the data path, with the same relative path
+            let path = format!("{}{favicon_url}", self.config.data_path);
+            match tokio::fs::read(&path).await {
+                Ok(data) => {
+                    let mime_type = image::guess_format(&data)
+                        .map(|format| format.to_mime_type())
+                        .unwrap_or("image/x-icon");
+
+                    favicons.push((
+                        feed.numeric_id,
+                        format!("{mime_type};base64,{}", STANDARD.encode(data)),
+                    ));
+                }
+                Err(e) => tracing::warn!("unable to read the favicon {path}: {e:?}"),
+            }
+        }
+
+        Ok(favicons)
+    }
+
+    async fn get_items(&self, query: ItemsQuery) -> Result<Vec<(Article, String)>> {
+        let articles = match query {
+            ItemsQuery::SinceId(since_id) => {
+                self.feed_repository
+                    .get_articles_since_numeric_id(since_id, MAX_ITEMS)
+                    .await?
+            }
+            ItemsQuery::MaxId(max_id) => {
+                self.feed_repository
+                    .get_articles_before_numeric_id(max_id, MAX_ITEMS)
+                    .await?
+            }
+            ItemsQuery::WithIds(ids) => {
+                let ids = &ids[..ids.len().min(MAX_ITEMS)];
+                self.feed_repository.get_articles_by_numeric_ids(ids).await?
+            }
+        };
+
+        let mut items = Vec::with_capacity(articles.len());
+        for article in articles {
+            let html = self.get_item_html(&article).await?;
+            items.push((article, html));
+        }
+
+        Ok(items)
+    }
+
+    async fn get_total_items(&self) -> Result<usize> {
+        Ok(self.feed_repository.get_article_count().await?)
+    }
+
+    async fn get_unread_item_ids(&self) -> Result<Vec<i64>> {
+        Ok(self
+            .feed_repository
+            .get_unread_article_numeric_ids()
+            .await?)
+    }
+
+    async fn get_saved_item_ids(&self) -> Result<Vec<i64>> {
+        Ok(self
+            .feed_repository
+            .get_starred_article_numeric_ids()
+            .await?)
+    }
+
+    async fn mark(
+        &self,
+        target: MarkTarget,
+        mark_as: MarkAs,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let before = before.unwrap_or_else(Utc::now);
+
+        match (target, mark_as) {
+            (MarkTarget::Item(numeric_id), mark_as) => {
+                // Clients send the changes made offline, an item can be deleted in the meantime
+                let Some(article) = self
+                    .feed_repository
+                    .get_article_by_numeric_id(numeric_id)
+                    .await?
+                else {
+                    tracing::warn!("unable to mark item {numeric_id}, it was not found");
+                    return Ok(());
+                };
+
+                let (feed_id, article_id) = (article.feed_id, article.id);
+                match mark_as {
+                    MarkAs::Read => {
+                        self.feed_repository
+                            .mark_article_as_read(feed_id, article_id)
+                            .await?
+                    }
+                    MarkAs::Unread => {
+                        self.feed_repository
+                            .mark_article_as_unread(feed_id, article_id)
+                            .await?
+                    }
+                    MarkAs::Saved | MarkAs::Unsaved => {
+                        self.feed_repository
+                            .set_article_starred(feed_id, article_id, mark_as == MarkAs::Saved)
+                            .await?
+                    }
+                }
+            }
+            (MarkTarget::Feed(numeric_id), MarkAs::Read) => {
+                let feed = self.get_feed_by_numeric_id(numeric_id).await?;
+                self.feed_repository
+                    .mark_articles_as_read(Some(feed.id), before)
+                    .await?;
+            }
+            (MarkTarget::Group(0), MarkAs::Read) => {
+                self.feed_repository
+                    .mark_articles_as_read(None, before)
+                    .await?;
+            }
+            (MarkTarget::Group(numeric_id), MarkAs::Read) => {
+                let category = self
+                    .category_repository
+                    .get_category_list()
+                    .await?
+                    .into_iter()
+                    .find(|c| c.numeric_id == numeric_id)
+                    .ok_or(FeverServiceError::GroupNotFound(numeric_id))?;
+
+                for feed in self.feed_repository.get_feed_list().await? {
+                    if feed.category_id == Some(category.id) {
+                        self.feed_repository
+                            .mark_articles_as_read(Some(feed.id), before)
+                            .await?;
+                    }
+                }
+            }
+            (MarkTarget::Feed(_) | MarkTarget::Group(_), _) => {
+                return Err(FeverServiceError::InvalidMark);
+            }
+        }
+
+        Ok(())
+    }
+}
+
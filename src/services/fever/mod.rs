@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Backend of the Fever API, used by third-party reader apps. Fever identifies feeds, groups
+//! (our categories) and items (our articles) by integer ids, so the numeric ids are used
+mod error;
+mod fever_service_impl;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+
+use error::FeverServiceError;
+pub use fever_service_impl::FeverServiceImpl;
+
+use crate::models::{article::Article, category::Category, feed::Feed};
+
+pub(crate) type Result<T> = std::result::Result<T, FeverServiceError>;
+
+/// Maximum number of items returned in a request, as defined by the Fever API
+pub const MAX_ITEMS: usize = 50;
+
+/// Items requested by a client
+#[derive(Debug, PartialEq)]
+pub enum ItemsQuery {
+    /// Items with an id greater than the given one, the oldest first
+    SinceId(i64),
+    /// Items with an id lower than the given one, the most recent first
+    MaxId(i64),
+    WithIds(Vec<i64>),
+}
+
+/// What a `mark` request applies to
+#[derive(Debug, PartialEq)]
+pub enum MarkTarget {
+    Item(i64),
+    Feed(i64),
+    /// Group 0 is the special "Kindling" group, that contains all the feeds
+    Group(i64),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MarkAs {
+    Read,
+    Unread,
+    Saved,
+    Unsaved,
+}
+
+#[async_trait]
+pub(crate) trait FeverService: Sync + Send {
+    /// Checks the API key sent by a client, the MD5 hash of `username:password`
+    fn is_authenticated(&self, api_key: &str) -> bool;
+
+    async fn get_feeds(&self) -> Result<Vec<Feed>>;
+
+    async fn get_groups(&self) -> Result<Vec<Category>>;
+
+    /// Returns the favicons of the feeds as `(feed numeric id, data)`, where data is the mime type
+    /// and the base64 encoded image, as Fever expects it (`image/png;base64,...`)
+    async fn get_favicons(&self) -> Result<Vec<(i64, String)>>;
+
+    /// Returns the requested items with their HTML content (up to [`MAX_ITEMS`])
+    async fn get_items(&self, query: ItemsQuery) -> Result<Vec<(Article, String)>>;
+
+    async fn get_total_items(&self) -> Result<usize>;
+
+    async fn get_unread_item_ids(&self) -> Result<Vec<i64>>;
+
+    async fn get_saved_item_ids(&self) -> Result<Vec<i64>>;
+
+    /// Marks an item, or all the items of a feed or group found before the given date, as read,
+    /// unread, saved (starred) or unsaved. Feeds and groups can only be marked as read
+    async fn mark(
+        &self,
+        target: MarkTarget,
+        mark_as: MarkAs,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<()>;
+}
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FilterRuleServiceError {
+    #[error("the filter rule {0} was not found")]
+    FilterRuleNotFound(Uuid),
+
+    #[error("invalid filter pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<FilterRuleServiceError> for ApiError {
+    fn from(error: FilterRuleServiceError) -> Self {
+        match error {
+            e @ FilterRuleServiceError::FilterRuleNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e @ FilterRuleServiceError::InvalidPattern(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use regex::RegexBuilder;
+use uuid::Uuid;
+
+use super::{error::FilterRuleServiceError, FilterRuleService, Result};
+use crate::{
+    models::{
+        article::Article,
+        filter_rule::{FilterAction, FilterField, FilterMatchType, FilterMatcher, FilterRule},
+    },
+    repositories::{
+        feed::FeedRepository, feed_content::FeedContentRepository,
+        filter_rule::FilterRuleRepository,
+    },
+};
+
+pub struct FilterRuleServiceImpl<FLR, FR, FCR>
+where
+    FLR: FilterRuleRepository,
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+{
+    filter_rule_repository: Arc<FLR>,
+    feed_repository: Arc<FR>,
+    feed_content_repository: Arc<FCR>,
+}
+
+impl<FLR, FR, FCR> FilterRuleServiceImpl<FLR, FR, FCR>
+where
+    FLR: FilterRuleRepository,
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+{
+    pub fn new(
+        filter_rule_repository: Arc<FLR>,
+        feed_repository: Arc<FR>,
+        feed_content_repository: Arc<FCR>,
+    ) -> Self {
+        Self {
+            filter_rule_repository,
+            feed_repository,
+            feed_content_repository,
+        }
+    }
+
+    fn validate_pattern(match_type: FilterMatchType, pattern: &str) -> Result<String> {
+        if pattern.trim().is_empty() {
+            return Err(FilterRuleServiceError::InvalidPattern(
+                "the pattern can not be empty".to_owned(),
+            ));
+        }
+
+        if match_type == FilterMatchType::Regex {
+            RegexBuilder::new(pattern)
+                .build()
+                .map_err(|e| FilterRuleServiceError::InvalidPattern(e.to_string()))?;
+        }
+
+        Ok(pattern.to_owned())
+    }
+}
+
+#[async_trait]
+impl<FLR, FR, FCR> FilterRuleService for FilterRuleServiceImpl<FLR, FR, FCR>
+where
+    FLR: FilterRuleRepository,
+    FR: FeedRepository,
+    FCR: FeedContentRepository,
+{
+    async fn get_filter_rule_list(&self) -> Result<Vec<FilterRule>> {
+        Ok(self.filter_rule_repository.get_filter_rule_list().await?)
+    }
+
+    async fn add_filter_rule(
+        &self,
+        feed_id: Option<Uuid>,
+        field: FilterField,
+        match_type: FilterMatchType,
+        pattern: &str,
+        action: FilterAction,
+    ) -> Result<FilterRule> {
+        let rule = FilterRule {
+            id: Uuid::new_v4(),
+            feed_id,
+            field,
+            match_type,
+            pattern: Self::validate_pattern(match_type, pattern)?,
+            action,
+        };
+
+        self.filter_rule_repository.add_filter_rule(&rule).await?;
+
+        Ok(rule)
+    }
+
+    async fn delete_filter_rule(&self, rule_id: Uuid) -> Result<()> {
+        if self
+            .filter_rule_repository
+            .get_filter_rule(rule_id)
+            .await?
+            .is_none()
+        {
+            return Err(FilterRuleServiceError::FilterRuleNotFound(rule_id));
+        }
+
+        Ok(self
+            .filter_rule_repository
+            .delete_filter_rule(rule_id)
+            .await?)
+    }
+
+    async fn test_filter_rule(
+        &self,
+        feed_id: Option<Uuid>,
+        field: FilterField,
+        match_type: FilterMatchType,
+        pattern: &str,
+    ) -> Result<Vec<Article>> {
+        let rule = FilterRule {
+            id: Uuid::new_v4(),
+            feed_id,
+            field,
+            match_type,
+            pattern: Self::validate_pattern(match_type, pattern)?,
+            action: FilterAction::Skip,
+        };
+
+        let feed_ids = match feed_id {
+            Some(feed_id) => vec![feed_id],
+            None => self
+                .feed_repository
+                .get_feed_list()
+                .await?
+                .into_iter()
+                .map(|f| f.id)
+                .collect(),
+        };
+
+        let matcher = FilterMatcher::from(&rule);
+        let mut matched_articles = vec![];
+        for feed_id in feed_ids {
+            for article in self.feed_repository.get_feed_articles(feed_id).await? {
+                // Only read the content from the storage if the rule needs it
+                let content = if matches!(field, FilterField::Content | FilterField::Any) {
+                    self.feed_content_repository
+                        .get_article_content(article.feed_id, article.id)
+                        .await?
+                } else {
+                    None
+                };
+
+                if matcher.matches(&article.title, article.author.as_deref(), content.as_deref()) {
+                    matched_articles.push(article);
+                }
+            }
+        }
+
+        matched_articles.sort_by_key(|a| std::cmp::Reverse(a.published_at));
+
+        Ok(matched_articles)
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod error;
+mod filter_rule_service_impl;
+
+use axum::async_trait;
+
+use error::FilterRuleServiceError;
+pub use filter_rule_service_impl::FilterRuleServiceImpl;
+use uuid::Uuid;
+
+use crate::models::{
+    article::Article,
+    filter_rule::{FilterAction, FilterField, FilterMatchType, FilterRule},
+};
+
+pub(crate) type Result<T> = std::result::Result<T, FilterRuleServiceError>;
+
+#[async_trait]
+pub(crate) trait FilterRuleService: Sync + Send {
+    async fn get_filter_rule_list(&self) -> Result<Vec<FilterRule>>;
+
+    async fn add_filter_rule(
+        &self,
+        feed_id: Option<Uuid>,
+        field: FilterField,
+        match_type: FilterMatchType,
+        pattern: &str,
+        action: FilterAction,
+    ) -> Result<FilterRule>;
+
+    async fn delete_filter_rule(&self, rule_id: Uuid) -> Result<()>;
+
+    /// Returns the already saved articles that would be matched by a rule. If `feed_id` is `None`
+    /// the articles of all the feeds are checked.
+    async fn test_filter_rule(
+        &self,
+        feed_id: Option<Uuid>,
+        field: FilterField,
+        match_type: FilterMatchType,
+        pattern: &str,
+    ) -> Result<Vec<Article>>;
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+
+use crate::{
+    controllers::ApiError,
+    services::{category::CategoryServiceError, feed::FeedServiceError},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GReaderServiceError {
+    #[error("invalid stream {0}")]
+    InvalidStream(String),
+
+    #[error("the stream {0} was not found")]
+    StreamNotFound(String),
+
+    #[error("the tag {0} can not be added to or removed from items")]
+    InvalidTag(String),
+
+    #[error("invalid continuation {0}")]
+    InvalidContinuation(String),
+
+    #[error("invalid feed url {0}")]
+    InvalidUrl(String),
+
+    #[error("a feed error ocurred: {0}")]
+    Feed(#[from] FeedServiceError),
+
+    #[error("a category error ocurred: {0}")]
+    Category(#[from] CategoryServiceError),
+}
+
+impl From<GReaderServiceError> for ApiError {
+    fn from(error: GReaderServiceError) -> Self {
+        match error {
+            GReaderServiceError::Feed(e) => e.into(),
+
+            GReaderServiceError::Category(e) => e.into(),
+
+            e @ GReaderServiceError::StreamNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+        }
+    }
+}
@@ -0,0 +1,345 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::{
+    error::GReaderServiceError, GReaderService, Result, StreamId, StreamQuery, MAX_ITEMS,
+};
+use crate::{
+    config::Config,
+    models::{article::Article, article_filter::ArticleFilter, category::Category, feed::Feed},
+    services::{category::CategoryService, feed::FeedService},
+};
+
+pub struct GReaderServiceImpl<FS, CS> {
+    feed_service: Arc<FS>,
+    category_service: Arc<CS>,
+    config: Arc<Config>,
+    /// Token given to the clients when they log in, None if the credentials are not configured
+    auth_token: Option<String>,
+}
+
+impl<FS, CS> GReaderServiceImpl<FS, CS> {
+    pub fn new(feed_service: Arc<FS>, category_service: Arc<CS>, config: Arc<Config>) -> Self {
+        // The token is derived from the credentials, so it is valid until they change
+        let auth_token = config
+            .api_username
+            .as_ref()
+            .zip(config.api_password.as_ref())
+            .map(|(username, password)| {
+                format!(
+                    "{:x}",
+                    Sha256::digest(format!("greader:{username}:{password}"))
+                )
+            });
+
+        Self {
+            feed_service,
+            category_service,
+            config,
+            auth_token,
+        }
+    }
+}
+
+/// Finds a feed by its numeric id or url
+async fn get_feed(feed_service: &impl FeedService, feed: &str) -> Result<Feed> {
+    feed_service
+        .get_feed_list()
+        .await?
+        .into_iter()
+        .find(|f| f.numeric_id.to_string() == feed || f.url == feed)
+        .ok_or_else(|| GReaderServiceError::StreamNotFound(format!("feed/{feed}")))
+}
+
+async fn get_category(
+    category_service: &impl CategoryService,
+    name: &str,
+) -> Result<Option<Category>> {
+    Ok(category_service
+        .get_category_list()
+        .await?
+        .into_iter()
+        .find(|category| category.name == name))
+}
+
+async fn get_or_add_category(
+    category_service: &impl CategoryService,
+    name: &str,
+) -> Result<Category> {
+    match get_category(category_service, name).await? {
+        Some(category) => Ok(category),
+        None => Ok(category_service.add_category(name).await?),
+    }
+}
+
+/// Returns the ids of the feeds of a stream, or None if the stream includes all of them
+async fn get_feed_ids(
+    feed_service: &impl FeedService,
+    category_service: &impl CategoryService,
+    stream: &StreamId,
+) -> Result<Option<Vec<Uuid>>> {
+    match stream {
+        StreamId::Feed(feed) => Ok(Some(vec![get_feed(feed_service, feed).await?.id])),
+        StreamId::Label(label) => {
+            let category = get_category(category_service, label)
+                .await?
+                .ok_or_else(|| GReaderServiceError::StreamNotFound(stream.to_string()))?;
+
+            let feed_ids = feed_service
+                .get_feed_list()
+                .await?
+                .into_iter()
+                .filter(|feed| feed.category_id == Some(category.id))
+                .map(|feed| feed.id)
+                .collect();
+
+            Ok(Some(feed_ids))
+        }
+        StreamId::ReadingList | StreamId::Read | StreamId::Starred | StreamId::KeptUnread => {
+            Ok(None)
+        }
+    }
+}
+
+/// Restricts the filter to the items that have (or do not have) a state tag
+fn filter_by_tag(filter: &mut ArticleFilter, tag: &StreamId, has_tag: bool) -> Result<()> {
+    match tag {
+        StreamId::ReadingList => {}
+        StreamId::Read => filter.read = Some(has_tag),
+        StreamId::KeptUnread => filter.read = Some(!has_tag),
+        StreamId::Starred => filter.starred = Some(has_tag),
+        StreamId::Feed(_) | StreamId::Label(_) => {
+            return Err(GReaderServiceError::InvalidTag(tag.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<FS, CS> GReaderService for GReaderServiceImpl<FS, CS>
+where
+    FS: FeedService,
+    CS: CategoryService,
+{
+    fn login(&self, username: &str, password: &str) -> Option<String> {
+        let valid = self.config.api_username.as_deref() == Some(username)
+            && self.config.api_password.as_deref() == Some(password);
+
+        valid.then(|| self.auth_token.clone()).flatten()
+    }
+
+    fn is_authenticated(&self, token: &str) -> bool {
+        self.auth_token.as_deref() == Some(token)
+    }
+
+    fn username(&self) -> &str {
+        self.config.api_username.as_deref().unwrap_or_default()
+    }
+
+    async fn get_subscriptions(&self) -> Result<Vec<(Feed, Option<Category>)>> {
+        let categories = self.category_service.get_category_list().await?;
+
+        Ok(self
+            .feed_service
+            .get_feed_list()
+            .await?
+            .into_iter()
+            .map(|feed| {
+                let category = categories
+                    .iter()
+                    .find(|category| Some(category.id) == feed.category_id)
+                    .cloned();
+                (feed, category)
+            })
+            .collect())
+    }
+
+    async fn get_labels(&self) -> Result<Vec<Category>> {
+        Ok(self.category_service.get_category_list().await?)
+    }
+
+    async fn get_stream(&self, query: &StreamQuery) -> Result<(Vec<Article>, Option<String>)> {
+        let offset = match &query.continuation {
+            Some(continuation) => continuation
+                .parse()
+                .map_err(|_| GReaderServiceError::InvalidContinuation(continuation.clone()))?,
+            None => 0,
+        };
+        let count = query.count.clamp(1, MAX_ITEMS);
+
+        // One more item is requested to know if there is a next page
+        let mut filter = ArticleFilter {
+            feed_ids: get_feed_ids(&*self.feed_service, &*self.category_service, &query.stream).await?,
+            published_after: query.newer_than,
+            published_before: query.older_than,
+            oldest_first: query.oldest_first,
+            offset,
+            limit: Some(count + 1),
+            ..Default::default()
+        };
+        // The feeds and labels streams are already filtered by their feed ids
+        if !matches!(query.stream, StreamId::Feed(_) | StreamId::Label(_)) {
+            filter_by_tag(&mut filter, &query.stream, true)?;
+        }
+        if let Some(include) = &query.include {
+            filter_by_tag(&mut filter, include, true)?;
+        }
+        if let Some(exclude) = &query.exclude {
+            filter_by_tag(&mut filter, exclude, false)?;
+        }
+
+        let mut articles = self.feed_service.get_articles(&filter).await?;
+        let continuation = (articles.len() > count).then(|| (offset + count).to_string());
+        articles.truncate(count);
+
+        Ok((articles, continuation))
+    }
+
+    async fn get_items(&self, numeric_ids: &[i64]) -> Result<Vec<Article>> {
+        Ok(self
+            .feed_service
+            .get_articles_by_numeric_ids(numeric_ids)
+            .await?)
+    }
+
+    async fn get_item_html(&self, article: &Article) -> Result<String> {
+        let content = self
+            .feed_service
+            .get_cached_content(article.feed_id, article.id)
+            .await?;
+
+        Ok(article.content_or_summary_html(content))
+    }
+
+    async fn edit_tags(
+        &self,
+        numeric_ids: &[i64],
+        add: &[StreamId],
+        remove: &[StreamId],
+    ) -> Result<()> {
+        // Only the state tags can be edited, labels belong to the feeds
+        if let Some(tag) = add.iter().chain(remove).find(|tag| {
+            !matches!(tag, StreamId::Read | StreamId::Starred | StreamId::KeptUnread)
+        }) {
+            return Err(GReaderServiceError::InvalidTag(tag.to_string()));
+        }
+
+        // Clients send the changes made offline, an item can be deleted in the meantime, so
+        // missing items are ignored
+        let articles = self
+            .feed_service
+            .get_articles_by_numeric_ids(numeric_ids)
+            .await?;
+
+        let changes = add
+            .iter()
+            .map(|tag| (tag, true))
+            .chain(remove.iter().map(|tag| (tag, false)));
+        for (tag, added) in changes {
+            for article in &articles {
+                let (feed_id, article_id) = (article.feed_id, article.id);
+                match (tag, added) {
+                    (StreamId::Read, true) | (StreamId::KeptUnread, false) => {
+                        self.feed_service
+                            .mark_article_as_read(feed_id, article_id)
+                            .await?
+                    }
+                    (StreamId::Read, false) | (StreamId::KeptUnread, true) => {
+                        self.feed_service
+                            .mark_article_as_unread(feed_id, article_id)
+                            .await?
+                    }
+                    (_, starred) => {
+                        self.feed_service
+                            .set_article_starred(feed_id, article_id, starred)
+                            .await?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn mark_all_as_read(
+        &self,
+        stream: &StreamId,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let before = before.unwrap_or_else(Utc::now);
+
+        match (stream, get_feed_ids(&*self.feed_service, &*self.category_service, stream).await?) {
+            (StreamId::ReadingList, _) => {
+                self.feed_service.mark_articles_as_read(None, before).await?;
+            }
+            (StreamId::Feed(_) | StreamId::Label(_), Some(feed_ids)) => {
+                for feed_id in feed_ids {
+                    self.feed_service
+                        .mark_articles_as_read(Some(feed_id), before)
+                        .await?;
+                }
+            }
+            _ => return Err(GReaderServiceError::InvalidStream(stream.to_string())),
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, url: &str, label: Option<&str>) -> Result<Feed> {
+        let url = Url::parse(url).map_err(|_| GReaderServiceError::InvalidUrl(url.to_owned()))?;
+
+        let category_id = match label {
+            Some(label) => Some(get_or_add_category(&*self.category_service, label).await?.id),
+            None => None,
+        };
+
+        if let Ok(feed) = get_feed(&*self.feed_service, url.as_str()).await {
+            return Ok(feed);
+        }
+
+        Ok(self.feed_service.add_feed(url, category_id).await?)
+    }
+
+    async fn unsubscribe(&self, stream: &StreamId) -> Result<()> {
+        let StreamId::Feed(feed) = stream else {
+            return Err(GReaderServiceError::InvalidStream(stream.to_string()));
+        };
+
+        let feed = get_feed(&*self.feed_service, feed).await?;
+        Ok(self.feed_service.delete_feed(feed.id).await?)
+    }
+
+    async fn edit_label(
+        &self,
+        stream: &StreamId,
+        add: Option<&str>,
+        remove: Option<&str>,
+    ) -> Result<()> {
+        let StreamId::Feed(feed) = stream else {
+            return Err(GReaderServiceError::InvalidStream(stream.to_string()));
+        };
+        let feed = get_feed(&*self.feed_service, feed).await?;
+
+        if let Some(label) = add {
+            let category = get_or_add_category(&*self.category_service, label).await?;
+            self.feed_service
+                .update_feed_category(feed.id, Some(category.id))
+                .await?;
+        } else if let Some(label) = remove {
+            let category = get_category(&*self.category_service, label).await?;
+            if category.is_some_and(|category| feed.category_id == Some(category.id)) {
+                self.feed_service.update_feed_category(feed.id, None).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Backend of the Google Reader API, used by third-party reader apps. Feeds are subscriptions,
+//! categories are labels and the read and starred states of the articles are tags of the items
+mod error;
+mod greader_service_impl;
+
+use std::{fmt::Display, str::FromStr};
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+
+use error::GReaderServiceError;
+pub use greader_service_impl::GReaderServiceImpl;
+
+use crate::models::{article::Article, category::Category, feed::Feed};
+
+pub(crate) type Result<T> = std::result::Result<T, GReaderServiceError>;
+
+/// Number of items returned in a stream request when the client does not specify it
+pub const DEFAULT_ITEMS: usize = 20;
+
+/// Maximum number of items returned in a stream request
+pub const MAX_ITEMS: usize = 1000;
+
+const ITEM_ID_PREFIX: &str = "tag:google.com,2005:reader/item/";
+
+/// Returns the long form of an item id, the hexadecimal numeric id of the article
+pub fn item_id(numeric_id: i64) -> String {
+    format!("{ITEM_ID_PREFIX}{numeric_id:016x}")
+}
+
+/// Parses an item id, either in its long form or in its short form (the decimal numeric id)
+pub fn parse_item_id(id: &str) -> Option<i64> {
+    match id.strip_prefix(ITEM_ID_PREFIX) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok().map(|id| id as i64),
+        None => id.parse().ok(),
+    }
+}
+
+/// Identifier of a stream (a list of items), that is also used to tag the items and the
+/// subscriptions. The user part of the ids (`user/<user id>/...`) is ignored, there is only one
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamId {
+    /// All the items
+    ReadingList,
+    Read,
+    Starred,
+    /// Tag used by some clients to mark an item as unread
+    KeptUnread,
+    /// A feed, identified by its numeric id or url
+    Feed(String),
+    /// A category, identified by its name
+    Label(String),
+}
+
+impl StreamId {
+    pub fn feed(feed: &Feed) -> Self {
+        Self::Feed(feed.numeric_id.to_string())
+    }
+}
+
+impl FromStr for StreamId {
+    type Err = GReaderServiceError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some(feed) = s.strip_prefix("feed/") {
+            return Ok(Self::Feed(feed.to_owned()));
+        }
+
+        let invalid = || GReaderServiceError::InvalidStream(s.to_owned());
+        let (_, id) = s
+            .strip_prefix("user/")
+            .and_then(|s| s.split_once('/'))
+            .ok_or_else(invalid)?;
+
+        if let Some(label) = id.strip_prefix("label/") {
+            return Ok(Self::Label(label.to_owned()));
+        }
+
+        match id.strip_prefix("state/com.google/").ok_or_else(invalid)? {
+            "reading-list" => Ok(Self::ReadingList),
+            "read" => Ok(Self::Read),
+            "starred" => Ok(Self::Starred),
+            "kept-unread" => Ok(Self::KeptUnread),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for StreamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadingList => write!(f, "user/-/state/com.google/reading-list"),
+            Self::Read => write!(f, "user/-/state/com.google/read"),
+            Self::Starred => write!(f, "user/-/state/com.google/starred"),
+            Self::KeptUnread => write!(f, "user/-/state/com.google/kept-unread"),
+            Self::Feed(feed) => write!(f, "feed/{feed}"),
+            Self::Label(label) => write!(f, "user/-/label/{label}"),
+        }
+    }
+}
+
+/// Items of a stream requested by a client
+#[derive(Debug)]
+pub struct StreamQuery {
+    pub stream: StreamId,
+    /// Only items that do not have this tag (`xt` parameter)
+    pub exclude: Option<StreamId>,
+    /// Only items that have this tag (`it` parameter)
+    pub include: Option<StreamId>,
+    pub count: usize,
+    pub oldest_first: bool,
+    pub newer_than: Option<DateTime<Utc>>,
+    pub older_than: Option<DateTime<Utc>>,
+    /// Returned with the previous page of items, to get the next one
+    pub continuation: Option<String>,
+}
+
+#[async_trait]
+pub(crate) trait GReaderService: Sync + Send {
+    /// Checks the credentials of a client and returns its auth token
+    fn login(&self, username: &str, password: &str) -> Option<String>;
+
+    fn is_authenticated(&self, token: &str) -> bool;
+
+    fn username(&self) -> &str;
+
+    /// Returns the feeds with their category
+    async fn get_subscriptions(&self) -> Result<Vec<(Feed, Option<Category>)>>;
+
+    async fn get_labels(&self) -> Result<Vec<Category>>;
+
+    /// Returns a page of items of a stream and the continuation to get the next one, if any
+    async fn get_stream(&self, query: &StreamQuery) -> Result<(Vec<Article>, Option<String>)>;
+
+    async fn get_items(&self, numeric_ids: &[i64]) -> Result<Vec<Article>>;
+
+    /// Returns the HTML content of an item, or its summary if it was not downloaded
+    async fn get_item_html(&self, article: &Article) -> Result<String>;
+
+    /// Adds and removes the read and starred tags of the given items
+    async fn edit_tags(&self, numeric_ids: &[i64], add: &[StreamId], remove: &[StreamId])
+        -> Result<()>;
+
+    /// Marks the items of a stream found before the given date as read
+    async fn mark_all_as_read(&self, stream: &StreamId, before: Option<DateTime<Utc>>)
+        -> Result<()>;
+
+    /// Adds a feed, creating its label (category) if it does not exist
+    async fn subscribe(&self, url: &str, label: Option<&str>) -> Result<Feed>;
+
+    async fn unsubscribe(&self, stream: &StreamId) -> Result<()>;
+
+    /// Adds a label to or removes it from a feed. A feed can only have one label (category), so
+    /// adding a label replaces the current one
+    async fn edit_label(
+        &self,
+        stream: &StreamId,
+        add: Option<&str>,
+        remove: Option<&str>,
+    ) -> Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_id() {
+        assert_eq!(item_id(42), "tag:google.com,2005:reader/item/000000000000002a");
+        assert_eq!(parse_item_id(&item_id(42)), Some(42));
+        assert_eq!(parse_item_id("42"), Some(42));
+        assert_eq!(parse_item_id("tag:google.com,2005:reader/item/xyz"), None);
+        assert_eq!(parse_item_id("abc"), None);
+    }
+
+    #[test]
+    fn test_stream_id() {
+        let cases = [
+            ("user/-/state/com.google/reading-list", StreamId::ReadingList),
+            ("user/1/state/com.google/read", StreamId::Read),
+            ("user/-/state/com.google/starred", StreamId::Starred),
+            ("user/-/label/Tech news", StreamId::Label("Tech news".to_owned())),
+            ("feed/3", StreamId::Feed("3".to_owned())),
+            ("feed/https://example.com/rss", StreamId::Feed("https://example.com/rss".to_owned())),
+        ];
+
+        for (id, stream) in cases {
+            assert_eq!(id.parse::<StreamId>().unwrap(), stream);
+        }
+
+        assert_eq!(StreamId::Read.to_string(), "user/-/state/com.google/read");
+        assert!("user/-/state/com.google/broadcast".parse::<StreamId>().is_err());
+        assert!("user/-".parse::<StreamId>().is_err());
+        assert!("something".parse::<StreamId>().is_err());
+    }
+}
@@ -1,5 +1,19 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+pub mod category;
+pub mod digest;
+pub mod email;
+pub mod export;
 pub mod feed;
+pub mod feed_rules;
+pub mod fever;
+pub mod greader;
+pub mod filter_rule;
 pub mod persisted_config;
+pub mod revision;
+pub mod rewrite_rule;
+pub mod save_to;
+pub mod tag;
 pub mod templates;
+pub mod webhook;
+pub mod websub;
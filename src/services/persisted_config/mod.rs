@@ -7,7 +7,9 @@ use axum::async_trait;
 
 use error::PersistedConfigError;
 
-use crate::models::persisted_config::PersistedConfig;
+use crate::models::persisted_config::{
+    ArticleSort, DigestConfig, PersistedConfig, SaveEndpointConfig, SmtpConfig, WallabagConfig,
+};
 
 pub use persisted_config_service_impl::PersistedConfigServiceImpl;
 
@@ -26,4 +28,16 @@ pub trait PersistedConfigService: Sync + Send {
     async fn set_hide_article_header(&self, value: bool) -> Result<()>;
 
     async fn set_dont_invert_images(&self, value: bool) -> Result<()>;
+
+    async fn set_show_thumbnails(&self, value: bool) -> Result<()>;
+
+    async fn set_article_sort(&self, value: ArticleSort) -> Result<()>;
+
+    async fn set_digest(&self, value: DigestConfig) -> Result<()>;
+
+    async fn set_smtp(&self, value: SmtpConfig) -> Result<()>;
+
+    async fn set_wallabag(&self, value: WallabagConfig) -> Result<()>;
+
+    async fn set_save_endpoint(&self, value: SaveEndpointConfig) -> Result<()>;
 }
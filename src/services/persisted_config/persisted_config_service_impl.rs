@@ -5,7 +5,9 @@ use std::sync::Arc;
 use axum::async_trait;
 
 use crate::{
-    models::persisted_config::PersistedConfig,
+    models::persisted_config::{
+        ArticleSort, DigestConfig, PersistedConfig, SaveEndpointConfig, SmtpConfig, WallabagConfig,
+    },
     providers::persisted_config::PersistedConfigProvider,
     repositories::persisted_config::PersistedConfigRepository,
 };
@@ -95,4 +97,60 @@ where
             .save_configuration(config)
             .await?)
     }
+
+    async fn set_show_thumbnails(&self, value: bool) -> Result<()> {
+        let config = self
+            .persisted_config_provider
+            .set_show_thumbnails(value)
+            .await?;
+
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
+
+    async fn set_article_sort(&self, value: ArticleSort) -> Result<()> {
+        let config = self
+            .persisted_config_provider
+            .set_article_sort(value)
+            .await?;
+
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
+
+    async fn set_digest(&self, value: DigestConfig) -> Result<()> {
+        let config = self.persisted_config_provider.set_digest(value).await?;
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
+
+    async fn set_smtp(&self, value: SmtpConfig) -> Result<()> {
+        let config = self.persisted_config_provider.set_smtp(value).await?;
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
+
+    async fn set_wallabag(&self, value: WallabagConfig) -> Result<()> {
+        let config = self.persisted_config_provider.set_wallabag(value).await?;
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
+
+    async fn set_save_endpoint(&self, value: SaveEndpointConfig) -> Result<()> {
+        let config = self.persisted_config_provider.set_save_endpoint(value).await?;
+        Ok(self
+            .persisted_config_repository
+            .save_configuration(config)
+            .await?)
+    }
 }
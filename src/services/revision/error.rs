@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RevisionServiceError {
+    #[error("the article revision {0} was not found")]
+    RevisionNotFound(Uuid),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<RevisionServiceError> for ApiError {
+    fn from(error: RevisionServiceError) -> Self {
+        match error {
+            e @ RevisionServiceError::RevisionNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
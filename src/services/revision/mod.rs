@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Previous versions of the articles. The feed service keeps the old content as a revision when
+//! the publisher updates an article, and this service reads them
+mod error;
+mod revision_service_impl;
+
+use axum::async_trait;
+
+use error::RevisionServiceError;
+pub use revision_service_impl::RevisionServiceImpl;
+use uuid::Uuid;
+
+use crate::models::article_revision::ArticleRevision;
+
+pub(crate) type Result<T> = std::result::Result<T, RevisionServiceError>;
+
+#[async_trait]
+pub(crate) trait RevisionService: Sync + Send {
+    /// Returns the previous versions of an article that was updated by the publisher, the most
+    /// recent first
+    async fn get_article_revisions(&self, article_id: Uuid) -> Result<Vec<ArticleRevision>>;
+
+    /// Returns a previous version of an article with its content
+    async fn get_article_revision(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<(ArticleRevision, String)>;
+}
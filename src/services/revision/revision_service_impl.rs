@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use super::{error::RevisionServiceError, Result, RevisionService};
+use crate::{
+    models::article_revision::ArticleRevision,
+    repositories::feed_content::FeedContentRepository,
+};
+
+pub struct RevisionServiceImpl<FCR>
+where
+    FCR: FeedContentRepository,
+{
+    feed_content_repository: Arc<FCR>,
+}
+
+impl<FCR> RevisionServiceImpl<FCR>
+where
+    FCR: FeedContentRepository,
+{
+    pub fn new(feed_content_repository: Arc<FCR>) -> Self {
+        Self {
+            feed_content_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<FCR> RevisionService for RevisionServiceImpl<FCR>
+where
+    FCR: FeedContentRepository,
+{
+    async fn get_article_revisions(&self, article_id: Uuid) -> Result<Vec<ArticleRevision>> {
+        Ok(self
+            .feed_content_repository
+            .get_article_revisions(article_id)
+            .await?)
+    }
+
+    async fn get_article_revision(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<(ArticleRevision, String)> {
+        self.feed_content_repository
+            .get_article_revision(feed_id, article_id, revision_id)
+            .await?
+            .ok_or(RevisionServiceError::RevisionNotFound(revision_id))
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RewriteRuleServiceError {
+    #[error("the feed {0} was not found")]
+    FeedNotFound(Uuid),
+
+    #[error("the rewrite rule {0} was not found")]
+    RewriteRuleNotFound(Uuid),
+
+    #[error("invalid rewrite pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<RewriteRuleServiceError> for ApiError {
+    fn from(error: RewriteRuleServiceError) -> Self {
+        match error {
+            e @ (RewriteRuleServiceError::FeedNotFound(_)
+            | RewriteRuleServiceError::RewriteRuleNotFound(_)) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e @ RewriteRuleServiceError::InvalidPattern(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod error;
+mod rewrite_rule_service_impl;
+
+use axum::async_trait;
+
+use error::RewriteRuleServiceError;
+pub use rewrite_rule_service_impl::RewriteRuleServiceImpl;
+use uuid::Uuid;
+
+use crate::models::rewrite_rule::{RewriteRule, RewriteRuleKind};
+
+pub(crate) type Result<T> = std::result::Result<T, RewriteRuleServiceError>;
+
+#[async_trait]
+pub(crate) trait RewriteRuleService: Sync + Send {
+    /// Returns the rules of a feed in the order they are applied
+    async fn get_feed_rewrite_rules(&self, feed_id: Uuid) -> Result<Vec<RewriteRule>>;
+
+    async fn add_rewrite_rule(
+        &self,
+        feed_id: Uuid,
+        kind: RewriteRuleKind,
+        pattern: &str,
+        replacement: Option<String>,
+    ) -> Result<RewriteRule>;
+
+    async fn delete_rewrite_rule(&self, feed_id: Uuid, rule_id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use regex::Regex;
+use scraper::Selector;
+use uuid::Uuid;
+
+use super::{error::RewriteRuleServiceError, Result, RewriteRuleService};
+use crate::{
+    models::rewrite_rule::{RewriteRule, RewriteRuleKind},
+    repositories::{feed::FeedRepository, rewrite_rule::RewriteRuleRepository},
+};
+
+pub struct RewriteRuleServiceImpl<RWR, FR>
+where
+    RWR: RewriteRuleRepository,
+    FR: FeedRepository,
+{
+    rewrite_rule_repository: Arc<RWR>,
+    feed_repository: Arc<FR>,
+}
+
+impl<RWR, FR> RewriteRuleServiceImpl<RWR, FR>
+where
+    RWR: RewriteRuleRepository,
+    FR: FeedRepository,
+{
+    pub fn new(rewrite_rule_repository: Arc<RWR>, feed_repository: Arc<FR>) -> Self {
+        Self {
+            rewrite_rule_repository,
+            feed_repository,
+        }
+    }
+
+    fn validate_pattern(kind: RewriteRuleKind, pattern: &str) -> Result<String> {
+        if pattern.trim().is_empty() {
+            return Err(RewriteRuleServiceError::InvalidPattern(
+                "the pattern can not be empty".to_owned(),
+            ));
+        }
+
+        match kind {
+            RewriteRuleKind::RegexReplace => {
+                Regex::new(pattern)
+                    .map_err(|e| RewriteRuleServiceError::InvalidPattern(e.to_string()))?;
+            }
+            RewriteRuleKind::RemoveSelector => {
+                Selector::parse(pattern)
+                    .map_err(|e| RewriteRuleServiceError::InvalidPattern(format!("{e:?}")))?;
+            }
+            RewriteRuleKind::StripUrlParams => {}
+        }
+
+        Ok(pattern.to_owned())
+    }
+
+    async fn check_feed_exists(&self, feed_id: Uuid) -> Result<()> {
+        if self.feed_repository.get_feed(feed_id).await?.is_none() {
+            return Err(RewriteRuleServiceError::FeedNotFound(feed_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<RWR, FR> RewriteRuleService for RewriteRuleServiceImpl<RWR, FR>
+where
+    RWR: RewriteRuleRepository,
+    FR: FeedRepository,
+{
+    async fn get_feed_rewrite_rules(&self, feed_id: Uuid) -> Result<Vec<RewriteRule>> {
+        Ok(self
+            .rewrite_rule_repository
+            .get_feed_rewrite_rules(feed_id)
+            .await?)
+    }
+
+    async fn add_rewrite_rule(
+        &self,
+        feed_id: Uuid,
+        kind: RewriteRuleKind,
+        pattern: &str,
+        replacement: Option<String>,
+    ) -> Result<RewriteRule> {
+        self.check_feed_exists(feed_id).await?;
+
+        let rule = RewriteRule {
+            id: Uuid::new_v4(),
+            feed_id,
+            kind,
+            pattern: Self::validate_pattern(kind, pattern)?,
+            // An empty replacement removes the matches, so we keep it as is
+            replacement: match kind {
+                RewriteRuleKind::RegexReplace => Some(replacement.unwrap_or_default()),
+                _ => None,
+            },
+        };
+
+        self.rewrite_rule_repository.add_rewrite_rule(&rule).await?;
+
+        Ok(rule)
+    }
+
+    async fn delete_rewrite_rule(&self, feed_id: Uuid, rule_id: Uuid) -> Result<()> {
+        match self
+            .rewrite_rule_repository
+            .get_rewrite_rule(rule_id)
+            .await?
+        {
+            Some(rule) if rule.feed_id == feed_id => Ok(self
+                .rewrite_rule_repository
+                .delete_rewrite_rule(rule_id)
+                .await?),
+            _ => Err(RewriteRuleServiceError::RewriteRuleNotFound(rule_id)),
+        }
+    }
+}
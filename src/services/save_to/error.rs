@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    controllers::ApiError,
+    models::saved_article::SaveTarget,
+    providers::{persisted_config::PersistedConfigProviderError, save_to::SaveToProviderError},
+    repositories::RepositoryError,
+    services::feed::FeedServiceError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SaveToServiceError {
+    #[error("the {} is not configured", .0.display_name())]
+    NotConfigured(SaveTarget),
+
+    #[error("the article {0} was not found")]
+    ArticleNotFound(Uuid),
+
+    #[error("unable to save the article to the {target}: {1}", target = .0.display_name())]
+    Provider(SaveTarget, SaveToProviderError),
+
+    #[error("a feed error ocurred: {0}")]
+    Feed(#[from] FeedServiceError),
+
+    #[error("unable to read the settings: {0}")]
+    Config(#[from] PersistedConfigProviderError),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<SaveToServiceError> for ApiError {
+    fn from(error: SaveToServiceError) -> Self {
+        match error {
+            e @ SaveToServiceError::NotConfigured(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e @ SaveToServiceError::ArticleNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e @ SaveToServiceError::Provider(_, _) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_GATEWAY,
+            },
+
+            SaveToServiceError::Feed(e) => e.into(),
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Saves the articles to read-it-later services and keeps a record of where each one was sent
+mod error;
+mod save_to_service_impl;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+pub use error::SaveToServiceError;
+pub use save_to_service_impl::SaveToServiceImpl;
+
+use crate::models::saved_article::{SaveTarget, SavedArticle};
+
+pub(crate) type Result<T> = std::result::Result<T, SaveToServiceError>;
+
+#[async_trait]
+pub(crate) trait SaveToService: Sync + Send {
+    /// Returns the services the article was saved to, the most recent first
+    async fn get_saved_article_list(&self, article_id: Uuid) -> Result<Vec<SavedArticle>>;
+
+    /// Saves the article to the service and records it
+    async fn save_article(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        target: SaveTarget,
+    ) -> Result<SavedArticle>;
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{Result, SaveToService, SaveToServiceError};
+use crate::{
+    models::{
+        persisted_config::{SaveEndpointConfig, WallabagConfig},
+        saved_article::{SaveTarget, SavedArticle},
+    },
+    providers::{persisted_config::PersistedConfigProvider, save_to::SaveToProvider},
+    repositories::saved_article::SavedArticleRepository,
+    services::feed::FeedService,
+};
+
+pub struct SaveToServiceImpl<SAR, FS, PCP, WBP, SEP> {
+    saved_article_repository: Arc<SAR>,
+    feed_service: Arc<FS>,
+    persisted_config_provider: Arc<PCP>,
+    wallabag_provider: Arc<WBP>,
+    save_endpoint_provider: Arc<SEP>,
+}
+
+impl<SAR, FS, PCP, WBP, SEP> SaveToServiceImpl<SAR, FS, PCP, WBP, SEP> {
+    pub fn new(
+        saved_article_repository: Arc<SAR>,
+        feed_service: Arc<FS>,
+        persisted_config_provider: Arc<PCP>,
+        wallabag_provider: Arc<WBP>,
+        save_endpoint_provider: Arc<SEP>,
+    ) -> Self {
+        Self {
+            saved_article_repository,
+            feed_service,
+            persisted_config_provider,
+            wallabag_provider,
+            save_endpoint_provider,
+        }
+    }
+}
+
+#[async_trait]
+impl<SAR, FS, PCP, WBP, SEP> SaveToService for SaveToServiceImpl<SAR, FS, PCP, WBP, SEP>
+where
+    SAR: SavedArticleRepository,
+    FS: FeedService,
+    PCP: PersistedConfigProvider,
+    WBP: SaveToProvider<Settings = WallabagConfig>,
+    SEP: SaveToProvider<Settings = SaveEndpointConfig>,
+{
+    async fn get_saved_article_list(&self, article_id: Uuid) -> Result<Vec<SavedArticle>> {
+        Ok(self
+            .saved_article_repository
+            .get_saved_article_list(article_id)
+            .await?)
+    }
+
+    async fn save_article(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        target: SaveTarget,
+    ) -> Result<SavedArticle> {
+        let article = self
+            .feed_service
+            .get_article(feed_id, article_id)
+            .await?
+            .ok_or(SaveToServiceError::ArticleNotFound(article_id))?;
+        let config = self.persisted_config_provider.get_configuration().await?;
+
+        let result = match target {
+            SaveTarget::Wallabag if config.wallabag.is_configured() => {
+                self.wallabag_provider.save(&config.wallabag, &article).await
+            }
+            SaveTarget::Endpoint if config.save_endpoint.is_configured() => {
+                self.save_endpoint_provider
+                    .save(&config.save_endpoint, &article)
+                    .await
+            }
+            _ => return Err(SaveToServiceError::NotConfigured(target)),
+        };
+        let url = result.map_err(|e| SaveToServiceError::Provider(target, e))?;
+
+        let saved_article = SavedArticle {
+            id: Uuid::new_v4(),
+            article_id,
+            feed_id,
+            target,
+            saved_at: Utc::now(),
+            url,
+        };
+        self.saved_article_repository
+            .add_saved_article(&saved_article)
+            .await?;
+
+        Ok(saved_article)
+    }
+}
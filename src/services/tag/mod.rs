@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+mod error;
+mod tag_service_impl;
+
+use axum::async_trait;
+
+use error::TagServiceError;
+pub use tag_service_impl::TagServiceImpl;
+use uuid::Uuid;
+
+use crate::models::{article::Article, tag::ArticleTag};
+
+pub(crate) type Result<T> = std::result::Result<T, TagServiceError>;
+
+#[async_trait]
+pub(crate) trait TagService: Sync + Send {
+    async fn get_article_tags(&self, article_id: Uuid) -> Result<Vec<ArticleTag>>;
+
+    /// Adds a user defined tag to an article
+    async fn add_article_tag(&self, feed_id: Uuid, article_id: Uuid, name: &str) -> Result<()>;
+
+    async fn delete_article_tag(&self, feed_id: Uuid, article_id: Uuid, name: &str)
+        -> Result<()>;
+
+    /// Returns the articles of all the feeds that have the tag, the most recent first
+    async fn get_tag_articles(&self, name: &str) -> Result<Vec<Article>>;
+}
@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::sync::Arc;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use super::{error::TagServiceError, Result, TagService};
+use crate::{
+    models::{article::Article, tag::ArticleTag},
+    repositories::{feed::FeedRepository, tag::TagRepository},
+};
+
+pub struct TagServiceImpl<TR, FR>
+where
+    TR: TagRepository,
+    FR: FeedRepository,
+{
+    tag_repository: Arc<TR>,
+    feed_repository: Arc<FR>,
+}
+
+impl<TR, FR> TagServiceImpl<TR, FR>
+where
+    TR: TagRepository,
+    FR: FeedRepository,
+{
+    pub fn new(tag_repository: Arc<TR>, feed_repository: Arc<FR>) -> Self {
+        Self {
+            tag_repository,
+            feed_repository,
+        }
+    }
+
+    async fn check_article_exists(&self, feed_id: Uuid, article_id: Uuid) -> Result<()> {
+        if self
+            .feed_repository
+            .get_article_description(feed_id, article_id)
+            .await?
+            .is_none()
+        {
+            return Err(TagServiceError::ArticleNotFound(article_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<TR, FR> TagService for TagServiceImpl<TR, FR>
+where
+    TR: TagRepository,
+    FR: FeedRepository,
+{
+    async fn get_article_tags(&self, article_id: Uuid) -> Result<Vec<ArticleTag>> {
+        Ok(self.tag_repository.get_article_tags(article_id).await?)
+    }
+
+    async fn add_article_tag(&self, feed_id: Uuid, article_id: Uuid, name: &str) -> Result<()> {
+        let name = ArticleTag::normalize_name(name).ok_or(TagServiceError::EmptyName)?;
+        self.check_article_exists(feed_id, article_id).await?;
+
+        let tag = ArticleTag {
+            article_id,
+            feed_id,
+            name,
+            user_defined: true,
+        };
+
+        Ok(self.tag_repository.add_article_tags(&[tag]).await?)
+    }
+
+    async fn delete_article_tag(
+        &self,
+        feed_id: Uuid,
+        article_id: Uuid,
+        name: &str,
+    ) -> Result<()> {
+        self.check_article_exists(feed_id, article_id).await?;
+
+        Ok(self
+            .tag_repository
+            .delete_article_tag(article_id, name)
+            .await?)
+    }
+
+    async fn get_tag_articles(&self, name: &str) -> Result<Vec<Article>> {
+        let Some(name) = ArticleTag::normalize_name(name) else {
+            return Ok(vec![]);
+        };
+
+        let mut articles = self.tag_repository.get_tag_articles(&name).await?;
+        articles.sort_by_key(|a| std::cmp::Reverse(a.published_at));
+
+        Ok(articles)
+    }
+}
@@ -14,33 +14,57 @@ pub(crate) type Result<T> = std::result::Result<T, TemplateServiceError>;
 
 pub const TEMPLATE_NAME_ARTICLE: &str = "article";
 pub const TEMPLATE_NAME_ARTICLE_LIST: &str = "article_list";
+pub const TEMPLATE_NAME_ARTICLE_RIVER: &str = "article_river";
+pub const TEMPLATE_NAME_CATEGORY_LIST: &str = "category_list";
 pub const TEMPLATE_NAME_COMMON_HEAD: &str = "common_head";
 pub const TEMPLATE_NAME_CONFIG: &str = "config";
 pub const TEMPLATE_NAME_DIALOG: &str = "dialog";
+pub const TEMPLATE_NAME_DIGESTS: &str = "digests";
 pub const TEMPLATE_NAME_FEED_ADD: &str = "feed_add";
 pub const TEMPLATE_NAME_FEED_LIST: &str = "feed_list";
+pub const TEMPLATE_NAME_FEED_SETTINGS: &str = "feed_settings";
 pub const TEMPLATE_NAME_ERROR: &str = "error";
+pub const TEMPLATE_NAME_FILTER_RULES: &str = "filter_rules";
+pub const TEMPLATE_NAME_OUTBOX: &str = "outbox";
+pub const TEMPLATE_NAME_SAVE_TO: &str = "save_to";
 pub const TEMPLATE_NAME_TOOLBAR: &str = "toolbar";
+pub const TEMPLATE_NAME_WEBHOOKS: &str = "webhooks";
 pub const TEMPLATE_PATH_ARTICLE: &str = "templates/article.html";
 pub const TEMPLATE_PATH_ARTICLE_LIST: &str = "templates/article_list.html";
+pub const TEMPLATE_PATH_ARTICLE_RIVER: &str = "templates/article_river.html";
+pub const TEMPLATE_PATH_CATEGORY_LIST: &str = "templates/category_list.html";
 pub const TEMPLATE_PATH_COMMON_HEAD: &str = "templates/common_head.html";
 pub const TEMPLATE_PATH_CONFIG: &str = "templates/config.html";
 pub const TEMPLATE_PATH_DIALOG: &str = "templates/dialog.html";
+pub const TEMPLATE_PATH_DIGESTS: &str = "templates/digests.html";
 pub const TEMPLATE_PATH_FEED_ADD: &str = "templates/feed_add.html";
 pub const TEMPLATE_PATH_FEED_LIST: &str = "templates/feed_list.html";
+pub const TEMPLATE_PATH_FEED_SETTINGS: &str = "templates/feed_settings.html";
 pub const TEMPLATE_PATH_ERROR: &str = "templates/error.html";
+pub const TEMPLATE_PATH_FILTER_RULES: &str = "templates/filter_rules.html";
+pub const TEMPLATE_PATH_OUTBOX: &str = "templates/outbox.html";
+pub const TEMPLATE_PATH_SAVE_TO: &str = "templates/save_to.html";
 pub const TEMPLATE_PATH_TOOLBAR: &str = "templates/toolbar.html";
+pub const TEMPLATE_PATH_WEBHOOKS: &str = "templates/webhooks.html";
 
-pub const TEMPLATES: [(&str, &str); 9] = [
+pub const TEMPLATES: [(&str, &str); 17] = [
     (TEMPLATE_NAME_ARTICLE, TEMPLATE_PATH_ARTICLE),
     (TEMPLATE_NAME_ARTICLE_LIST, TEMPLATE_PATH_ARTICLE_LIST),
+    (TEMPLATE_NAME_ARTICLE_RIVER, TEMPLATE_PATH_ARTICLE_RIVER),
+    (TEMPLATE_NAME_CATEGORY_LIST, TEMPLATE_PATH_CATEGORY_LIST),
     (TEMPLATE_NAME_COMMON_HEAD, TEMPLATE_PATH_COMMON_HEAD),
     (TEMPLATE_NAME_CONFIG, TEMPLATE_PATH_CONFIG),
     (TEMPLATE_NAME_DIALOG, TEMPLATE_PATH_DIALOG),
+    (TEMPLATE_NAME_DIGESTS, TEMPLATE_PATH_DIGESTS),
     (TEMPLATE_NAME_FEED_ADD, TEMPLATE_PATH_FEED_ADD),
     (TEMPLATE_NAME_FEED_LIST, TEMPLATE_PATH_FEED_LIST),
+    (TEMPLATE_NAME_FEED_SETTINGS, TEMPLATE_PATH_FEED_SETTINGS),
     (TEMPLATE_NAME_ERROR, TEMPLATE_PATH_ERROR),
+    (TEMPLATE_NAME_FILTER_RULES, TEMPLATE_PATH_FILTER_RULES),
+    (TEMPLATE_NAME_OUTBOX, TEMPLATE_PATH_OUTBOX),
+    (TEMPLATE_NAME_SAVE_TO, TEMPLATE_PATH_SAVE_TO),
     (TEMPLATE_NAME_TOOLBAR, TEMPLATE_PATH_TOOLBAR),
+    (TEMPLATE_NAME_WEBHOOKS, TEMPLATE_PATH_WEBHOOKS),
 ];
 
 #[async_trait]
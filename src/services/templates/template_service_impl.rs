@@ -60,7 +60,8 @@ where
             .persisted_config_provider
             .get_configuration()
             .await
-            .unwrap();
+            .unwrap()
+            .without_secrets();
 
         self.environment
             .get_template(name)
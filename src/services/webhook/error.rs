@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookServiceError {
+    #[error("the webhook {0} was not found")]
+    WebhookNotFound(Uuid),
+
+    #[error("invalid webhook url {0}")]
+    InvalidUrl(String),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<WebhookServiceError> for ApiError {
+    fn from(error: WebhookServiceError) -> Self {
+        match error {
+            e @ WebhookServiceError::WebhookNotFound(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e @ WebhookServiceError::InvalidUrl(_) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::BAD_REQUEST,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
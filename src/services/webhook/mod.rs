@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Outgoing webhooks. The feed service queues a delivery for every webhook that applies to a feed
+//! when new articles are found, and this service sends them in the background
+mod error;
+mod webhook_service_impl;
+
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use error::WebhookServiceError;
+pub use webhook_service_impl::WebhookServiceImpl;
+use uuid::Uuid;
+
+use crate::models::webhook::{Webhook, WebhookDelivery};
+
+pub(crate) type Result<T> = std::result::Result<T, WebhookServiceError>;
+
+/// Header with the HMAC-SHA256 signature of the body, when the webhook has a secret
+pub const SIGNATURE_HEADER: &str = "X-Kindly-Signature-256";
+
+/// Header with the id of the delivery, it is the same in all its attempts
+pub const DELIVERY_HEADER: &str = "X-Kindly-Delivery";
+
+/// How often the queue is checked for deliveries to send
+const DELIVERY_QUEUE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Deliveries shown in the log
+pub const DELIVERY_LOG_SIZE: u32 = 50;
+
+/// Days the finished deliveries are kept
+pub const DELIVERY_LOG_DAYS: i64 = 30;
+
+#[async_trait]
+pub(crate) trait WebhookService: Sync + Send {
+    async fn get_webhook_list(&self) -> Result<Vec<Webhook>>;
+
+    async fn add_webhook(
+        &self,
+        url: &str,
+        feed_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        secret: Option<String>,
+    ) -> Result<Webhook>;
+
+    async fn delete_webhook(&self, webhook_id: Uuid) -> Result<()>;
+
+    /// Returns the most recent deliveries, the newest first
+    async fn get_delivery_log(&self) -> Result<Vec<WebhookDelivery>>;
+
+    /// Sends the queued deliveries whose attempt is due and returns how many of them succeeded
+    async fn deliver_pending(&self) -> Result<usize>;
+
+    /// Deletes the finished deliveries older than `DELIVERY_LOG_DAYS`
+    async fn prune_delivery_log(&self) -> Result<()>;
+}
+
+/// Signature of a payload, in the `sha256=<hex digest>` format
+pub fn sign_payload(secret: &str, payload: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(payload.as_bytes());
+
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Sends the queued deliveries periodically. It never returns, so it must be spawned
+pub(crate) async fn run_delivery_queue<WS: WebhookService>(service: Arc<WS>) {
+    let mut interval = tokio::time::interval(DELIVERY_QUEUE_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = service.deliver_pending().await {
+            tracing::error!("there was an error sending the webhook deliveries: {e:?}");
+        }
+
+        if let Err(e) = service.prune_delivery_log().await {
+            tracing::error!("there was an error pruning the webhook delivery log: {e:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_signature() {
+        assert_eq!(
+            sign_payload("key", "The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use axum::async_trait;
+use chrono::{TimeDelta, Utc};
+use reqwest::{header::CONTENT_TYPE, Client, Url};
+use uuid::Uuid;
+
+use super::{
+    error::WebhookServiceError, sign_payload, Result, WebhookService, DELIVERY_HEADER,
+    DELIVERY_LOG_DAYS, DELIVERY_LOG_SIZE, SIGNATURE_HEADER,
+};
+use crate::{
+    models::webhook::{DeliveryStatus, Webhook, WebhookDelivery},
+    repositories::webhook::WebhookRepository,
+};
+
+/// Time to wait for the response of a webhook
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct WebhookServiceImpl<WHR>
+where
+    WHR: WebhookRepository,
+{
+    webhook_repository: Arc<WHR>,
+    client: Client,
+}
+
+impl<WHR> WebhookServiceImpl<WHR>
+where
+    WHR: WebhookRepository,
+{
+    pub fn new(webhook_repository: Arc<WHR>) -> Self {
+        let client = Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .expect("unable to initialize the webhooks http client");
+
+        Self {
+            webhook_repository,
+            client,
+        }
+    }
+
+    /// Makes an attempt to send the delivery and records its result in it
+    async fn attempt_delivery(&self, webhook: &Webhook, delivery: &mut WebhookDelivery) {
+        let mut request = self
+            .client
+            .post(&webhook.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(DELIVERY_HEADER, delivery.id.to_string());
+
+        if let Some(secret) = &webhook.secret {
+            request = request.header(SIGNATURE_HEADER, sign_payload(secret, &delivery.payload));
+        }
+
+        let (status, error) = match request.body(delivery.payload.clone()).send().await {
+            Ok(response) => (Some(response.status().as_u16()), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        delivery.record_attempt(Utc::now(), status, error);
+    }
+}
+
+#[async_trait]
+impl<WHR> WebhookService for WebhookServiceImpl<WHR>
+where
+    WHR: WebhookRepository,
+{
+    async fn get_webhook_list(&self) -> Result<Vec<Webhook>> {
+        Ok(self.webhook_repository.get_webhook_list().await?)
+    }
+
+    async fn add_webhook(
+        &self,
+        url: &str,
+        feed_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        secret: Option<String>,
+    ) -> Result<Webhook> {
+        let url = url.trim();
+        if !Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+            return Err(WebhookServiceError::InvalidUrl(url.to_owned()));
+        }
+
+        let webhook = Webhook {
+            id: Uuid::new_v4(),
+            url: url.to_owned(),
+            feed_id,
+            category_id,
+            secret: secret.filter(|secret| !secret.is_empty()),
+        };
+        self.webhook_repository.add_webhook(&webhook).await?;
+
+        Ok(webhook)
+    }
+
+    async fn delete_webhook(&self, webhook_id: Uuid) -> Result<()> {
+        self.webhook_repository
+            .get_webhook(webhook_id)
+            .await?
+            .ok_or(WebhookServiceError::WebhookNotFound(webhook_id))?;
+
+        Ok(self.webhook_repository.delete_webhook(webhook_id).await?)
+    }
+
+    async fn get_delivery_log(&self) -> Result<Vec<WebhookDelivery>> {
+        Ok(self
+            .webhook_repository
+            .get_delivery_list(DELIVERY_LOG_SIZE)
+            .await?)
+    }
+
+    async fn deliver_pending(&self) -> Result<usize> {
+        let deliveries = self
+            .webhook_repository
+            .get_due_deliveries(Utc::now())
+            .await?;
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        let webhooks: HashMap<Uuid, Webhook> = self
+            .webhook_repository
+            .get_webhook_list()
+            .await?
+            .into_iter()
+            .map(|webhook| (webhook.id, webhook))
+            .collect();
+
+        let mut delivered = 0;
+        for mut delivery in deliveries {
+            // The deliveries are deleted with their webhook
+            let Some(webhook) = webhooks.get(&delivery.webhook_id) else {
+                continue;
+            };
+
+            self.attempt_delivery(webhook, &mut delivery).await;
+            match delivery.status {
+                DeliveryStatus::Delivered => delivered += 1,
+                DeliveryStatus::Failed => tracing::warn!(
+                    "giving up delivery {} to webhook {}: {:?}",
+                    delivery.id,
+                    webhook.url,
+                    delivery.error
+                ),
+                DeliveryStatus::Pending => {}
+            }
+
+            self.webhook_repository.update_delivery(&delivery).await?;
+        }
+
+        Ok(delivered)
+    }
+
+    async fn prune_delivery_log(&self) -> Result<()> {
+        Ok(self
+            .webhook_repository
+            .delete_deliveries_before(Utc::now() - TimeDelta::days(DELIVERY_LOG_DAYS))
+            .await?)
+    }
+}
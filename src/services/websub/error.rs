@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{controllers::ApiError, repositories::RepositoryError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebSubServiceError {
+    #[error("there is no WebSub subscription for the feed {0}")]
+    SubscriptionNotFound(Uuid),
+
+    #[error("the WebSub verification of the feed {0} does not match its subscription")]
+    VerificationMismatch(Uuid),
+
+    #[error("a repository error ocurred: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<WebSubServiceError> for ApiError {
+    fn from(error: WebSubServiceError) -> Self {
+        match error {
+            // The hubs expect a 404 when the subscriber does not agree with the verification
+            e @ (WebSubServiceError::SubscriptionNotFound(_)
+            | WebSubServiceError::VerificationMismatch(_)) => Self {
+                original_error: e.into(),
+                status_code: StatusCode::NOT_FOUND,
+            },
+
+            e => Self {
+                original_error: e.into(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+        }
+    }
+}
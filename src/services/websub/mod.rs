@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! WebSub subscriber. The feed service keeps a subscription for every feed that advertises a hub,
+//! and this service requests them to the hubs, answers their verifications and authenticates the
+//! content they push
+mod error;
+mod websub_service_impl;
+
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha1::Sha1;
+use sha2::{Sha256, Sha384, Sha512};
+
+pub use error::WebSubServiceError;
+pub use websub_service_impl::WebSubServiceImpl;
+use uuid::Uuid;
+
+pub(crate) type Result<T> = std::result::Result<T, WebSubServiceError>;
+
+/// Header with the signature of the pushed content
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature";
+
+/// Path of the callbacks of the subscriptions, followed by the id of the feed
+pub const WEBSUB_CALLBACK_PATH: &str = "/websub";
+
+/// How often the subscriptions are checked for the ones that must be requested or renewed
+const SUBSCRIPTION_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationMode {
+    Subscribe,
+    Unsubscribe,
+    Denied,
+}
+
+/// Verification of intent sent by a hub to the callback of a subscription
+#[derive(Deserialize, Debug)]
+pub struct Verification {
+    #[serde(rename = "hub.mode")]
+    pub mode: VerificationMode,
+    #[serde(rename = "hub.topic")]
+    pub topic: String,
+    /// It is not sent when the subscription is denied
+    #[serde(rename = "hub.challenge")]
+    pub challenge: Option<String>,
+    #[serde(rename = "hub.lease_seconds")]
+    pub lease_seconds: Option<i64>,
+    #[serde(rename = "hub.reason")]
+    pub reason: Option<String>,
+}
+
+#[async_trait]
+pub(crate) trait WebSubService: Sync + Send {
+    /// Answers the verification of intent of a hub. Returns the challenge to send back, if the
+    /// verification is confirmed
+    async fn verify_intent(
+        &self,
+        feed_id: Uuid,
+        verification: &Verification,
+    ) -> Result<Option<String>>;
+
+    /// Checks the signature (`X-Hub-Signature` header) of the content pushed by the hub of a
+    /// feed. The content with an invalid signature must be ignored
+    async fn authenticate_content(
+        &self,
+        feed_id: Uuid,
+        signature: Option<&str>,
+        content: &[u8],
+    ) -> Result<bool>;
+
+    /// Requests to the hubs the subscriptions that were not requested yet, were not verified or
+    /// are about to expire
+    async fn request_subscriptions(&self) -> Result<()>;
+}
+
+/// Checks a `X-Hub-Signature` header (`<method>=<hex digest>`) against the content
+pub fn verify_signature(secret: &str, signature: &str, content: &[u8]) -> bool {
+    fn verify<M: Mac + hmac::digest::KeyInit>(secret: &str, digest: &[u8], content: &[u8]) -> bool {
+        <M as Mac>::new_from_slice(secret.as_bytes())
+            .map(|mut mac| {
+                mac.update(content);
+                mac.verify_slice(digest).is_ok()
+            })
+            .unwrap_or(false)
+    }
+
+    let Some((method, digest)) = signature.split_once('=') else {
+        return false;
+    };
+    let Some(digest) = decode_hex(digest) else {
+        return false;
+    };
+
+    match method {
+        "sha1" => verify::<Hmac<Sha1>>(secret, &digest, content),
+        "sha256" => verify::<Hmac<Sha256>>(secret, &digest, content),
+        "sha384" => verify::<Hmac<Sha384>>(secret, &digest, content),
+        "sha512" => verify::<Hmac<Sha512>>(secret, &digest, content),
+        _ => false,
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) || !value.is_ascii() {
+        return None;
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Requests and renews the subscriptions periodically. It never returns, so it must be spawned
+pub(crate) async fn run_subscription_requests<WSS: WebSubService>(service: Arc<WSS>) {
+    let mut interval = tokio::time::interval(SUBSCRIPTION_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = service.request_subscriptions().await {
+            tracing::error!("there was an error requesting the WebSub subscriptions: {e:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signatures_are_verified() {
+        let content = b"The quick brown fox jumps over the lazy dog";
+
+        assert!(verify_signature(
+            "key",
+            "sha1=de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9",
+            content
+        ));
+        assert!(verify_signature(
+            "key",
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+            content
+        ));
+        assert!(!verify_signature(
+            "other key",
+            "sha1=de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9",
+            content
+        ));
+        assert!(!verify_signature("key", "md5=80070713463e7749b90c2dc24911e275", content));
+        assert!(!verify_signature("key", "sha1=not hex", content));
+    }
+}
@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use chrono::{TimeDelta, Utc};
+use reqwest::Client;
+use uuid::Uuid;
+
+use super::{
+    error::WebSubServiceError, verify_signature, Result, Verification, VerificationMode,
+    WebSubService, WEBSUB_CALLBACK_PATH,
+};
+use crate::{
+    config::Config,
+    models::websub_subscription::{
+        SubscriptionStatus, WebSubSubscription, REQUESTED_LEASE_SECONDS,
+    },
+    repositories::websub_subscription::WebSubSubscriptionRepository,
+};
+
+/// Time to wait for the response of a hub
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct WebSubServiceImpl<WSR>
+where
+    WSR: WebSubSubscriptionRepository,
+{
+    websub_subscription_repository: Arc<WSR>,
+    config: Arc<Config>,
+    client: Client,
+}
+
+impl<WSR> WebSubServiceImpl<WSR>
+where
+    WSR: WebSubSubscriptionRepository,
+{
+    pub fn new(websub_subscription_repository: Arc<WSR>, config: Arc<Config>) -> Self {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("unable to initialize the WebSub http client");
+
+        Self {
+            websub_subscription_repository,
+            config,
+            client,
+        }
+    }
+
+    /// Sends the subscription request to the hub. The hub confirms it later through the callback
+    async fn request_subscription(
+        &self,
+        public_url: &str,
+        subscription: &WebSubSubscription,
+    ) -> std::result::Result<(), String> {
+        let callback = format!(
+            "{}{WEBSUB_CALLBACK_PATH}/{}",
+            public_url.trim_end_matches('/'),
+            subscription.feed_id
+        );
+        let lease_seconds = REQUESTED_LEASE_SECONDS.to_string();
+
+        let response = self
+            .client
+            .post(&subscription.hub_url)
+            .form(&[
+                ("hub.callback", callback.as_str()),
+                ("hub.mode", "subscribe"),
+                ("hub.topic", subscription.topic_url.as_str()),
+                ("hub.secret", subscription.secret.as_str()),
+                ("hub.lease_seconds", lease_seconds.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("the hub responded with status {}", response.status()))
+        }
+    }
+}
+
+#[async_trait]
+impl<WSR> WebSubService for WebSubServiceImpl<WSR>
+where
+    WSR: WebSubSubscriptionRepository,
+{
+    async fn verify_intent(
+        &self,
+        feed_id: Uuid,
+        verification: &Verification,
+    ) -> Result<Option<String>> {
+        let subscription = self
+            .websub_subscription_repository
+            .get_subscription(feed_id)
+            .await?
+            .filter(|subscription| subscription.topic_url == verification.topic);
+
+        match (verification.mode, subscription) {
+            (VerificationMode::Subscribe, Some(mut subscription))
+                if matches!(
+                    subscription.status,
+                    SubscriptionStatus::Requested | SubscriptionStatus::Active
+                ) =>
+            {
+                let lease_seconds = verification.lease_seconds.unwrap_or(REQUESTED_LEASE_SECONDS);
+                subscription.status = SubscriptionStatus::Active;
+                subscription.lease_expires_at =
+                    Some(Utc::now() + TimeDelta::seconds(lease_seconds));
+                self.websub_subscription_repository
+                    .save_subscription(&subscription)
+                    .await?;
+
+                tracing::info!("the WebSub subscription of the feed {feed_id} is active");
+                Ok(verification.challenge.clone())
+            }
+            // The subscriptions are only dropped when the feed stops advertising the hub, so an
+            // unsubscription is confirmed only if there is no subscription for the topic
+            (VerificationMode::Unsubscribe, None) => Ok(verification.challenge.clone()),
+            (VerificationMode::Denied, Some(mut subscription)) => {
+                tracing::warn!(
+                    "the WebSub hub denied the subscription of the feed {feed_id}: {}",
+                    verification.reason.as_deref().unwrap_or("no reason given")
+                );
+                subscription.status = SubscriptionStatus::Denied;
+                subscription.lease_expires_at = None;
+                self.websub_subscription_repository
+                    .save_subscription(&subscription)
+                    .await?;
+
+                Ok(None)
+            }
+            _ => Err(WebSubServiceError::VerificationMismatch(feed_id)),
+        }
+    }
+
+    async fn authenticate_content(
+        &self,
+        feed_id: Uuid,
+        signature: Option<&str>,
+        content: &[u8],
+    ) -> Result<bool> {
+        let subscription = self
+            .websub_subscription_repository
+            .get_subscription(feed_id)
+            .await?
+            .ok_or(WebSubServiceError::SubscriptionNotFound(feed_id))?;
+
+        let authentic = signature
+            .is_some_and(|signature| verify_signature(&subscription.secret, signature, content));
+        if !authentic {
+            tracing::warn!("ignoring WebSub content with an invalid signature for feed {feed_id}");
+        }
+
+        Ok(authentic)
+    }
+
+    async fn request_subscriptions(&self) -> Result<()> {
+        let Some(public_url) = &self.config.public_url else {
+            return Ok(());
+        };
+
+        let now = Utc::now();
+        let subscriptions = self
+            .websub_subscription_repository
+            .get_subscription_list()
+            .await?;
+
+        for mut subscription in subscriptions {
+            if !subscription.needs_request(now) {
+                continue;
+            }
+
+            if let Err(e) = self.request_subscription(public_url, &subscription).await {
+                tracing::warn!(
+                    "unable to request the WebSub subscription of the feed {} to {}: {e}",
+                    subscription.feed_id,
+                    subscription.hub_url
+                );
+            }
+
+            // A failed request is retried after the verification timeout. An active subscription
+            // keeps pushing until its lease expires, even if the renewal is not verified
+            subscription.requested_at = Some(now);
+            if subscription.status != SubscriptionStatus::Active {
+                subscription.status = SubscriptionStatus::Requested;
+            }
+            self.websub_subscription_repository
+                .save_subscription(&subscription)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
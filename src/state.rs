@@ -3,13 +3,27 @@
 use crate::{
     config::Config,
     providers::{
+        email::EmailProviderSmtpImpl,
+        enclosure::EnclosureProviderImpl,
+        epub::EpubProviderImpl,
         favicon::FaviconProviderImpl,
         feed_parser::{AtomParserImpl, RssParserImpl},
         html_processor::HtmlProcessorImpl,
         persisted_config::PersistedConfigProviderImpl,
+        save_to::{SaveEndpointProviderImpl, WallabagProviderImpl},
     },
     repositories::{
+        category::CategoryRepositoryImpl,
+        digest::DigestRepositoryImpl,
+        email_delivery::EmailDeliveryRepositoryImpl,
+        enclosure::EnclosureRepositoryImpl,
         feed_content::FeedContentFsRepositoryImpl,
+        filter_rule::FilterRuleRepositoryImpl,
+        rewrite_rule::RewriteRuleRepositoryImpl,
+        saved_article::SavedArticleRepositoryImpl,
+        tag::TagRepositoryImpl,
+        webhook::WebhookRepositoryImpl,
+        websub_subscription::WebSubSubscriptionRepositoryImpl,
         persisted_config::{
             persisted_config_repository_impl::PersistedConfigFsRepositoryImpl,
             PersistedConfigRepository,
@@ -25,29 +39,118 @@ use sqlite::ConnectionThreadSafe;
 use crate::{
     repositories::feed::FeedRepositoryImpl,
     services::{
+        category::{CategoryService, CategoryServiceImpl},
+        digest::{DigestService, DigestServiceImpl},
+        email::{EmailService, EmailServiceImpl},
+        export::{ExportService, ExportServiceImpl},
         feed::{FeedService, FeedServiceImpl},
+        feed_rules::FeedRulesServiceImpl,
+        fever::{FeverService, FeverServiceImpl},
+        greader::{GReaderService, GReaderServiceImpl},
+        filter_rule::{FilterRuleService, FilterRuleServiceImpl},
         persisted_config::{PersistedConfigService, PersistedConfigServiceImpl},
+        revision::{RevisionService, RevisionServiceImpl},
+        rewrite_rule::{RewriteRuleService, RewriteRuleServiceImpl},
+        save_to::{SaveToService, SaveToServiceImpl},
+        tag::{TagService, TagServiceImpl},
         templates::{TemplateService, TemplateServiceImpl},
+        webhook::{WebhookService, WebhookServiceImpl},
+        websub::{WebSubService, WebSubServiceImpl},
     },
 };
 
+type FeedServiceType = FeedServiceImpl<
+    FeedRepositoryImpl,
+    CategoryRepositoryImpl,
+    FeedContentFsRepositoryImpl,
+    FeedRulesServiceImpl<FilterRuleRepositoryImpl, RewriteRuleRepositoryImpl>,
+    EnclosureRepositoryImpl,
+    TagRepositoryImpl,
+    WebhookRepositoryImpl,
+    WebSubSubscriptionRepositoryImpl,
+    HtmlProcessorImpl,
+    RssParserImpl,
+    AtomParserImpl,
+    FaviconProviderImpl,
+    EnclosureProviderImpl,
+    PersistedConfigProviderImpl,
+>;
+
+type EmailServiceType = EmailServiceImpl<
+    EmailDeliveryRepositoryImpl,
+    DigestRepositoryImpl,
+    FeedServiceType,
+    PersistedConfigProviderImpl,
+    EpubProviderImpl,
+    EmailProviderSmtpImpl,
+>;
+
 #[derive(Clone)]
 pub struct State {
     pub template_service: Arc<TemplateServiceImpl<'static, PersistedConfigProviderImpl>>,
 
-    pub feed_service: Arc<
-        FeedServiceImpl<
+    pub feed_service: Arc<FeedServiceType>,
+
+    pub persisted_config_service: Arc<
+        PersistedConfigServiceImpl<PersistedConfigFsRepositoryImpl, PersistedConfigProviderImpl>,
+    >,
+
+    pub category_service: Arc<CategoryServiceImpl<CategoryRepositoryImpl>>,
+
+    pub filter_rule_service: Arc<
+        FilterRuleServiceImpl<
+            FilterRuleRepositoryImpl,
             FeedRepositoryImpl,
             FeedContentFsRepositoryImpl,
-            HtmlProcessorImpl,
-            RssParserImpl,
-            AtomParserImpl,
-            FaviconProviderImpl,
         >,
     >,
 
-    pub persisted_config_service: Arc<
-        PersistedConfigServiceImpl<PersistedConfigFsRepositoryImpl, PersistedConfigProviderImpl>,
+    pub rewrite_rule_service:
+        Arc<RewriteRuleServiceImpl<RewriteRuleRepositoryImpl, FeedRepositoryImpl>>,
+
+    pub tag_service: Arc<TagServiceImpl<TagRepositoryImpl, FeedRepositoryImpl>>,
+
+    pub revision_service: Arc<RevisionServiceImpl<FeedContentFsRepositoryImpl>>,
+
+    pub fever_service: Arc<
+        FeverServiceImpl<FeedRepositoryImpl, FeedContentFsRepositoryImpl, CategoryRepositoryImpl>,
+    >,
+
+    pub export_service: Arc<
+        ExportServiceImpl<
+            FeedRepositoryImpl,
+            FeedContentFsRepositoryImpl,
+            CategoryRepositoryImpl,
+            EpubProviderImpl,
+        >,
+    >,
+
+    pub greader_service:
+        Arc<GReaderServiceImpl<FeedServiceType, CategoryServiceImpl<CategoryRepositoryImpl>>>,
+
+    pub webhook_service: Arc<WebhookServiceImpl<WebhookRepositoryImpl>>,
+
+    pub websub_service: Arc<WebSubServiceImpl<WebSubSubscriptionRepositoryImpl>>,
+
+    pub digest_service: Arc<
+        DigestServiceImpl<
+            DigestRepositoryImpl,
+            FeedServiceType,
+            PersistedConfigProviderImpl,
+            EpubProviderImpl,
+        >,
+    >,
+
+    pub email_service: Arc<EmailServiceType>,
+
+    pub save_to_service: Arc<
+        SaveToServiceImpl<
+            SavedArticleRepositoryImpl,
+            FeedServiceType,
+            PersistedConfigProviderImpl,
+            WallabagProviderImpl,
+            SaveEndpointProviderImpl,
+        >,
     >,
 }
 
@@ -56,12 +159,51 @@ pub trait AppState: Sync + Send + Clone + 'static {
     type TS: TemplateService<'static>;
     type FS: FeedService;
     type PCS: PersistedConfigService;
+    type CS: CategoryService;
+    type FLS: FilterRuleService;
+    type RWS: RewriteRuleService;
+    type TGS: TagService;
+    type RVS: RevisionService;
+    type FVS: FeverService;
+    type GRS: GReaderService;
+    type EXS: ExportService;
+    type WHS: WebhookService;
+    type WSS: WebSubService;
+    type DGS: DigestService;
+    type EMS: EmailService;
+    type STS: SaveToService;
 
     fn template_service(&self) -> &Self::TS;
 
     fn feed_service(&self) -> &Self::FS;
 
     fn persisted_config_service(&self) -> &Self::PCS;
+
+    fn category_service(&self) -> &Self::CS;
+
+    fn filter_rule_service(&self) -> &Self::FLS;
+
+    fn rewrite_rule_service(&self) -> &Self::RWS;
+
+    fn tag_service(&self) -> &Self::TGS;
+
+    fn revision_service(&self) -> &Self::RVS;
+
+    fn fever_service(&self) -> &Self::FVS;
+
+    fn greader_service(&self) -> &Self::GRS;
+
+    fn export_service(&self) -> &Self::EXS;
+
+    fn webhook_service(&self) -> &Self::WHS;
+
+    fn websub_service(&self) -> &Self::WSS;
+
+    fn digest_service(&self) -> &Self::DGS;
+
+    fn email_service(&self) -> &Self::EMS;
+
+    fn save_to_service(&self) -> &Self::STS;
 }
 
 impl State {
@@ -71,6 +213,20 @@ impl State {
 
         // Initialize repositories
         let feed_repository = Arc::new(FeedRepositoryImpl::new(connection.clone()));
+        let category_repository = Arc::new(CategoryRepositoryImpl::new(connection.clone()));
+        let filter_rule_repository = Arc::new(FilterRuleRepositoryImpl::new(connection.clone()));
+        let rewrite_rule_repository = Arc::new(RewriteRuleRepositoryImpl::new(connection.clone()));
+        let enclosure_repository = Arc::new(EnclosureRepositoryImpl::new(connection.clone()));
+        let tag_repository = Arc::new(TagRepositoryImpl::new(connection.clone()));
+        let webhook_repository = Arc::new(WebhookRepositoryImpl::new(connection.clone()));
+        let websub_subscription_repository =
+            Arc::new(WebSubSubscriptionRepositoryImpl::new(connection.clone()));
+        let digest_repository =
+            Arc::new(DigestRepositoryImpl::new(connection.clone(), config.clone()));
+        let email_delivery_repository =
+            Arc::new(EmailDeliveryRepositoryImpl::new(connection.clone(), config.clone()));
+        let saved_article_repository =
+            Arc::new(SavedArticleRepositoryImpl::new(connection.clone()));
         let feed_content_repository =
             Arc::new(FeedContentFsRepositoryImpl::new(connection, config.clone()));
         let persisted_config_repository = Arc::new(PersistedConfigFsRepositoryImpl::new(
@@ -82,6 +238,7 @@ impl State {
             Arc::new(HtmlProcessorImpl::new().expect("unable to initialize html processor"));
         let rss_parser_provider = Arc::new(RssParserImpl);
         let atom_parser_provider = Arc::new(AtomParserImpl);
+        let epub_provider = Arc::new(EpubProviderImpl::new(config.clone(), ARTICLES_DIR));
 
         let persisted_config = persisted_config_repository.load_configuration().await;
         let persisted_config_provider =
@@ -97,41 +254,161 @@ impl State {
         let template_service = Arc::new(template_service);
 
         let feed_service = Arc::new(FeedServiceImpl::new(
-            feed_repository,
-            feed_content_repository,
+            feed_repository.clone(),
+            category_repository.clone(),
+            feed_content_repository.clone(),
+            Arc::new(FeedRulesServiceImpl::new(
+                filter_rule_repository.clone(),
+                rewrite_rule_repository.clone(),
+            )),
+            enclosure_repository,
+            tag_repository.clone(),
+            webhook_repository.clone(),
+            websub_subscription_repository.clone(),
             html_processor_provider,
             rss_parser_provider,
             atom_parser_provider,
             Arc::new(FaviconProviderImpl::new(config.clone(), FAVICONS_DIR)),
+            Arc::new(EnclosureProviderImpl::new(config.clone(), ARTICLES_DIR)),
+            persisted_config_provider.clone(),
             config.clone(),
             ARTICLES_DIR,
         ));
 
         let persisted_config_service = Arc::new(PersistedConfigServiceImpl::new(
             persisted_config_repository,
+            persisted_config_provider.clone(),
+        ));
+
+        let fever_service = Arc::new(FeverServiceImpl::new(
+            feed_repository.clone(),
+            feed_content_repository.clone(),
+            category_repository.clone(),
+            config.clone(),
+        ));
+
+        let export_service = Arc::new(ExportServiceImpl::new(
+            feed_repository.clone(),
+            feed_content_repository.clone(),
+            category_repository.clone(),
+            epub_provider.clone(),
+            config.clone(),
+        ));
+
+        let category_service = Arc::new(CategoryServiceImpl::new(category_repository));
+
+        let filter_rule_service = Arc::new(FilterRuleServiceImpl::new(
+            filter_rule_repository,
+            feed_repository.clone(),
+            feed_content_repository.clone(),
+        ));
+
+        let rewrite_rule_service = Arc::new(RewriteRuleServiceImpl::new(
+            rewrite_rule_repository,
+            feed_repository.clone(),
+        ));
+
+        let tag_service = Arc::new(TagServiceImpl::new(tag_repository, feed_repository));
+
+        let revision_service = Arc::new(RevisionServiceImpl::new(feed_content_repository));
+
+        let webhook_service = Arc::new(WebhookServiceImpl::new(webhook_repository));
+
+        let websub_service = Arc::new(WebSubServiceImpl::new(
+            websub_subscription_repository,
+            config.clone(),
+        ));
+
+        let digest_service = Arc::new(DigestServiceImpl::new(
+            digest_repository.clone(),
+            feed_service.clone(),
+            persisted_config_provider.clone(),
+            epub_provider.clone(),
+        ));
+
+        let email_service = Arc::new(EmailServiceImpl::new(
+            email_delivery_repository,
+            digest_repository,
+            feed_service.clone(),
+            persisted_config_provider.clone(),
+            epub_provider,
+            Arc::new(EmailProviderSmtpImpl),
+        ));
+
+        let save_to_service = Arc::new(SaveToServiceImpl::new(
+            saved_article_repository,
+            feed_service.clone(),
             persisted_config_provider,
+            Arc::new(WallabagProviderImpl::new()),
+            Arc::new(SaveEndpointProviderImpl::new()),
+        ));
+
+        let greader_service = Arc::new(GReaderServiceImpl::new(
+            feed_service.clone(),
+            category_service.clone(),
+            config,
         ));
 
         Self {
             template_service,
             feed_service,
             persisted_config_service,
+            category_service,
+            filter_rule_service,
+            rewrite_rule_service,
+            tag_service,
+            revision_service,
+            fever_service,
+            greader_service,
+            export_service,
+            webhook_service,
+            websub_service,
+            digest_service,
+            email_service,
+            save_to_service,
         }
     }
 }
 
 impl AppState for State {
     type TS = TemplateServiceImpl<'static, PersistedConfigProviderImpl>;
-    type FS = FeedServiceImpl<
+    type FS = FeedServiceType;
+    type PCS =
+        PersistedConfigServiceImpl<PersistedConfigFsRepositoryImpl, PersistedConfigProviderImpl>;
+    type CS = CategoryServiceImpl<CategoryRepositoryImpl>;
+    type FLS = FilterRuleServiceImpl<
+        FilterRuleRepositoryImpl,
         FeedRepositoryImpl,
         FeedContentFsRepositoryImpl,
-        HtmlProcessorImpl,
-        RssParserImpl,
-        AtomParserImpl,
-        FaviconProviderImpl,
     >;
-    type PCS =
-        PersistedConfigServiceImpl<PersistedConfigFsRepositoryImpl, PersistedConfigProviderImpl>;
+    type RWS = RewriteRuleServiceImpl<RewriteRuleRepositoryImpl, FeedRepositoryImpl>;
+    type TGS = TagServiceImpl<TagRepositoryImpl, FeedRepositoryImpl>;
+    type RVS = RevisionServiceImpl<FeedContentFsRepositoryImpl>;
+    type FVS =
+        FeverServiceImpl<FeedRepositoryImpl, FeedContentFsRepositoryImpl, CategoryRepositoryImpl>;
+    type GRS = GReaderServiceImpl<FeedServiceType, CategoryServiceImpl<CategoryRepositoryImpl>>;
+    type EXS = ExportServiceImpl<
+        FeedRepositoryImpl,
+        FeedContentFsRepositoryImpl,
+        CategoryRepositoryImpl,
+        EpubProviderImpl,
+    >;
+    type WHS = WebhookServiceImpl<WebhookRepositoryImpl>;
+    type WSS = WebSubServiceImpl<WebSubSubscriptionRepositoryImpl>;
+    type DGS = DigestServiceImpl<
+        DigestRepositoryImpl,
+        FeedServiceType,
+        PersistedConfigProviderImpl,
+        EpubProviderImpl,
+    >;
+    type EMS = EmailServiceType;
+    type STS = SaveToServiceImpl<
+        SavedArticleRepositoryImpl,
+        FeedServiceType,
+        PersistedConfigProviderImpl,
+        WallabagProviderImpl,
+        SaveEndpointProviderImpl,
+    >;
 
     fn template_service(&self) -> &Self::TS {
         &self.template_service
@@ -144,4 +421,56 @@ impl AppState for State {
     fn persisted_config_service(&self) -> &Self::PCS {
         &self.persisted_config_service
     }
+
+    fn category_service(&self) -> &Self::CS {
+        &self.category_service
+    }
+
+    fn filter_rule_service(&self) -> &Self::FLS {
+        &self.filter_rule_service
+    }
+
+    fn rewrite_rule_service(&self) -> &Self::RWS {
+        &self.rewrite_rule_service
+    }
+
+    fn tag_service(&self) -> &Self::TGS {
+        &self.tag_service
+    }
+
+    fn revision_service(&self) -> &Self::RVS {
+        &self.revision_service
+    }
+
+    fn fever_service(&self) -> &Self::FVS {
+        &self.fever_service
+    }
+
+    fn greader_service(&self) -> &Self::GRS {
+        &self.greader_service
+    }
+
+    fn export_service(&self) -> &Self::EXS {
+        &self.export_service
+    }
+
+    fn webhook_service(&self) -> &Self::WHS {
+        &self.webhook_service
+    }
+
+    fn websub_service(&self) -> &Self::WSS {
+        &self.websub_service
+    }
+
+    fn digest_service(&self) -> &Self::DGS {
+        &self.digest_service
+    }
+
+    fn email_service(&self) -> &Self::EMS {
+        &self.email_service
+    }
+
+    fn save_to_service(&self) -> &Self::STS {
+        &self.save_to_service
+    }
 }
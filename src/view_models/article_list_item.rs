@@ -1,27 +1,79 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
+use std::collections::HashMap;
+
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::models::article::Article;
+use crate::models::{article::Article, feed::Feed};
 
 #[derive(Serialize)]
 pub struct ArticleListItem {
     id: Uuid,
+    feed_id: Uuid,
     title: String,
     author: String,
+    summary: Option<String>,
+    thumbnail_url: Option<String>,
     date: String,
     read: bool,
+    starred: bool,
+    /// Whether the publisher updated the article since the user read it
+    has_update: bool,
+    /// Only set in lists that mix articles of several feeds
+    feed_title: Option<String>,
+    /// Titles of the other feeds that published a copy of the article. Only set in lists that
+    /// mix articles of several feeds
+    also_in: Vec<String>,
+}
+
+impl ArticleListItem {
+    /// Attaches the title of the feed the article belongs to, so it can be shown in lists that
+    /// mix articles of several feeds
+    pub fn with_feed(mut self, feed: Option<&Feed>) -> Self {
+        self.feed_title = feed.map(|f| f.title.clone());
+        self
+    }
+
+    /// Creates the items of a list that mixes articles of several feeds. The copies of an article
+    /// published by different feeds are grouped in a single item, the first one of the list
+    pub fn from_articles(articles: Vec<Article>, feeds: &HashMap<Uuid, Feed>) -> Vec<Self> {
+        let mut items: Vec<Self> = vec![];
+        let mut groups: HashMap<Uuid, usize> = HashMap::new();
+
+        for article in articles {
+            let group = article.duplicate_of.unwrap_or(article.id);
+            let feed = feeds.get(&article.feed_id);
+
+            if let Some(&index) = groups.get(&group) {
+                if let Some(feed) = feed {
+                    items[index].also_in.push(feed.title.clone());
+                }
+            } else {
+                groups.insert(group, items.len());
+                items.push(Self::from(article).with_feed(feed));
+            }
+        }
+
+        items
+    }
 }
 
 impl From<Article> for ArticleListItem {
     fn from(value: Article) -> Self {
         Self {
             id: value.id,
+            feed_id: value.feed_id,
             title: value.title,
             author: value.author.unwrap_or_default(),
-            date: value.last_updated.format("%B %d, %Y").to_string(),
+            summary: value.summary,
+            thumbnail_url: value.thumbnail_url,
+            date: value.published_at.format("%B %d, %Y").to_string(),
             read: value.read,
+            starred: value.starred,
+            has_update: value.has_update,
+            feed_title: None,
+            also_in: vec![],
         }
     }
 }
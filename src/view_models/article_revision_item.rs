@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::article_revision::ArticleRevision;
+
+#[derive(Serialize)]
+pub struct ArticleRevisionItem {
+    id: Uuid,
+    title: String,
+    date: String,
+}
+
+impl From<ArticleRevision> for ArticleRevisionItem {
+    fn from(value: ArticleRevision) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            date: value.created_at.format("%B %d, %Y %H:%M").to_string(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Atom feed of the exported articles
+use atom_syndication::{Content, Entry, Feed as AtomFeed, Generator, Link, Person, Source, Text};
+use chrono::Utc;
+
+use crate::{
+    models::{article::Article, feed::Feed},
+    services::export::ExportedArticles,
+};
+
+/// Builds the Atom feed of the exported articles. `id` identifies the feed and `self_url` is the
+/// url it is served from
+pub fn atom_feed(exported: ExportedArticles, id: String, self_url: String) -> AtomFeed {
+    let updated = exported
+        .articles
+        .iter()
+        .map(|(article, _, _)| article.last_updated)
+        .max()
+        .unwrap_or_else(Utc::now);
+
+    AtomFeed {
+        title: Text::plain(exported.title),
+        id,
+        updated: updated.fixed_offset(),
+        generator: Some(Generator {
+            value: "Kindly RSS Reader".to_owned(),
+            ..Default::default()
+        }),
+        links: vec![Link {
+            href: self_url,
+            rel: "self".to_owned(),
+            mime_type: Some("application/atom+xml".to_owned()),
+            ..Default::default()
+        }],
+        entries: exported
+            .articles
+            .into_iter()
+            .map(|(article, feed, html)| atom_entry(article, &feed, html))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn atom_entry(article: Article, feed: &Feed, html: String) -> Entry {
+    Entry {
+        title: Text::plain(article.title),
+        id: format!("urn:uuid:{}", article.id),
+        updated: article.last_updated.fixed_offset(),
+        published: Some(article.published_at.fixed_offset()),
+        authors: vec![Person {
+            name: article.author.unwrap_or_else(|| feed.title.clone()),
+            ..Default::default()
+        }],
+        links: vec![Link {
+            href: article.link,
+            ..Default::default()
+        }],
+        content: Some(Content {
+            value: Some(html),
+            content_type: Some("html".to_owned()),
+            ..Default::default()
+        }),
+        // The feed the article was published in
+        source: Some(Source {
+            title: Text::plain(feed.title.clone()),
+            id: feed.url.clone(),
+            updated: feed.last_updated.fixed_offset(),
+            links: vec![Link {
+                href: feed.link.clone(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
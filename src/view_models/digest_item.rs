@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::digest::Digest;
+
+const DATE_FORMAT: &str = "%B %d, %Y %H:%M";
+
+/// Entry of the list of digests
+#[derive(Serialize)]
+pub struct DigestItem {
+    id: Uuid,
+    title: String,
+    date: String,
+    article_count: usize,
+}
+
+impl From<Digest> for DigestItem {
+    fn from(value: Digest) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            date: value.created_at.format(DATE_FORMAT).to_string(),
+            article_count: value.article_count,
+        }
+    }
+}
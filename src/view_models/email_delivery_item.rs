@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::{email_delivery::EmailDelivery, webhook::DeliveryStatus};
+
+const DATE_FORMAT: &str = "%B %d, %Y %H:%M";
+
+/// Entry of the outbox of the emails sent to the device
+#[derive(Serialize)]
+pub struct EmailDeliveryItem {
+    id: Uuid,
+    title: String,
+    status: DeliveryStatus,
+    attempts: u32,
+    date: String,
+    /// When it is going to be retried, if it is pending
+    next_attempt: Option<String>,
+    error: Option<String>,
+}
+
+impl From<EmailDelivery> for EmailDeliveryItem {
+    fn from(value: EmailDelivery) -> Self {
+        let next_attempt = (value.status == DeliveryStatus::Pending)
+            .then(|| value.next_attempt_at.format(DATE_FORMAT).to_string());
+
+        Self {
+            id: value.id,
+            title: value.title,
+            status: value.status,
+            attempts: value.attempts,
+            date: value.created_at.format(DATE_FORMAT).to_string(),
+            next_attempt,
+            error: value.error,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+
+use crate::models::enclosure::Enclosure;
+
+#[derive(Serialize)]
+pub struct EnclosureItem {
+    /// Url of the cached file if it was downloaded to the server, the original one otherwise
+    url: String,
+    name: String,
+    mime_type: Option<String>,
+    size: Option<String>,
+    duration: Option<String>,
+    cached: bool,
+}
+
+impl EnclosureItem {
+    fn format_size(bytes: u64) -> String {
+        const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+        let mut size = bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            format!("{bytes} {}", UNITS[unit])
+        } else {
+            format!("{size:.1} {}", UNITS[unit])
+        }
+    }
+
+    fn format_duration(seconds: u32) -> String {
+        let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+
+        if hours > 0 {
+            format!("{hours}:{minutes:02}:{seconds:02}")
+        } else {
+            format!("{minutes}:{seconds:02}")
+        }
+    }
+}
+
+impl From<Enclosure> for EnclosureItem {
+    fn from(value: Enclosure) -> Self {
+        let name = reqwest::Url::parse(&value.url)
+            .ok()
+            .and_then(|url| {
+                url.path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .filter(|name| !name.is_empty())
+                    .map(|name| name.to_owned())
+            })
+            .unwrap_or_else(|| value.url.clone());
+
+        Self {
+            cached: value.cached_url.is_some(),
+            url: value.cached_url.unwrap_or(value.url),
+            name,
+            mime_type: value.mime_type,
+            size: value.length.map(Self::format_size),
+            duration: value.duration.map(Self::format_duration),
+        }
+    }
+}
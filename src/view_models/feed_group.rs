@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+
+use crate::models::{category::Category, feed::Feed};
+
+/// A group of feeds shown together in the feed list. Feeds without a category are grouped
+/// together in a group with no category.
+#[derive(Serialize)]
+pub struct FeedGroup {
+    category: Option<Category>,
+    feeds: Vec<Feed>,
+}
+
+impl FeedGroup {
+    /// Groups the feeds by category, keeping the categories order. Feeds without a category are
+    /// returned in the last group.
+    pub fn group(categories: Vec<Category>, feeds: Vec<Feed>) -> Vec<Self> {
+        let mut groups: Vec<Self> = categories
+            .into_iter()
+            .map(|category| Self {
+                category: Some(category),
+                feeds: vec![],
+            })
+            .collect();
+
+        let mut uncategorized = vec![];
+        for feed in feeds {
+            let group = feed.category_id.and_then(|category_id| {
+                groups
+                    .iter_mut()
+                    .find(|g| g.category.as_ref().is_some_and(|c| c.id == category_id))
+            });
+
+            match group {
+                Some(group) => group.feeds.push(feed),
+                None => uncategorized.push(feed),
+            }
+        }
+
+        if !uncategorized.is_empty() {
+            groups.push(Self {
+                category: None,
+                feeds: uncategorized,
+            });
+        }
+
+        groups
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Objects of the Fever API responses
+use std::collections::HashMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::{article::Article, category::Category, feed::Feed};
+
+/// Fever sends lists of ids as comma separated strings
+pub fn join_ids(ids: &[i64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[derive(Serialize)]
+pub struct FeverFeed {
+    id: i64,
+    favicon_id: i64,
+    title: String,
+    url: String,
+    site_url: String,
+    is_spark: u8,
+    last_updated_on_time: i64,
+}
+
+impl From<&Feed> for FeverFeed {
+    fn from(value: &Feed) -> Self {
+        Self {
+            id: value.numeric_id,
+            // The favicons are identified by the id of their feed
+            favicon_id: value.favicon_url.as_ref().map_or(0, |_| value.numeric_id),
+            title: value.title.clone(),
+            url: value.url.clone(),
+            site_url: value.link.clone(),
+            is_spark: 0,
+            last_updated_on_time: value.last_updated.timestamp(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeverGroup {
+    id: i64,
+    title: String,
+}
+
+impl From<&Category> for FeverGroup {
+    fn from(value: &Category) -> Self {
+        Self {
+            id: value.numeric_id,
+            title: value.name.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeverFeedsGroup {
+    group_id: i64,
+    feed_ids: String,
+}
+
+impl FeverFeedsGroup {
+    /// Groups the feeds by category. Feeds without a category are not included
+    pub fn from_feeds(feeds: &[Feed], categories: &[Category]) -> Vec<Self> {
+        categories
+            .iter()
+            .map(|category| {
+                let feed_ids: Vec<i64> = feeds
+                    .iter()
+                    .filter(|feed| feed.category_id == Some(category.id))
+                    .map(|feed| feed.numeric_id)
+                    .collect();
+
+                Self {
+                    group_id: category.numeric_id,
+                    feed_ids: join_ids(&feed_ids),
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeverItem {
+    id: i64,
+    feed_id: i64,
+    title: String,
+    author: String,
+    html: String,
+    url: String,
+    is_saved: u8,
+    is_read: u8,
+    created_on_time: i64,
+}
+
+impl FeverItem {
+    /// `feed_ids` maps the UUIDs of the feeds to their numeric ids
+    pub fn new(article: Article, html: String, feed_ids: &HashMap<Uuid, i64>) -> Self {
+        Self {
+            id: article.numeric_id,
+            feed_id: feed_ids.get(&article.feed_id).copied().unwrap_or_default(),
+            title: article.title,
+            author: article.author.unwrap_or_default(),
+            html,
+            url: article.link,
+            is_saved: article.starred.into(),
+            is_read: article.read.into(),
+            created_on_time: article.published_at.timestamp(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeverFavicon {
+    id: i64,
+    data: String,
+}
+
+impl From<(i64, String)> for FeverFavicon {
+    fn from((id, data): (i64, String)) -> Self {
+        Self { id, data }
+    }
+}
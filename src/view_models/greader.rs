@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+//! Objects of the Google Reader API responses
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::{
+    models::{article::Article, category::Category, feed::Feed},
+    services::greader::{item_id, StreamId},
+};
+
+#[derive(Serialize)]
+pub struct GReaderCategory {
+    id: String,
+    label: String,
+}
+
+impl From<&Category> for GReaderCategory {
+    fn from(value: &Category) -> Self {
+        Self {
+            id: StreamId::Label(value.name.clone()).to_string(),
+            label: value.name.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderSubscription {
+    id: String,
+    title: String,
+    categories: Vec<GReaderCategory>,
+    url: String,
+    html_url: String,
+    icon_url: String,
+}
+
+impl GReaderSubscription {
+    /// `base_url` is used to build the absolute url of the favicon, that is served by us
+    pub fn new(feed: &Feed, category: Option<&Category>, base_url: &str) -> Self {
+        Self {
+            id: StreamId::feed(feed).to_string(),
+            title: feed.title.clone(),
+            categories: category.into_iter().map(GReaderCategory::from).collect(),
+            url: feed.url.clone(),
+            html_url: feed.link.clone(),
+            icon_url: feed
+                .favicon_url
+                .as_ref()
+                .map(|favicon_url| format!("{base_url}{favicon_url}"))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct GReaderTag {
+    id: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    tag_type: Option<&'static str>,
+}
+
+impl From<StreamId> for GReaderTag {
+    fn from(value: StreamId) -> Self {
+        let tag_type = matches!(value, StreamId::Label(_)).then_some("folder");
+        Self {
+            id: value.to_string(),
+            tag_type,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderUnreadCount {
+    id: String,
+    count: u32,
+    newest_item_timestamp_usec: String,
+}
+
+impl GReaderUnreadCount {
+    pub fn new(stream: StreamId, count: u32, newest_item: i64) -> Self {
+        Self {
+            id: stream.to_string(),
+            count,
+            newest_item_timestamp_usec: newest_item.to_string(),
+        }
+    }
+}
+
+/// Reference to an item, returned when the client only requests the ids
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItemRef {
+    /// The short form of the item id, a decimal number
+    id: String,
+    direct_stream_ids: Vec<String>,
+    timestamp_usec: String,
+}
+
+impl GReaderItemRef {
+    pub fn new(article: &Article, feed: Option<&Feed>) -> Self {
+        Self {
+            id: article.numeric_id.to_string(),
+            direct_stream_ids: feed.map(|f| StreamId::feed(f).to_string()).into_iter().collect(),
+            timestamp_usec: article.published_at.timestamp_micros().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct GReaderLink {
+    href: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    link_type: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+pub struct GReaderContent {
+    content: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderOrigin {
+    stream_id: String,
+    title: String,
+    html_url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItem {
+    id: String,
+    crawl_time_msec: String,
+    timestamp_usec: String,
+    published: i64,
+    updated: i64,
+    title: String,
+    canonical: Vec<GReaderLink>,
+    alternate: Vec<GReaderLink>,
+    summary: GReaderContent,
+    author: String,
+    /// The state tags of the item and the label of its feed
+    categories: Vec<String>,
+    origin: GReaderOrigin,
+}
+
+impl GReaderItem {
+    pub fn new(
+        article: Article,
+        html: String,
+        feed: Option<&Feed>,
+        category: Option<&Category>,
+    ) -> Self {
+        let mut categories = vec![StreamId::ReadingList.to_string()];
+        if article.read {
+            categories.push(StreamId::Read.to_string());
+        }
+        if article.starred {
+            categories.push(StreamId::Starred.to_string());
+        }
+        if let Some(category) = category {
+            categories.push(StreamId::Label(category.name.clone()).to_string());
+        }
+
+        Self {
+            id: item_id(article.numeric_id),
+            crawl_time_msec: article.found_at.timestamp_millis().to_string(),
+            timestamp_usec: article.published_at.timestamp_micros().to_string(),
+            published: article.published_at.timestamp(),
+            updated: article.last_updated.timestamp(),
+            title: article.title,
+            canonical: vec![GReaderLink {
+                href: article.link.clone(),
+                link_type: None,
+            }],
+            alternate: vec![GReaderLink {
+                href: article.link,
+                link_type: Some("text/html"),
+            }],
+            summary: GReaderContent { content: html },
+            author: article.author.unwrap_or_default(),
+            categories,
+            origin: GReaderOrigin {
+                stream_id: feed.map(|f| StreamId::feed(f).to_string()).unwrap_or_default(),
+                title: feed.map(|f| f.title.clone()).unwrap_or_default(),
+                html_url: feed.map(|f| f.link.clone()).unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// Page of items of a stream
+#[derive(Serialize)]
+pub struct GReaderItems {
+    id: String,
+    updated: i64,
+    items: Vec<GReaderItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    continuation: Option<String>,
+}
+
+impl GReaderItems {
+    pub fn new(stream: StreamId, items: Vec<GReaderItem>, continuation: Option<String>) -> Self {
+        Self {
+            id: stream.to_string(),
+            updated: Utc::now().timestamp(),
+            items,
+            continuation,
+        }
+    }
+}
+
+/// Page of item references of a stream
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItemRefs {
+    item_refs: Vec<GReaderItemRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    continuation: Option<String>,
+}
+
+impl GReaderItemRefs {
+    pub fn new(item_refs: Vec<GReaderItemRef>, continuation: Option<String>) -> Self {
+        Self {
+            item_refs,
+            continuation,
+        }
+    }
+}
@@ -1,4 +1,14 @@
 // SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
 // SPDX-License-Identifier: AGPL-3.0-only
 pub mod article_list_item;
+pub mod article_revision_item;
+pub mod atom_export;
+pub mod digest_item;
+pub mod email_delivery_item;
+pub mod enclosure_item;
 pub mod error;
+pub mod feed_group;
+pub mod fever;
+pub mod greader;
+pub mod saved_article_item;
+pub mod webhook_delivery_item;
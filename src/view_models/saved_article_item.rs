@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use reqwest::Url;
+use serde::Serialize;
+
+use crate::models::saved_article::SavedArticle;
+
+const DATE_FORMAT: &str = "%B %d, %Y %H:%M";
+
+/// Record of an article saved to a read-it-later service
+#[derive(Serialize)]
+pub struct SavedArticleItem {
+    target: &'static str,
+    date: String,
+    /// Where the article can be read in the service, if it returns it. Only http(s) urls are
+    /// kept, as the service is remote and the url is rendered as a link
+    url: Option<String>,
+}
+
+impl From<SavedArticle> for SavedArticleItem {
+    fn from(value: SavedArticle) -> Self {
+        Self {
+            target: value.target.display_name(),
+            date: value.saved_at.format(DATE_FORMAT).to_string(),
+            url: value.url.filter(|url| {
+                Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::models::saved_article::SaveTarget;
+
+    fn saved_article(url: &str) -> SavedArticle {
+        SavedArticle {
+            id: Uuid::new_v4(),
+            article_id: Uuid::new_v4(),
+            feed_id: Uuid::new_v4(),
+            target: SaveTarget::Wallabag,
+            saved_at: Utc::now(),
+            url: Some(url.to_owned()),
+        }
+    }
+
+    #[test]
+    fn only_http_urls_are_linked() {
+        let item = SavedArticleItem::from(saved_article("https://wallabag.example/view/1"));
+        assert_eq!(Some("https://wallabag.example/view/1"), item.url.as_deref());
+        let item = SavedArticleItem::from(saved_article("javascript:alert(1)"));
+        assert_eq!(None, item.url);
+    }
+}
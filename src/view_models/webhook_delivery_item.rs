@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2025-2026 Nicolás Antinori <nico.antinori.7@gmail.com>
+// SPDX-License-Identifier: AGPL-3.0-only
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::models::webhook::{DeliveryStatus, WebhookDelivery};
+
+const DATE_FORMAT: &str = "%B %d, %Y %H:%M";
+
+/// Entry of the delivery log of the webhooks
+#[derive(Serialize)]
+pub struct WebhookDeliveryItem {
+    id: Uuid,
+    webhook_id: Uuid,
+    status: DeliveryStatus,
+    attempts: u32,
+    date: String,
+    /// When it is going to be retried, if it is pending
+    next_attempt: Option<String>,
+    response_status: Option<u16>,
+    error: Option<String>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryItem {
+    fn from(value: WebhookDelivery) -> Self {
+        let next_attempt = (value.status == DeliveryStatus::Pending)
+            .then(|| value.next_attempt_at.format(DATE_FORMAT).to_string());
+
+        Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            status: value.status,
+            attempts: value.attempts,
+            date: value.created_at.format(DATE_FORMAT).to_string(),
+            next_attempt,
+            response_status: value.response_status,
+            error: value.error,
+        }
+    }
+}